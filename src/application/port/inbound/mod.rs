@@ -3,6 +3,10 @@
 //! Inbound ports define the interfaces that the application exposes to external
 //! actors. These represent the use cases of the application and are implemented
 //! by application services.
+pub mod source_service;
+pub mod token_metadata_service;
 pub mod token_supply_service;
 
+pub use source_service::SourceService;
+pub use token_metadata_service::TokenMetadataService;
 pub use token_supply_service::TokenSupplyService;
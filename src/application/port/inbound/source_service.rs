@@ -0,0 +1,58 @@
+use anyhow::Result;
+
+use crate::domain::model::{ChainAddress, Source};
+
+/// Defines the operations for managing the live list of excluded sources.
+///
+/// The supply worker reads the list before every fetch, so changes take effect
+/// from the next refresh without a restart.
+pub trait SourceService {
+    /// Returns the sources currently excluded from the circulating supply.
+    ///
+    /// # Returns
+    ///
+    /// A future resolving to the excluded sources.
+    fn list_sources(&self) -> impl Future<Output = Vec<Source>> + Send;
+
+    /// Adds a source to the excluded sources.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The source to add.
+    /// * `actor` - Who is making the change, recorded in the audit log.
+    ///
+    /// # Returns
+    ///
+    /// A future resolving to a success indicator, or an error if the source was
+    /// rejected or could not be saved.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SourceError`](crate::domain::error::SourceError) if the
+    /// source is invalid or already excluded, or another error if the change
+    /// could not be recorded or saved.
+    fn add_source(&self, source: Source, actor: &str) -> impl Future<Output = Result<()>> + Send;
+
+    /// Removes a source from the excluded sources.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the source to remove.
+    /// * `actor` - Who is making the change, recorded in the audit log.
+    ///
+    /// # Returns
+    ///
+    /// A future resolving to the removed [`Source`], or an error if it was not
+    /// excluded or the change could not be saved.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SourceError`](crate::domain::error::SourceError) if no
+    /// source has the address, or another error if the change could not be
+    /// recorded or saved.
+    fn remove_source(
+        &self,
+        address: ChainAddress,
+        actor: &str,
+    ) -> impl Future<Output = Result<Source>> + Send;
+}
@@ -0,0 +1,47 @@
+use anyhow::Result;
+
+use crate::domain::model::{ChainAddress, Token, TokenMetadataOverrides};
+
+/// Defines the service operations for resolving token metadata.
+///
+/// Metadata is resolved from configured overrides first, then from a cache,
+/// and only then from an external source.
+pub trait TokenMetadataService {
+    /// Resolves the metadata of a token.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_address` - The address of the token.
+    /// * `overrides` - Configured values that take precedence over fetched ones.
+    ///
+    /// # Returns
+    ///
+    /// A future resolving to the resolved [`Token`], or an error if the
+    /// metadata could not be resolved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the metadata is neither fully overridden nor cached,
+    /// and could not be fetched.
+    fn resolve_token_metadata(
+        &self,
+        token_address: ChainAddress,
+        overrides: &TokenMetadataOverrides,
+    ) -> impl Future<Output = Result<Token>> + Send;
+
+    /// Compares the decimals of a resolved token with the external source.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The resolved token to verify.
+    ///
+    /// # Returns
+    ///
+    /// A future resolving to `true` if the decimals agree, `false` if they
+    /// disagree, or an error if the metadata could not be fetched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the metadata could not be fetched.
+    fn verify_token_decimals(&self, token: &Token) -> impl Future<Output = Result<bool>> + Send;
+}
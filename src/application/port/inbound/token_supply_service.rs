@@ -16,8 +16,8 @@ pub trait TokenSupplyService {
     ///
     /// # Returns
     ///
-    /// A future resolving to [`TokenSupply`], or an error if the data could not
-    /// be fetched.
+    /// A future resolving to [`TokenSupply`] stamped with the fetch time, or an
+    /// error if the data could not be fetched.
     ///
     /// # Errors
     ///
@@ -30,6 +30,9 @@ pub trait TokenSupplyService {
 
     /// Updates the stored token supply information.
     ///
+    /// The stored snapshot is given the next version number. A supply that
+    /// fails the sanity checks is rejected and the stored snapshot is kept.
+    ///
     /// # Arguments
     ///
     /// * `supply` - The new token supply data to store.
@@ -41,7 +44,10 @@ pub trait TokenSupplyService {
     ///
     /// # Errors
     ///
-    /// Returns an error if the supply data could not be updated in storage.
+    /// Returns a [`SupplyRejection`] if the supply is implausible, or an error
+    /// if the supply data could not be updated in storage.
+    ///
+    /// [`SupplyRejection`]: crate::domain::error::SupplyRejection
     fn update_token_supply(&self, supply: TokenSupply) -> impl Future<Output = Result<()>> + Send;
 
     /// Retrieves the current token supply data from storage.
@@ -0,0 +1,19 @@
+use anyhow::Result;
+
+/// Defines a capability to fetch the identifier of the connected chain.
+///
+/// This trait is implemented by components that talk to a blockchain node,
+/// allowing the application to confirm it is connected to the intended network.
+pub trait ChainIdProvider {
+    /// Fetches the chain ID reported by the connected node.
+    ///
+    /// # Returns
+    ///
+    /// A future resolving to the chain ID, or an error if it could not be
+    /// retrieved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node could not be reached.
+    fn fetch_chain_id(&self) -> impl Future<Output = Result<u64>> + Send;
+}
@@ -4,10 +4,20 @@
 //! communicate with external systems. These are contracts that must be
 //! implemented by infrastructure adapters.
 
+pub mod chain_id_provider;
+pub mod source_audit_log;
+pub mod source_repository;
+pub mod supply_event_notifier;
+pub mod token_metadata_cache;
 pub mod token_metadata_provider;
 pub mod token_supply_provider;
 pub mod token_supply_repository;
 
+pub use chain_id_provider::ChainIdProvider;
+pub use source_audit_log::SourceAuditLog;
+pub use source_repository::SourceRepository;
+pub use supply_event_notifier::SupplyEventNotifier;
+pub use token_metadata_cache::TokenMetadataCache;
 pub use token_metadata_provider::TokenMetadataProvider;
 pub use token_supply_provider::TokenSupplyProvider;
 pub use token_supply_repository::TokenSupplyRepository;
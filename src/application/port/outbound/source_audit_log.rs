@@ -0,0 +1,22 @@
+use anyhow::Result;
+
+use crate::domain::model::SourceChange;
+
+/// Append-only log of changes made to the excluded sources.
+pub trait SourceAuditLog {
+    /// Appends a change to the log.
+    ///
+    /// # Arguments
+    ///
+    /// * `change` - The change to record.
+    ///
+    /// # Returns
+    ///
+    /// A future resolving to a success indicator, or an error if the operation
+    /// failed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the change could not be recorded.
+    fn record(&self, change: &SourceChange) -> impl Future<Output = Result<()>> + Send;
+}
@@ -0,0 +1,36 @@
+use anyhow::Result;
+
+use crate::domain::model::Source;
+
+/// Repository trait for the list of excluded sources.
+///
+/// Sources changed at runtime are saved here so that they survive a restart.
+pub trait SourceRepository {
+    /// Loads the saved sources.
+    ///
+    /// # Returns
+    ///
+    /// A future resolving to the saved sources, `None` if they have never been
+    /// saved, or an error if they could not be read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if saved sources exist but could not be read.
+    fn load(&self) -> impl Future<Output = Result<Option<Vec<Source>>>> + Send;
+
+    /// Replaces the saved sources.
+    ///
+    /// # Arguments
+    ///
+    /// * `sources` - The complete list of sources to save.
+    ///
+    /// # Returns
+    ///
+    /// A future resolving to a success indicator, or an error if the operation
+    /// failed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sources could not be saved.
+    fn save(&self, sources: &[Source]) -> impl Future<Output = Result<()>> + Send;
+}
@@ -0,0 +1,22 @@
+use anyhow::Result;
+
+use crate::domain::model::SupplyEvent;
+
+/// Delivers [`SupplyEvent`]s to operators.
+pub trait SupplyEventNotifier {
+    /// Delivers an event to every subscriber interested in it.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The event to deliver.
+    ///
+    /// # Returns
+    ///
+    /// A future resolving to a success indicator, or an error if the event
+    /// could not be delivered to every subscriber.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any delivery failed after retrying.
+    fn notify(&self, event: &SupplyEvent) -> impl Future<Output = Result<()>> + Send;
+}
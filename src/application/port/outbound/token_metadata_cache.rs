@@ -0,0 +1,44 @@
+use anyhow::Result;
+
+use crate::domain::model::{ChainAddress, Token};
+
+/// Cache trait for previously fetched token metadata.
+///
+/// Token metadata never changes once a token is deployed, so a cached copy
+/// allows later startups to skip the external lookup entirely.
+pub trait TokenMetadataCache {
+    /// Loads the cached metadata of a token.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_address` - The address of the token to look up.
+    ///
+    /// # Returns
+    ///
+    /// A future resolving to the cached [`Token`], `None` if the token is not
+    /// cached, or an error if the cache could not be read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache exists but could not be read.
+    fn load(
+        &self,
+        token_address: ChainAddress,
+    ) -> impl Future<Output = Result<Option<Token>>> + Send;
+
+    /// Stores the metadata of a token in the cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The token metadata to cache.
+    ///
+    /// # Returns
+    ///
+    /// A future resolving to a success indicator, or an error if the operation
+    /// failed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache could not be written.
+    fn store(&self, token: &Token) -> impl Future<Output = Result<()>> + Send;
+}
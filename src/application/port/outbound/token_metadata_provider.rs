@@ -1,7 +1,6 @@
-use alloy::primitives::Address;
 use anyhow::Result;
 
-use crate::domain::model::Token;
+use crate::domain::model::{ChainAddress, Token};
 
 /// Defines a capability to fetch token metadata from an external source.
 ///
@@ -24,6 +23,6 @@ pub trait TokenMetadataProvider {
     /// Returns an error if the data could not be retrieved.
     fn fetch_token_metadata(
         &self,
-        token_address: Address,
+        token_address: ChainAddress,
     ) -> impl Future<Output = Result<Token>> + Send;
 }
@@ -2,6 +2,16 @@
 //!
 //! This module contains application services that coordinate domain operations.
 
+pub mod source_use_case;
+pub mod supply_adjuster;
+pub mod supply_event_detector;
+pub mod supply_guard;
+pub mod token_metadata_use_case;
 pub mod token_supply_use_case;
 
+pub use source_use_case::SourceUseCase;
+pub use supply_adjuster::SupplyAdjuster;
+pub use supply_event_detector::SupplyEventDetector;
+pub use supply_guard::SupplyGuard;
+pub use token_metadata_use_case::TokenMetadataUseCase;
 pub use token_supply_use_case::TokenSupplyUseCase;
@@ -0,0 +1,564 @@
+use anyhow::{Context, Result};
+use tokio::sync::RwLock;
+use tracing::{error, info, warn};
+
+use crate::application::port::inbound::SourceService;
+use crate::application::port::outbound::{SourceAuditLog, SourceRepository};
+use crate::domain::error::SourceError;
+use crate::domain::model::{AddressKind, ChainAddress, Source, SourceAction, SourceChange};
+
+/// Default implementation of the [`SourceService`].
+///
+/// This service keeps the live list of excluded sources in memory, saves every
+/// change to a source repository and then records it in an audit log. A change
+/// that cannot be recorded is rolled back and rejected, so none goes unaudited.
+pub struct SourceUseCase<R, A> {
+    repository: R,
+    audit_log: A,
+    sources: RwLock<Vec<Source>>,
+    address_kind: Option<AddressKind>,
+    token_address: Option<ChainAddress>,
+}
+
+impl<R, A> SourceUseCase<R, A>
+where
+    R: SourceRepository,
+    A: SourceAuditLog,
+{
+    /// Creates a new [`SourceUseCase`], restoring the saved sources.
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - Storage for the excluded sources.
+    /// * `audit_log` - Log that every change is recorded in.
+    /// * `configured` - The sources to use if none have been saved yet.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of [`SourceUseCase`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if saved sources exist but could not be read. Falling
+    /// back to the configured sources would silently undo runtime changes.
+    pub async fn load(repository: R, audit_log: A, configured: Vec<Source>) -> Result<Self> {
+        let sources = match repository.load().await? {
+            Some(saved) => {
+                info!(
+                    "Using {} saved excluded source(s) instead of the configured list",
+                    saved.len()
+                );
+                warn_if_diverged(&configured, &saved);
+                saved
+            }
+            None => configured,
+        };
+
+        Ok(Self {
+            repository,
+            audit_log,
+            sources: RwLock::new(sources),
+            address_kind: None,
+            token_address: None,
+        })
+    }
+
+    /// Only accepts new sources with addresses of the given kind.
+    ///
+    /// # Arguments
+    ///
+    /// * `address_kind` - The kind of address the supply provider reads.
+    ///
+    /// # Returns
+    ///
+    /// The use case, rejecting addresses of any other kind.
+    pub fn with_address_kind(mut self, address_kind: AddressKind) -> Self {
+        self.address_kind = Some(address_kind);
+        self
+    }
+
+    /// Rejects new sources at the address of the token itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_address` - The address of the token the supply is read for.
+    ///
+    /// # Returns
+    ///
+    /// The use case, rejecting the token's own address as a source.
+    pub fn with_token_address(mut self, token_address: ChainAddress) -> Self {
+        self.token_address = Some(token_address);
+        self
+    }
+
+    /// Saves `updated`, records `change` and, once both succeed, makes
+    /// `updated` the live list. If the change cannot be recorded, the
+    /// previous list is saved again.
+    /// Checks the loaded sources as if each had been added through
+    /// [`SourceService::add_source`].
+    ///
+    /// Call this once the use case is configured, since the checks depend on
+    /// [`Self::with_address_kind`] and [`Self::with_token_address`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the first source that would be rejected.
+    pub async fn validate(&self) -> Result<()> {
+        let sources = self.sources.read().await;
+
+        for (index, source) in sources.iter().enumerate() {
+            let result = match sources[..index].iter().any(|s| s.address == source.address) {
+                true => Err(SourceError::Duplicate(source.address.clone())),
+                false => self.check(source),
+            };
+
+            result.with_context(|| format!("excluded source `{}` is invalid", source.name))?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks a source against every rule except uniqueness.
+    fn check(&self, source: &Source) -> Result<(), SourceError> {
+        if source.name.trim().is_empty() {
+            return Err(SourceError::EmptyName);
+        }
+
+        if let Some(expected) = self.address_kind
+            && source.address.kind() != expected
+        {
+            return Err(SourceError::UnsupportedAddress {
+                address: source.address.clone(),
+                expected,
+            });
+        }
+
+        if source.address.is_zero() {
+            return Err(SourceError::ZeroAddress(source.address.clone()));
+        }
+
+        if self.token_address.as_ref() == Some(&source.address) {
+            return Err(SourceError::TokenAddress(source.address.clone()));
+        }
+
+        Ok(())
+    }
+
+    async fn commit(
+        &self,
+        sources: &mut Vec<Source>,
+        updated: Vec<Source>,
+        change: &SourceChange,
+    ) -> Result<()> {
+        self.repository.save(&updated).await?;
+
+        if let Err(e) = self.audit_log.record(change).await {
+            if let Err(rollback) = self.repository.save(sources).await {
+                error!(
+                    "Failed to restore excluded sources after `{}` went unrecorded: {:#}",
+                    change, rollback
+                );
+            }
+
+            return Err(e)
+                .with_context(|| format!("failed to record excluded source change `{}`", change));
+        }
+
+        info!("Excluded sources changed: {}", change);
+        *sources = updated;
+
+        Ok(())
+    }
+}
+
+/// Warns when the configured sources no longer match the saved ones, since
+/// edits to `excluded_sources` have no effect while a saved list exists.
+fn warn_if_diverged(configured: &[Source], saved: &[Source]) {
+    let only_configured: Vec<_> = configured.iter().filter(|s| !saved.contains(s)).collect();
+    let only_saved: Vec<_> = saved.iter().filter(|s| !configured.contains(s)).collect();
+
+    if only_configured.is_empty() && only_saved.is_empty() {
+        return;
+    }
+
+    let names = |sources: &[&Source]| {
+        sources
+            .iter()
+            .map(|s| format!("{} ({})", s.name, s.address))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    warn!(
+        "The configured excluded sources are ignored because a saved list exists. \
+         Only configured: [{}]. Only saved: [{}]. Delete the saved list to use the \
+         configured one.",
+        names(&only_configured),
+        names(&only_saved)
+    );
+}
+
+impl<R, A> SourceService for SourceUseCase<R, A>
+where
+    R: SourceRepository + Send + Sync,
+    A: SourceAuditLog + Send + Sync,
+{
+    async fn list_sources(&self) -> Vec<Source> {
+        self.sources.read().await.clone()
+    }
+
+    async fn add_source(&self, source: Source, actor: &str) -> Result<()> {
+        let source = Source {
+            name: source.name.trim().to_string(),
+            address: source.address,
+        };
+
+        self.check(&source)?;
+
+        let mut sources = self.sources.write().await;
+
+        if sources.iter().any(|s| s.address == source.address) {
+            return Err(SourceError::Duplicate(source.address).into());
+        }
+
+        let mut updated = sources.clone();
+        updated.push(source.clone());
+
+        let change = SourceChange::now(actor, SourceAction::Added, source);
+        self.commit(&mut sources, updated, &change).await
+    }
+
+    async fn remove_source(&self, address: ChainAddress, actor: &str) -> Result<Source> {
+        let mut sources = self.sources.write().await;
+
+        let Some(index) = sources.iter().position(|s| s.address == address) else {
+            return Err(SourceError::NotFound(address).into());
+        };
+
+        let mut updated = sources.clone();
+        let removed = updated.remove(index);
+
+        let change = SourceChange::now(actor, SourceAction::Removed, removed.clone());
+        self.commit(&mut sources, updated, &change).await?;
+
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use alloy::primitives::{Address, address};
+    use anyhow::anyhow;
+
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Mock Implementations for Testing
+
+    #[derive(Default)]
+    struct MockRepository {
+        saved: Arc<Mutex<Option<Vec<Source>>>>,
+        save_should_fail: bool,
+    }
+
+    impl SourceRepository for MockRepository {
+        async fn load(&self) -> Result<Option<Vec<Source>>> {
+            Ok(self.saved.lock().unwrap().clone())
+        }
+
+        async fn save(&self, sources: &[Source]) -> Result<()> {
+            match self.save_should_fail {
+                true => Err(anyhow!("Simulated save failure")),
+                false => {
+                    *self.saved.lock().unwrap() = Some(sources.to_vec());
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct MockAuditLog {
+        changes: Arc<Mutex<Vec<SourceChange>>>,
+        record_should_fail: bool,
+    }
+
+    impl SourceAuditLog for MockAuditLog {
+        async fn record(&self, change: &SourceChange) -> Result<()> {
+            if self.record_should_fail {
+                return Err(anyhow!("Simulated audit failure"));
+            }
+
+            self.changes.lock().unwrap().push(change.clone());
+            Ok(())
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Test Helper Functions
+
+    const SINK: ChainAddress =
+        ChainAddress::Evm(address!("0xB1a932A665FB0A1D5d7979cd63e80a59EDCe31B4"));
+    const TREASURY: ChainAddress =
+        ChainAddress::Evm(address!("0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118"));
+
+    fn source(name: &str, address: ChainAddress) -> Source {
+        Source {
+            name: name.into(),
+            address,
+        }
+    }
+
+    async fn create_use_case(
+        repository: MockRepository,
+    ) -> SourceUseCase<MockRepository, MockAuditLog> {
+        SourceUseCase::load(
+            repository,
+            MockAuditLog::default(),
+            vec![source("Sink", SINK)],
+        )
+        .await
+        .unwrap()
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[tokio::test]
+    async fn load_prefers_saved_sources_over_configured() {
+        // Arrange.
+        let repository = MockRepository::default();
+        *repository.saved.lock().unwrap() = Some(vec![source("Treasury", TREASURY)]);
+
+        // Act.
+        let use_case = create_use_case(repository).await;
+
+        // Assert.
+        assert_eq!(
+            use_case.list_sources().await,
+            vec![source("Treasury", TREASURY)]
+        );
+    }
+
+    #[tokio::test]
+    async fn validate_accepts_the_configured_sources() {
+        // Arrange.
+        let use_case = create_use_case(MockRepository::default())
+            .await
+            .with_address_kind(AddressKind::Evm)
+            .with_token_address(TREASURY);
+
+        // Act.
+        let result = use_case.validate().await;
+
+        // Assert.
+        assert!(result.is_ok(), "Expected valid sources: {:?}", result);
+    }
+
+    #[tokio::test]
+    async fn validate_rejects_saved_sources_that_could_not_be_added() {
+        // Arrange.
+        let repository = MockRepository::default();
+        *repository.saved.lock().unwrap() = Some(vec![source("Token", TREASURY)]);
+        let use_case = create_use_case(repository)
+            .await
+            .with_token_address(TREASURY);
+
+        // Act.
+        let result = use_case.validate().await;
+
+        // Assert.
+        assert_eq!(
+            result.unwrap_err().downcast_ref::<SourceError>(),
+            Some(&SourceError::TokenAddress(TREASURY))
+        );
+    }
+
+    #[tokio::test]
+    async fn validate_rejects_duplicate_sources() {
+        // Arrange.
+        let repository = MockRepository::default();
+        *repository.saved.lock().unwrap() = Some(vec![source("Sink", SINK), source("Copy", SINK)]);
+        let use_case = create_use_case(repository).await;
+
+        // Act.
+        let result = use_case.validate().await;
+
+        // Assert.
+        assert_eq!(
+            result.unwrap_err().downcast_ref::<SourceError>(),
+            Some(&SourceError::Duplicate(SINK))
+        );
+    }
+
+    #[tokio::test]
+    async fn add_source_saves_and_records_the_change() {
+        // Arrange.
+        let repository = MockRepository::default();
+        let saved = repository.saved.clone();
+        let use_case = create_use_case(repository).await;
+        let changes = use_case.audit_log.changes.clone();
+
+        // Act.
+        use_case
+            .add_source(source(" Treasury ", TREASURY), "ops")
+            .await
+            .unwrap();
+
+        // Assert.
+        let expected = vec![source("Sink", SINK), source("Treasury", TREASURY)];
+        assert_eq!(use_case.list_sources().await, expected);
+        assert_eq!(saved.lock().unwrap().as_ref(), Some(&expected));
+
+        let changes = changes.lock().unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].actor, "ops");
+        assert_eq!(changes[0].action, SourceAction::Added);
+        assert_eq!(changes[0].source, source("Treasury", TREASURY));
+    }
+
+    #[tokio::test]
+    async fn add_source_rejects_duplicates_and_empty_names() {
+        // Arrange.
+        let use_case = create_use_case(MockRepository::default()).await;
+
+        // Act.
+        let duplicate = use_case.add_source(source("Copy", SINK), "ops").await;
+        let unnamed = use_case.add_source(source(" ", TREASURY), "ops").await;
+
+        // Assert.
+        assert_eq!(
+            duplicate.unwrap_err().downcast_ref::<SourceError>(),
+            Some(&SourceError::Duplicate(SINK))
+        );
+        assert_eq!(
+            unnamed.unwrap_err().downcast_ref::<SourceError>(),
+            Some(&SourceError::EmptyName)
+        );
+        assert_eq!(use_case.list_sources().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn add_source_rejects_addresses_of_another_chain() {
+        // Arrange.
+        let use_case = create_use_case(MockRepository::default())
+            .await
+            .with_address_kind(AddressKind::Evm);
+        let solana: ChainAddress = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"
+            .parse()
+            .unwrap();
+
+        // Act.
+        let result = use_case
+            .add_source(source("Treasury", solana.clone()), "ops")
+            .await;
+
+        // Assert.
+        assert_eq!(
+            result.unwrap_err().downcast_ref::<SourceError>(),
+            Some(&SourceError::UnsupportedAddress {
+                address: solana,
+                expected: AddressKind::Evm,
+            })
+        );
+        assert_eq!(use_case.list_sources().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn add_source_rejects_the_zero_and_token_addresses() {
+        // Arrange.
+        let use_case = create_use_case(MockRepository::default())
+            .await
+            .with_token_address(TREASURY);
+        let zero = ChainAddress::Evm(Address::ZERO);
+
+        // Act.
+        let zero_result = use_case
+            .add_source(source("Burn", zero.clone()), "ops")
+            .await;
+        let token_result = use_case.add_source(source("Token", TREASURY), "ops").await;
+
+        // Assert.
+        assert_eq!(
+            zero_result.unwrap_err().downcast_ref::<SourceError>(),
+            Some(&SourceError::ZeroAddress(zero))
+        );
+        assert_eq!(
+            token_result.unwrap_err().downcast_ref::<SourceError>(),
+            Some(&SourceError::TokenAddress(TREASURY))
+        );
+        assert_eq!(use_case.list_sources().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn remove_source_returns_the_removed_source() {
+        // Arrange.
+        let use_case = create_use_case(MockRepository::default()).await;
+        let changes = use_case.audit_log.changes.clone();
+
+        // Act.
+        let removed = use_case.remove_source(SINK, "ops").await.unwrap();
+        let missing = use_case.remove_source(SINK, "ops").await;
+
+        // Assert.
+        assert_eq!(removed, source("Sink", SINK));
+        assert!(use_case.list_sources().await.is_empty());
+        assert_eq!(
+            missing.unwrap_err().downcast_ref::<SourceError>(),
+            Some(&SourceError::NotFound(SINK))
+        );
+        assert_eq!(changes.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn failed_save_leaves_sources_unchanged() {
+        // Arrange.
+        let repository = MockRepository {
+            save_should_fail: true,
+            ..Default::default()
+        };
+        let use_case = create_use_case(repository).await;
+        let changes = use_case.audit_log.changes.clone();
+
+        // Act.
+        let result = use_case
+            .add_source(source("Treasury", TREASURY), "ops")
+            .await;
+
+        // Assert.
+        assert!(result.is_err(), "Expected error when saving fails");
+        assert_eq!(use_case.list_sources().await, vec![source("Sink", SINK)]);
+        assert!(changes.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn failed_audit_rolls_back_the_change() {
+        // Arrange.
+        let repository = MockRepository::default();
+        let saved = repository.saved.clone();
+        let audit_log = MockAuditLog {
+            record_should_fail: true,
+            ..Default::default()
+        };
+        let use_case = SourceUseCase::load(repository, audit_log, vec![source("Sink", SINK)])
+            .await
+            .unwrap();
+
+        // Act.
+        let added = use_case
+            .add_source(source("Treasury", TREASURY), "ops")
+            .await;
+        let removed = use_case.remove_source(SINK, "ops").await;
+
+        // Assert.
+        assert!(added.is_err(), "Expected error when recording fails");
+        assert!(removed.is_err(), "Expected error when recording fails");
+        assert_eq!(use_case.list_sources().await, vec![source("Sink", SINK)]);
+        assert_eq!(
+            saved.lock().unwrap().as_ref(),
+            Some(&vec![source("Sink", SINK)])
+        );
+    }
+}
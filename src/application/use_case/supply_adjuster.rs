@@ -0,0 +1,184 @@
+use alloy::primitives::{I256, utils};
+use anyhow::{Result, anyhow};
+use chrono::NaiveDate;
+
+use crate::domain::error::SupplyRejection;
+use crate::domain::model::{AppliedAdjustment, SupplyAdjustment, TokenSupply};
+
+/// Applies the configured manual adjustments to a fetched supply.
+///
+/// Adjustments are applied after the excluded balances were subtracted, and
+/// only change the circulating supply. Each one that is active on the day of
+/// the fetch is recorded on the supply, so the published figures can be
+/// traced back to them.
+#[derive(Debug, Clone, Default)]
+pub struct SupplyAdjuster {
+    adjustments: Vec<SupplyAdjustment>,
+}
+
+impl SupplyAdjuster {
+    /// Creates a new [`SupplyAdjuster`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `adjustments` - The configured manual adjustments.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`SupplyAdjuster`] instance.
+    pub fn new(adjustments: Vec<SupplyAdjustment>) -> Self {
+        Self { adjustments }
+    }
+
+    /// Applies the adjustments that are active on the given day.
+    ///
+    /// # Arguments
+    ///
+    /// * `supply` - The supply as computed from the chain.
+    /// * `decimals` - The number of decimals the token uses.
+    /// * `day` - The day of the fetch, in UTC.
+    ///
+    /// # Returns
+    ///
+    /// The adjusted supply.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an amount is invalid for the token, or a
+    /// [`SupplyRejection::Underflow`] if the adjustments remove more than the
+    /// circulating supply.
+    pub fn apply(&self, supply: TokenSupply, decimals: u8, day: NaiveDate) -> Result<TokenSupply> {
+        let mut net = I256::ZERO;
+        let mut applied = Vec::new();
+
+        for adjustment in self.adjustments.iter().filter(|a| a.is_active(day)) {
+            let raw_amount = adjustment
+                .raw_amount(decimals)
+                .map_err(|e| anyhow!("invalid adjustment `{}`: {}", adjustment.reason, e))?;
+
+            net = net
+                .checked_add(raw_amount)
+                .ok_or_else(|| anyhow!("adjustments overflow"))?;
+
+            applied.push(AppliedAdjustment {
+                reason: adjustment.reason.clone(),
+                amount: utils::format_units(raw_amount, decimals)?,
+                raw_amount,
+                effective_from: adjustment.effective_from,
+                expires_on: adjustment.expires_on,
+            });
+        }
+
+        if applied.is_empty() {
+            return Ok(supply);
+        }
+
+        let total = supply.raw_total_supply;
+        let circulating = supply.raw_circulating_supply;
+        let removed = net.unsigned_abs();
+
+        let adjusted = match net.is_negative() {
+            true => circulating.saturating_add(removed),
+            false => circulating
+                .checked_sub(removed)
+                .ok_or(SupplyRejection::Underflow {
+                    total,
+                    excluded: total.saturating_sub(circulating).saturating_add(removed),
+                })?,
+        };
+
+        let formatted = utils::format_units(adjusted, decimals)?;
+
+        Ok(TokenSupply {
+            circulating_supply: formatted,
+            raw_circulating_supply: adjusted,
+            ..supply
+        }
+        .with_adjustments(applied))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::U256;
+
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Test Helper Functions
+
+    fn day(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    fn adjustment(reason: &str, amount: &str, expires_on: Option<&str>) -> SupplyAdjustment {
+        SupplyAdjustment {
+            reason: reason.into(),
+            amount: amount.into(),
+            effective_from: day("2025-01-01"),
+            expires_on: expires_on.map(day),
+        }
+    }
+
+    fn create_supply() -> TokenSupply {
+        TokenSupply::new("1000.00", "900.00").with_raw(U256::from(100_000), U256::from(90_000))
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[test]
+    fn applies_active_adjustments_and_records_them() {
+        // Arrange.
+        let adjuster = SupplyAdjuster::new(vec![
+            adjustment("Custodian", "150.5", None),
+            adjustment("Exchange", "-50", None),
+            adjustment("Expired", "100", Some("2025-02-01")),
+        ]);
+
+        // Act.
+        let supply = adjuster
+            .apply(create_supply(), 2, day("2025-03-01"))
+            .unwrap();
+
+        // Assert.
+        assert_eq!(supply.total_supply, "1000.00");
+        assert_eq!(supply.circulating_supply, "799.50");
+        assert_eq!(supply.raw_circulating_supply, U256::from(79_950));
+        assert_eq!(supply.adjustments.len(), 2);
+        assert_eq!(supply.adjustments[0].amount, "150.50");
+        assert_eq!(supply.adjustments[1].amount, "-50.00");
+    }
+
+    #[test]
+    fn leaves_supply_untouched_without_active_adjustments() {
+        // Arrange.
+        let adjuster = SupplyAdjuster::new(vec![adjustment("Custodian", "150", None)]);
+
+        // Act.
+        let supply = adjuster
+            .apply(create_supply(), 2, day("2024-12-31"))
+            .unwrap();
+
+        // Assert.
+        assert_eq!(supply, create_supply());
+    }
+
+    #[test]
+    fn rejects_adjustments_that_remove_more_than_circulates() {
+        // Arrange.
+        let adjuster = SupplyAdjuster::new(vec![adjustment("Custodian", "901", None)]);
+
+        // Act.
+        let result = adjuster.apply(create_supply(), 2, day("2025-03-01"));
+
+        // Assert.
+        assert_eq!(
+            result.unwrap_err().downcast_ref::<SupplyRejection>(),
+            Some(&SupplyRejection::Underflow {
+                total: U256::from(100_000),
+                excluded: U256::from(100_100),
+            })
+        );
+    }
+}
@@ -0,0 +1,306 @@
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::domain::model::{SupplyEvent, TokenSupply};
+
+use super::supply_guard::change_bps;
+
+/// Turns the outcomes of supply refreshes into [`SupplyEvent`]s.
+///
+/// Each condition is reported once when it starts. Failures and staleness are
+/// followed by a single [`SupplyEvent::Recovered`] once a refresh succeeds
+/// again.
+#[derive(Debug, Clone)]
+pub struct SupplyEventDetector {
+    change_bps: Option<u64>,
+    failure_threshold: u32,
+    stale_after: TimeDelta,
+    previous: Option<TokenSupply>,
+    failures: u32,
+    reported_failing: bool,
+    reported_stale: bool,
+}
+
+impl SupplyEventDetector {
+    /// Creates a new [`SupplyEventDetector`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `change_percent` - The change between consecutive snapshots that is
+    ///   reported, or `None` to never report changes.
+    /// * `failure_threshold` - The number of consecutive failed refreshes that
+    ///   is reported.
+    /// * `stale_after` - How old the published snapshot may get before it is
+    ///   reported as stale.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`SupplyEventDetector`] instance.
+    pub fn new(
+        change_percent: Option<f64>,
+        failure_threshold: u32,
+        stale_after: TimeDelta,
+    ) -> Self {
+        Self {
+            change_bps: change_percent.map(|p| (p * 100.0).round() as u64),
+            failure_threshold: failure_threshold.max(1),
+            stale_after,
+            previous: None,
+            failures: 0,
+            reported_failing: false,
+            reported_stale: false,
+        }
+    }
+
+    /// Sets the snapshot that was published before detection started.
+    ///
+    /// Only pass a snapshot that was actually stored, not a placeholder.
+    ///
+    /// # Arguments
+    ///
+    /// * `previous` - The published snapshot.
+    ///
+    /// # Returns
+    ///
+    /// The detector, comparing the next refresh against `previous`.
+    pub fn with_previous(mut self, previous: TokenSupply) -> Self {
+        self.previous = Some(previous);
+        self
+    }
+
+    /// Records a successful refresh.
+    ///
+    /// # Arguments
+    ///
+    /// * `supply` - The newly published snapshot.
+    ///
+    /// # Returns
+    ///
+    /// The events raised by the refresh.
+    pub fn on_success(&mut self, supply: &TokenSupply) -> Vec<SupplyEvent> {
+        let mut events = Vec::new();
+
+        if self.reported_failing || self.reported_stale {
+            events.push(SupplyEvent::Recovered {
+                failures: self.failures,
+            });
+        }
+
+        self.failures = 0;
+        self.reported_failing = false;
+        self.reported_stale = false;
+
+        if let (Some(limit_bps), Some(previous)) = (self.change_bps, &self.previous)
+            && previous.token == supply.token
+        {
+            let figures = [
+                (
+                    "total",
+                    previous.raw_total_supply,
+                    supply.raw_total_supply,
+                    &previous.total_supply,
+                    &supply.total_supply,
+                ),
+                (
+                    "circulating",
+                    previous.raw_circulating_supply,
+                    supply.raw_circulating_supply,
+                    &previous.circulating_supply,
+                    &supply.circulating_supply,
+                ),
+            ];
+
+            for (field, before, after, previous, current) in figures {
+                match change_bps(before, after) {
+                    Some(change_bps) if change_bps > limit_bps => {
+                        events.push(SupplyEvent::SupplyChanged {
+                            field,
+                            previous: previous.clone(),
+                            current: current.clone(),
+                            change_bps,
+                        })
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        self.previous = Some(supply.clone());
+
+        events
+    }
+
+    /// Records a failed refresh.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - Why the refresh failed.
+    ///
+    /// # Returns
+    ///
+    /// A [`SupplyEvent::FetchFailing`] if this failure reached the threshold.
+    pub fn on_failure(&mut self, error: &str) -> Option<SupplyEvent> {
+        self.failures = self.failures.saturating_add(1);
+
+        if self.reported_failing || self.failures < self.failure_threshold {
+            return None;
+        }
+
+        self.reported_failing = true;
+
+        Some(SupplyEvent::FetchFailing {
+            consecutive_failures: self.failures,
+            error: error.to_string(),
+        })
+    }
+
+    /// Checks whether the published snapshot has gone stale.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time.
+    ///
+    /// # Returns
+    ///
+    /// A [`SupplyEvent::Stale`] the first time the snapshot is found stale.
+    pub fn check_stale(&mut self, now: DateTime<Utc>) -> Option<SupplyEvent> {
+        let previous = self.previous.as_ref()?;
+        let age = now - previous.fetched_at;
+
+        if self.reported_stale || age <= self.stale_after {
+            return None;
+        }
+
+        self.reported_stale = true;
+
+        Some(SupplyEvent::Stale {
+            fetched_at: previous.fetched_at,
+            age_seconds: age.num_seconds().max(0) as u64,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::U256;
+
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Test Helper Functions
+
+    fn create_supply(total: u64, circulating: u64) -> TokenSupply {
+        TokenSupply::new(total.to_string(), circulating.to_string())
+            .with_raw(U256::from(total), U256::from(circulating))
+    }
+
+    fn create_detector() -> SupplyEventDetector {
+        SupplyEventDetector::new(Some(10.0), 3, TimeDelta::seconds(600))
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[test]
+    fn reports_changes_above_the_threshold() {
+        // Arrange.
+        let mut detector = create_detector().with_previous(create_supply(1000, 800));
+
+        // Act.
+        let small = detector.on_success(&create_supply(1000, 850));
+        let large = detector.on_success(&create_supply(1000, 500));
+
+        // Assert.
+        assert!(small.is_empty());
+        assert_eq!(
+            large,
+            vec![SupplyEvent::SupplyChanged {
+                field: "circulating",
+                previous: "850".into(),
+                current: "500".into(),
+                change_bps: 4117,
+            }]
+        );
+    }
+
+    #[test]
+    fn ignores_changes_without_a_threshold() {
+        // Arrange.
+        let mut detector = SupplyEventDetector::new(None, 3, TimeDelta::seconds(600))
+            .with_previous(create_supply(1000, 800));
+
+        // Act.
+        let events = detector.on_success(&create_supply(2000, 100));
+
+        // Assert.
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn reports_failures_once_at_the_threshold() {
+        // Arrange.
+        let mut detector = create_detector();
+
+        // Act.
+        let events: Vec<_> = (0..5).map(|_| detector.on_failure("timeout")).collect();
+
+        // Assert.
+        assert_eq!(
+            events,
+            vec![
+                None,
+                None,
+                Some(SupplyEvent::FetchFailing {
+                    consecutive_failures: 3,
+                    error: "timeout".into(),
+                }),
+                None,
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_recovery_only_after_reported_failures() {
+        // Arrange.
+        let mut brief = create_detector();
+        brief.on_failure("timeout");
+
+        let mut prolonged = create_detector();
+        (0..4).for_each(|_| _ = prolonged.on_failure("timeout"));
+
+        // Act.
+        let brief = brief.on_success(&create_supply(1000, 800));
+        let prolonged = prolonged.on_success(&create_supply(1000, 800));
+
+        // Assert.
+        assert!(brief.is_empty());
+        assert_eq!(prolonged, vec![SupplyEvent::Recovered { failures: 4 }]);
+    }
+
+    #[test]
+    fn reports_staleness_once_until_recovered() {
+        // Arrange.
+        let supply = create_supply(1000, 800);
+        let fetched_at = supply.fetched_at;
+        let mut detector = create_detector().with_previous(supply);
+        let later = fetched_at + TimeDelta::seconds(601);
+
+        // Act.
+        let fresh = detector.check_stale(fetched_at + TimeDelta::seconds(600));
+        let stale = detector.check_stale(later);
+        let repeated = detector.check_stale(later);
+        let recovered = detector.on_success(&create_supply(1000, 800));
+
+        // Assert.
+        assert_eq!(fresh, None);
+        assert_eq!(
+            stale,
+            Some(SupplyEvent::Stale {
+                fetched_at,
+                age_seconds: 601,
+            })
+        );
+        assert_eq!(repeated, None);
+        assert_eq!(recovered, vec![SupplyEvent::Recovered { failures: 0 }]);
+    }
+}
@@ -0,0 +1,410 @@
+use std::sync::Mutex;
+
+use alloy::primitives::U256;
+use tracing::warn;
+
+use crate::domain::error::SupplyRejection;
+use crate::domain::model::TokenSupply;
+
+/// Hundredths of a percent in a whole.
+const BPS: u64 = 10_000;
+
+/// Checks that a fetched supply is plausible before it replaces the stored
+/// snapshot.
+///
+/// A supply is rejected if its excluded balances exceed the total, if its
+/// circulating supply exceeds the total, if the total is zero or, unless
+/// allowed, the circulating supply is zero while the total is not, or, when a
+/// limit is
+/// configured, if either figure moved further from the previous snapshot than
+/// the limit allows.
+///
+/// A genuine large move, such as a burn, is accepted as the new baseline once
+/// it has been rejected for the configured number of consecutive fetches that
+/// agree with each other within the limit.
+#[derive(Debug, Default)]
+pub struct SupplyGuard {
+    max_change_bps: Option<u64>,
+    confirmations: u32,
+    allow_zero_circulating: bool,
+    pending: Mutex<Option<PendingJump>>,
+}
+
+/// The figures of consecutive rejected jumps that agree with each other.
+#[derive(Debug, Clone, Copy)]
+struct PendingJump {
+    total: U256,
+    circulating: U256,
+    rejections: u32,
+}
+
+impl SupplyGuard {
+    /// Creates a new [`SupplyGuard`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_change_percent` - The largest change from the previous snapshot
+    ///   allowed, or `None` to allow any change.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`SupplyGuard`] instance.
+    pub fn new(max_change_percent: Option<f64>) -> Self {
+        Self {
+            max_change_bps: max_change_percent.map(|p| (p * 100.0).round() as u64),
+            confirmations: 0,
+            allow_zero_circulating: false,
+            pending: Mutex::new(None),
+        }
+    }
+
+    /// Accepts a jump as the new baseline once it has been rejected this many
+    /// times in a row, with figures that agree with each other within the
+    /// limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `confirmations` - The number of consecutive agreeing rejections, or
+    ///   `0` to keep rejecting the jump until the limit is raised.
+    ///
+    /// # Returns
+    ///
+    /// The guard, re-baselining after `confirmations` rejections.
+    pub fn with_confirmations(mut self, confirmations: u32) -> Self {
+        self.confirmations = confirmations;
+        self
+    }
+
+    /// Rejects a zero circulating supply, as a sign of a misreported balance.
+    ///
+    /// # Arguments
+    ///
+    /// * `reject` - Whether to reject it. Tokens that are fully locked or not
+    ///   yet launched legitimately report zero.
+    ///
+    /// # Returns
+    ///
+    /// The guard, rejecting a zero circulating supply if asked to.
+    pub fn with_reject_zero_circulating(mut self, reject: bool) -> Self {
+        self.allow_zero_circulating = !reject;
+        self
+    }
+
+    /// Checks a fetched supply against the previous snapshot.
+    ///
+    /// The change limit is not applied when there is no previous snapshot, or
+    /// when it was recorded for a different token. A jump that agrees with the
+    /// configured number of consecutive rejected ones is accepted.
+    ///
+    /// # Arguments
+    ///
+    /// * `previous` - The stored snapshot, if any.
+    /// * `supply` - The fetched supply.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`SupplyRejection`] explaining why the supply is
+    /// implausible.
+    pub fn check(
+        &self,
+        previous: Option<&TokenSupply>,
+        supply: &TokenSupply,
+    ) -> Result<(), SupplyRejection> {
+        let total = supply.raw_total_supply;
+        let excluded = supply
+            .breakdown
+            .iter()
+            .fold(U256::ZERO, |sum, b| sum.saturating_add(b.raw_balance));
+
+        if excluded > total {
+            return Err(SupplyRejection::Underflow { total, excluded });
+        }
+
+        if supply.raw_circulating_supply > total {
+            return Err(SupplyRejection::CirculatingExceedsTotal {
+                total,
+                circulating: supply.raw_circulating_supply,
+            });
+        }
+
+        if total.is_zero() {
+            return Err(SupplyRejection::Zero { field: "total" });
+        }
+
+        if supply.raw_circulating_supply.is_zero() && !self.allow_zero_circulating {
+            return Err(SupplyRejection::Zero {
+                field: "circulating",
+            });
+        }
+
+        let (Some(limit_bps), Some(previous)) = (self.max_change_bps, previous) else {
+            return Ok(());
+        };
+
+        if previous.version == 0 || previous.token != supply.token {
+            return Ok(());
+        }
+
+        let Err(rejection) = jump(limit_bps, previous, supply) else {
+            self.reset();
+            return Ok(());
+        };
+
+        if self.confirmations == 0 {
+            return Err(rejection);
+        }
+
+        let mut pending = self.pending.lock().unwrap();
+
+        let rejections = match *pending {
+            Some(p)
+                if within(limit_bps, p.total, total)
+                    && within(limit_bps, p.circulating, supply.raw_circulating_supply) =>
+            {
+                p.rejections
+            }
+            _ => 0,
+        };
+
+        if rejections >= self.confirmations {
+            *pending = None;
+
+            warn!(
+                "Accepting the supply as a new baseline after {} consecutive rejections: {}",
+                rejections, rejection
+            );
+
+            return Ok(());
+        }
+
+        *pending = Some(PendingJump {
+            total,
+            circulating: supply.raw_circulating_supply,
+            rejections: rejections + 1,
+        });
+
+        Err(rejection)
+    }
+
+    /// Forgets the rejected jumps counted towards re-baselining.
+    fn reset(&self) {
+        *self.pending.lock().unwrap() = None;
+    }
+}
+
+/// Checks that neither figure moved further from the previous snapshot than
+/// the limit allows.
+fn jump(
+    limit_bps: u64,
+    previous: &TokenSupply,
+    supply: &TokenSupply,
+) -> Result<(), SupplyRejection> {
+    let figures = [
+        ("total", previous.raw_total_supply, supply.raw_total_supply),
+        (
+            "circulating",
+            previous.raw_circulating_supply,
+            supply.raw_circulating_supply,
+        ),
+    ];
+
+    for (field, before, after) in figures {
+        let Some(change_bps) = change_bps(before, after) else {
+            continue;
+        };
+
+        if change_bps > limit_bps {
+            return Err(SupplyRejection::Jump {
+                field,
+                change_bps,
+                limit_bps,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns `true` if `after` is within the limit of `before`.
+fn within(limit_bps: u64, before: U256, after: U256) -> bool {
+    change_bps(before, after).is_none_or(|bps| bps <= limit_bps)
+}
+
+/// Returns how far `after` is from `before` in hundredths of a percent, or
+/// `None` if `before` is zero and there is nothing to compare against.
+pub(crate) fn change_bps(before: U256, after: U256) -> Option<u64> {
+    if before.is_zero() {
+        return None;
+    }
+
+    let difference = before.max(after) - before.min(after);
+    let bps = difference.saturating_mul(U256::from(BPS)) / before;
+
+    Some(bps.try_into().unwrap_or(u64::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use crate::domain::model::SourceBalance;
+
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Test Helper Functions
+
+    fn create_supply(total: u64, circulating: u64) -> TokenSupply {
+        TokenSupply::new(total.to_string(), circulating.to_string())
+            .with_raw(U256::from(total), U256::from(circulating))
+    }
+
+    fn create_previous(total: u64, circulating: u64) -> TokenSupply {
+        let mut supply = create_supply(total, circulating);
+        supply.version = 1;
+        supply
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[test]
+    fn accepts_plausible_supply() {
+        // Arrange.
+        let guard = SupplyGuard::new(Some(10.0));
+        let previous = create_previous(1000, 800);
+        let supply = create_supply(1000, 850);
+
+        // Act & Assert.
+        assert_eq!(guard.check(Some(&previous), &supply), Ok(()));
+    }
+
+    #[test]
+    fn rejects_excluded_balances_above_total() {
+        // Arrange.
+        let sink = address!("0xB1a932A665FB0A1D5d7979cd63e80a59EDCe31B4");
+        let supply = create_supply(1000, 0).with_breakdown(vec![
+            SourceBalance::new("Sink", sink, "1200").with_raw(U256::from(1200)),
+        ]);
+
+        // Act.
+        let result = SupplyGuard::default().check(None, &supply);
+
+        // Assert.
+        assert_eq!(
+            result,
+            Err(SupplyRejection::Underflow {
+                total: U256::from(1000),
+                excluded: U256::from(1200),
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_circulating_above_total() {
+        // Arrange.
+        let supply = create_supply(1000, 1001);
+
+        // Act.
+        let result = SupplyGuard::default().check(None, &supply);
+
+        // Assert.
+        assert!(matches!(
+            result,
+            Err(SupplyRejection::CirculatingExceedsTotal { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_jumps_above_limit() {
+        // Arrange.
+        let guard = SupplyGuard::new(Some(10.0));
+        let previous = create_previous(1000, 800);
+        let supply = create_supply(1000, 600);
+
+        // Act.
+        let result = guard.check(Some(&previous), &supply);
+
+        // Assert.
+        assert_eq!(
+            result,
+            Err(SupplyRejection::Jump {
+                field: "circulating",
+                change_bps: 2500,
+                limit_bps: 1000,
+            })
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "circulating supply changed by 25.00%, more than the 10.00% allowed"
+        );
+    }
+
+    #[test]
+    fn ignores_jumps_without_limit_or_baseline() {
+        // Arrange.
+        let unlimited = SupplyGuard::default();
+        let limited = SupplyGuard::new(Some(10.0));
+        let previous = create_previous(1000, 800);
+        let unstored = create_supply(10, 8);
+        let supply = create_supply(5000, 4000);
+
+        // Act & Assert.
+        assert_eq!(unlimited.check(Some(&previous), &supply), Ok(()));
+        assert_eq!(limited.check(Some(&unstored), &supply), Ok(()));
+        assert_eq!(limited.check(None, &supply), Ok(()));
+    }
+
+    #[test]
+    fn rejects_zero_figures_without_limit() {
+        // Act.
+        let zero_total = SupplyGuard::default().check(None, &create_supply(0, 0));
+        let zero_circulating = SupplyGuard::default().check(None, &create_supply(1000, 0));
+
+        // Assert.
+        assert_eq!(zero_total, Err(SupplyRejection::Zero { field: "total" }));
+        assert_eq!(
+            zero_circulating,
+            Err(SupplyRejection::Zero {
+                field: "circulating"
+            })
+        );
+    }
+
+    #[test]
+    fn accepts_zero_circulating_when_allowed() {
+        // Arrange.
+        let guard = SupplyGuard::default().with_reject_zero_circulating(false);
+
+        // Act.
+        let locked = guard.check(None, &create_supply(1000, 0));
+        let zero_total = guard.check(None, &create_supply(0, 0));
+
+        // Assert.
+        assert_eq!(locked, Ok(()));
+        assert_eq!(zero_total, Err(SupplyRejection::Zero { field: "total" }));
+    }
+
+    #[test]
+    fn accepts_a_confirmed_jump_as_new_baseline() {
+        // Arrange.
+        let guard = SupplyGuard::new(Some(10.0)).with_confirmations(2);
+        let previous = create_previous(1000, 800);
+        let burned = create_supply(500, 400);
+        let other = create_supply(200, 100);
+
+        // Act.
+        let first = guard.check(Some(&previous), &burned);
+        let outlier = guard.check(Some(&previous), &other);
+        let second = guard.check(Some(&previous), &burned);
+        let third = guard.check(Some(&previous), &burned);
+        let fourth = guard.check(Some(&previous), &burned);
+
+        // Assert.
+        assert!(first.is_err());
+        assert!(outlier.is_err(), "A disagreeing jump restarts the count");
+        assert!(second.is_err());
+        assert!(third.is_err());
+        assert_eq!(fourth, Ok(()));
+    }
+}
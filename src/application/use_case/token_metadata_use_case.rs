@@ -0,0 +1,268 @@
+use anyhow::Result;
+use tracing::{info, warn};
+
+use crate::application::port::inbound::TokenMetadataService;
+use crate::application::port::outbound::{TokenMetadataCache, TokenMetadataProvider};
+use crate::domain::model::{ChainAddress, Token, TokenMetadataOverrides};
+
+/// Default implementation of the [`TokenMetadataService`].
+///
+/// This service coordinates between a token metadata provider and a token
+/// metadata cache, applying configured overrides on top of either.
+pub struct TokenMetadataUseCase<M, C> {
+    provider: M,
+    cache: C,
+}
+
+impl<M, C> TokenMetadataUseCase<M, C>
+where
+    M: TokenMetadataProvider,
+    C: TokenMetadataCache,
+{
+    /// Creates a new [`TokenMetadataUseCase`].
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - Component that fetches token metadata.
+    /// * `cache` - Storage for previously fetched token metadata.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of [`TokenMetadataUseCase`].
+    pub fn new(provider: M, cache: C) -> Self {
+        Self { provider, cache }
+    }
+
+    async fn fetch_and_cache(&self, token_address: ChainAddress) -> Result<Token> {
+        let token = self.provider.fetch_token_metadata(token_address).await?;
+
+        if let Err(e) = self.cache.store(&token).await {
+            warn!("Failed to cache token metadata: {}", e);
+        }
+
+        Ok(token)
+    }
+}
+
+impl<M, C> TokenMetadataService for TokenMetadataUseCase<M, C>
+where
+    M: TokenMetadataProvider + Send + Sync,
+    C: TokenMetadataCache + Send + Sync,
+{
+    async fn resolve_token_metadata(
+        &self,
+        token_address: ChainAddress,
+        overrides: &TokenMetadataOverrides,
+    ) -> Result<Token> {
+        if let Some(token) = overrides.to_token(token_address.clone()) {
+            info!("Using configured token metadata: {}", token);
+            return Ok(token);
+        }
+
+        let cached = match self.cache.load(token_address.clone()).await {
+            Ok(cached) => cached,
+            Err(e) => {
+                warn!("Failed to read cached token metadata: {}", e);
+                None
+            }
+        };
+
+        let token = match cached {
+            Some(token) => {
+                info!("Using cached token metadata: {}", token);
+                token
+            }
+            None => self.fetch_and_cache(token_address).await?,
+        };
+
+        Ok(overrides.apply(token))
+    }
+
+    async fn verify_token_decimals(&self, token: &Token) -> Result<bool> {
+        let fetched = self.fetch_and_cache(token.address.clone()).await?;
+
+        if fetched.decimals != token.decimals {
+            warn!(
+                "On-chain decimals ({}) disagree with the resolved value ({}) for {}",
+                fetched.decimals, token.decimals, token.address
+            );
+
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use alloy::primitives::address;
+    use anyhow::anyhow;
+
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Mock Implementations for Testing
+
+    struct MockProvider {
+        should_fail: bool,
+        decimals: u8,
+        fetch_count: Arc<AtomicU32>,
+    }
+
+    impl MockProvider {
+        fn new(should_fail: bool, decimals: u8) -> Self {
+            Self {
+                should_fail,
+                decimals,
+                fetch_count: Arc::new(AtomicU32::new(0)),
+            }
+        }
+    }
+
+    impl TokenMetadataProvider for MockProvider {
+        async fn fetch_token_metadata(&self, token_address: ChainAddress) -> Result<Token> {
+            self.fetch_count.fetch_add(1, Ordering::SeqCst);
+
+            match self.should_fail {
+                true => Err(anyhow!("Simulated fetch failure")),
+                false => Ok(Token::new("Supply", "SUPPLY", token_address, self.decimals)),
+            }
+        }
+    }
+
+    struct MockCache {
+        cached: Arc<Mutex<Option<Token>>>,
+    }
+
+    impl MockCache {
+        fn new(cached: Option<Token>) -> Self {
+            Self {
+                cached: Arc::new(Mutex::new(cached)),
+            }
+        }
+    }
+
+    impl TokenMetadataCache for MockCache {
+        async fn load(&self, token_address: ChainAddress) -> Result<Option<Token>> {
+            let cached = self.cached.lock().unwrap();
+            Ok(cached.clone().filter(|t| t.address == token_address))
+        }
+
+        async fn store(&self, token: &Token) -> Result<()> {
+            *self.cached.lock().unwrap() = Some(token.clone());
+            Ok(())
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Test Helper Functions
+
+    const TOKEN: ChainAddress =
+        ChainAddress::Evm(address!("0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118"));
+
+    fn create_token(decimals: u8) -> Token {
+        Token::new("Supply", "SUPPLY", TOKEN, decimals)
+    }
+
+    fn complete_overrides() -> TokenMetadataOverrides {
+        TokenMetadataOverrides {
+            name: Some("Configured".into()),
+            symbol: Some("CONF".into()),
+            decimals: Some(6),
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[tokio::test]
+    async fn complete_overrides_skip_the_provider() {
+        // Arrange.
+        let provider = MockProvider::new(true, 18);
+        let fetch_count = provider.fetch_count.clone();
+        let service = TokenMetadataUseCase::new(provider, MockCache::new(None));
+
+        // Act.
+        let token = service
+            .resolve_token_metadata(TOKEN, &complete_overrides())
+            .await
+            .unwrap();
+
+        // Assert.
+        assert_eq!(token, Token::new("Configured", "CONF", TOKEN, 6));
+        assert_eq!(fetch_count.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn cached_metadata_skips_the_provider() {
+        // Arrange.
+        let provider = MockProvider::new(true, 18);
+        let fetch_count = provider.fetch_count.clone();
+        let cache = MockCache::new(Some(create_token(18)));
+        let service = TokenMetadataUseCase::new(provider, cache);
+
+        // Act.
+        let token = service
+            .resolve_token_metadata(TOKEN, &TokenMetadataOverrides::default())
+            .await
+            .unwrap();
+
+        // Assert.
+        assert_eq!(token, create_token(18));
+        assert_eq!(fetch_count.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn fetched_metadata_is_cached_and_overridden() {
+        // Arrange.
+        let cache = MockCache::new(None);
+        let cached = cache.cached.clone();
+        let service = TokenMetadataUseCase::new(MockProvider::new(false, 18), cache);
+        let overrides = TokenMetadataOverrides {
+            symbol: Some("SPLY".into()),
+            ..Default::default()
+        };
+
+        // Act.
+        let token = service
+            .resolve_token_metadata(TOKEN, &overrides)
+            .await
+            .unwrap();
+
+        // Assert.
+        assert_eq!(token, Token::new("Supply", "SPLY", TOKEN, 18));
+        assert_eq!(*cached.lock().unwrap(), Some(create_token(18)));
+    }
+
+    #[tokio::test]
+    async fn resolve_propagates_provider_errors_without_cache() {
+        // Arrange.
+        let service = TokenMetadataUseCase::new(MockProvider::new(true, 18), MockCache::new(None));
+
+        // Act.
+        let result = service
+            .resolve_token_metadata(TOKEN, &TokenMetadataOverrides::default())
+            .await;
+
+        // Assert.
+        assert!(result.is_err(), "Expected error when provider fails");
+    }
+
+    #[tokio::test]
+    async fn verify_token_decimals_detects_disagreement() {
+        // Arrange.
+        let service = TokenMetadataUseCase::new(MockProvider::new(false, 18), MockCache::new(None));
+
+        // Act.
+        let matching = service.verify_token_decimals(&create_token(18)).await;
+        let differing = service.verify_token_decimals(&create_token(6)).await;
+
+        // Assert.
+        assert!(matching.unwrap(), "Expected matching decimals");
+        assert!(!differing.unwrap(), "Expected differing decimals");
+    }
+}
@@ -1,17 +1,26 @@
 use anyhow::Result;
+use chrono::Utc;
 
 use crate::application::port::inbound::TokenSupplyService;
 use crate::application::port::outbound::TokenSupplyProvider;
 use crate::application::port::outbound::TokenSupplyRepository;
 use crate::domain::model::{Source, Token, TokenSupply};
 
+use super::{SupplyAdjuster, SupplyGuard};
+
 /// Default implementation of the [`TokenSupplyService`].
 ///
 /// This service coordinates between a token supply provider and a token supply
 /// repository to manage token supply information.
+///
+/// Fetched supplies have the manual adjustments of a [`SupplyAdjuster`]
+/// applied. Updates are checked by a [`SupplyGuard`] first, and an implausible
+/// supply is rejected so that the stored snapshot stays in place.
 pub struct TokenSupplyUseCase<S, R> {
     provider: S,
     repository: R,
+    guard: SupplyGuard,
+    adjuster: SupplyAdjuster,
 }
 
 impl<S, R> TokenSupplyUseCase<S, R>
@@ -33,8 +42,39 @@ where
         Self {
             provider,
             repository,
+            guard: SupplyGuard::default(),
+            adjuster: SupplyAdjuster::default(),
         }
     }
+
+    /// Checks updates with the given guard instead of the default one, which
+    /// allows any change between snapshots.
+    ///
+    /// # Arguments
+    ///
+    /// * `guard` - The checks a supply must pass before it is stored.
+    ///
+    /// # Returns
+    ///
+    /// The service, checking updates with `guard`.
+    pub fn with_guard(mut self, guard: SupplyGuard) -> Self {
+        self.guard = guard;
+        self
+    }
+
+    /// Applies the given manual adjustments to every fetched supply.
+    ///
+    /// # Arguments
+    ///
+    /// * `adjuster` - The manual adjustments to apply.
+    ///
+    /// # Returns
+    ///
+    /// The service, applying the adjustments of `adjuster`.
+    pub fn with_adjuster(mut self, adjuster: SupplyAdjuster) -> Self {
+        self.adjuster = adjuster;
+        self
+    }
 }
 
 impl<S, R> TokenSupplyService for TokenSupplyUseCase<S, R>
@@ -43,10 +83,21 @@ where
     R: TokenSupplyRepository + Send + Sync,
 {
     async fn fetch_token_supply(&self, token: &Token, sources: &[Source]) -> Result<TokenSupply> {
-        self.provider.fetch_token_supply(token, sources).await
+        let supply = self.provider.fetch_token_supply(token, sources).await?;
+        let now = Utc::now();
+        let supply = self
+            .adjuster
+            .apply(supply, token.decimals, now.date_naive())?;
+
+        Ok(supply.with_token(token.clone()).with_fetched_at(now))
     }
 
-    async fn update_token_supply(&self, supply: TokenSupply) -> Result<()> {
+    async fn update_token_supply(&self, mut supply: TokenSupply) -> Result<()> {
+        let previous = self.repository.get_current().await.ok();
+        self.guard.check(previous.as_ref(), &supply)?;
+
+        supply.version = previous.map_or(0, |s| s.version) + 1;
+
         self.repository.store(supply).await
     }
 
@@ -59,10 +110,12 @@ where
 mod tests {
     use std::sync::{Arc, Mutex};
 
-    use alloy::primitives::address;
+    use alloy::primitives::{U256, address};
     use anyhow::anyhow;
 
     use crate::application::port::outbound::TokenSupplyProvider;
+    use crate::domain::error::SupplyRejection;
+    use crate::domain::model::SupplyAdjustment;
 
     use super::*;
 
@@ -147,6 +200,7 @@ mod tests {
         Token::new(name, symbol, address, decimals)
     }
 
+    #[allow(clippy::let_and_return)]
     fn create_test_service(
         provider_should_fail: bool,
         repo_should_fail: bool,
@@ -184,6 +238,30 @@ mod tests {
         assert!(result.is_ok(), "Expected successful supply fetch");
         let supply = result.unwrap();
         assert_supply_values(&supply, total, circulating);
+        assert_eq!(supply.token, Some(token));
+    }
+
+    #[tokio::test]
+    async fn fetch_token_supply_applies_adjustments() {
+        // Arrange.
+        let adjustment = SupplyAdjustment {
+            reason: "Released by custodian".into(),
+            amount: "-1.5".into(),
+            effective_from: "2025-01-01".parse().unwrap(),
+            expires_on: None,
+        };
+        let service = create_test_service(false, false, "1000.00", "0.00")
+            .with_adjuster(SupplyAdjuster::new(vec![adjustment]));
+
+        // Act.
+        let supply = service
+            .fetch_token_supply(&create_token(), &[])
+            .await
+            .unwrap();
+
+        // Assert.
+        assert_eq!(supply.circulating_supply, "1.500000000000000000");
+        assert_eq!(supply.adjustments[0].reason, "Released by custodian");
     }
 
     #[tokio::test]
@@ -206,7 +284,8 @@ mod tests {
         let service = create_test_service(false, false, "0.00", "0.00");
         let total = "2000.00";
         let circulating = "1000.00";
-        let new_supply = TokenSupply::new(total, circulating);
+        let new_supply =
+            TokenSupply::new(total, circulating).with_raw(U256::from(2000), U256::from(1000));
 
         // Act.
         let store_result = service.update_token_supply(new_supply).await;
@@ -221,11 +300,76 @@ mod tests {
         assert_supply_values(&stored_supply, total, circulating);
     }
 
+    #[tokio::test]
+    async fn test_fetch_token_supply_should_record_fetch_time() {
+        // Arrange.
+        let service = create_test_service(false, false, "1000.00", "500.00");
+        let before = Utc::now();
+
+        // Act.
+        let supply = service
+            .fetch_token_supply(&create_token(), &[])
+            .await
+            .unwrap();
+
+        // Assert.
+        assert!(supply.fetched_at >= before && supply.fetched_at <= Utc::now());
+    }
+
+    #[tokio::test]
+    async fn test_update_token_supply_should_increment_version() {
+        // Arrange.
+        let service = create_test_service(false, false, "0.00", "0.00");
+        service
+            .update_token_supply(TokenSupply::new("1.00", "1.00").with_raw(U256::ONE, U256::ONE))
+            .await
+            .unwrap();
+
+        // Act.
+        service
+            .update_token_supply(
+                TokenSupply::new("2.00", "2.00").with_raw(U256::from(2), U256::from(2)),
+            )
+            .await
+            .unwrap();
+
+        // Assert.
+        let stored = service.get_token_supply().await.unwrap();
+        assert_eq!(stored.version, 2);
+    }
+
+    #[tokio::test]
+    async fn test_update_token_supply_should_keep_snapshot_when_rejected() {
+        // Arrange.
+        let service = create_test_service(false, false, "0.00", "0.00")
+            .with_guard(SupplyGuard::new(Some(10.0)));
+        let supply = |total: u64, circulating: u64| {
+            TokenSupply::new(total.to_string(), circulating.to_string())
+                .with_raw(U256::from(total), U256::from(circulating))
+        };
+        service
+            .update_token_supply(supply(1000, 800))
+            .await
+            .unwrap();
+
+        // Act.
+        let result = service.update_token_supply(supply(1000, 400)).await;
+
+        // Assert.
+        let rejection = result.unwrap_err();
+        assert!(rejection.is::<SupplyRejection>());
+
+        let stored = service.get_token_supply().await.unwrap();
+        assert_supply_values(&stored, "1000", "800");
+        assert_eq!(stored.version, 1);
+    }
+
     #[tokio::test]
     async fn test_update_token_supply_should_propagate_repository_errors() {
         // Arrange.
         let service = create_test_service(false, true, "1000.00", "500.00");
-        let new_supply = TokenSupply::new("2000.00", "1000.00");
+        let new_supply =
+            TokenSupply::new("2000.00", "1000.00").with_raw(U256::from(2000), U256::from(1000));
 
         // Act
         let result = service.update_token_supply(new_supply).await;
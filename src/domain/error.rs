@@ -0,0 +1,155 @@
+use std::fmt::Display;
+
+use alloy::primitives::U256;
+
+use crate::domain::model::{AddressKind, ChainAddress};
+
+/// Errors explaining why token supply data cannot be served.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SupplyError {
+    /// No token supply has been fetched or restored yet.
+    NotInitialised,
+}
+
+impl Display for SupplyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SupplyError::NotInitialised => write!(
+                f,
+                "token supply has not been fetched yet, the service is still connecting to the blockchain"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SupplyError {}
+
+/// Errors explaining why a fetched supply was judged implausible and not
+/// stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SupplyRejection {
+    /// The excluded balances add up to more than the total supply, so the
+    /// circulating supply would be negative.
+    Underflow {
+        /// The total supply in the token's smallest unit.
+        total: U256,
+
+        /// The sum of the excluded balances in the token's smallest unit.
+        excluded: U256,
+    },
+
+    /// The circulating supply is larger than the total supply.
+    CirculatingExceedsTotal {
+        /// The total supply in the token's smallest unit.
+        total: U256,
+
+        /// The circulating supply in the token's smallest unit.
+        circulating: U256,
+    },
+
+    /// A figure is zero, which a live token never reports.
+    Zero {
+        /// The figure that is zero, `total` or `circulating`.
+        field: &'static str,
+    },
+
+    /// A figure moved further from the stored snapshot than allowed.
+    Jump {
+        /// The figure that moved, `total` or `circulating`.
+        field: &'static str,
+
+        /// The change, in hundredths of a percent.
+        change_bps: u64,
+
+        /// The largest change allowed, in hundredths of a percent.
+        limit_bps: u64,
+    },
+}
+
+impl Display for SupplyRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SupplyRejection::Underflow { total, excluded } => write!(
+                f,
+                "excluded balances ({}) exceed the total supply ({})",
+                excluded, total
+            ),
+            SupplyRejection::CirculatingExceedsTotal { total, circulating } => write!(
+                f,
+                "circulating supply ({}) exceeds the total supply ({})",
+                circulating, total
+            ),
+            SupplyRejection::Zero { field } => write!(f, "{} supply is zero", field),
+            SupplyRejection::Jump {
+                field,
+                change_bps,
+                limit_bps,
+            } => write!(
+                f,
+                "{} supply changed by {}.{:02}%, more than the {}.{:02}% allowed",
+                field,
+                change_bps / 100,
+                change_bps % 100,
+                limit_bps / 100,
+                limit_bps % 100
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SupplyRejection {}
+
+/// Errors explaining why a change to the excluded sources was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SourceError {
+    /// The source has no name.
+    EmptyName,
+
+    /// A source with the same address is already excluded.
+    Duplicate(ChainAddress),
+
+    /// No source with the address is excluded.
+    NotFound(ChainAddress),
+
+    /// The address is the zero address, which cannot hold tokens.
+    ZeroAddress(ChainAddress),
+
+    /// The address is the token itself.
+    TokenAddress(ChainAddress),
+
+    /// The address belongs to a chain the supply is not read from.
+    UnsupportedAddress {
+        /// The rejected address.
+        address: ChainAddress,
+
+        /// The kind of address the supply is read with.
+        expected: AddressKind,
+    },
+}
+
+impl Display for SourceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SourceError::EmptyName => write!(f, "source name must not be empty"),
+            SourceError::Duplicate(address) => {
+                write!(f, "{} is already an excluded source", address)
+            }
+            SourceError::NotFound(address) => write!(f, "{} is not an excluded source", address),
+            SourceError::ZeroAddress(address) => {
+                write!(f, "{} is the zero address and cannot be a source", address)
+            }
+            SourceError::TokenAddress(address) => {
+                write!(f, "{} is the token itself and cannot be a source", address)
+            }
+            SourceError::UnsupportedAddress { address, expected } => write!(
+                f,
+                "{} is a {} address, but sources must be {} addresses",
+                address,
+                address.kind(),
+                expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SourceError {}
@@ -4,4 +4,5 @@
 //! representing the business concepts and rules independent of external
 //! frameworks or technologies.
 
+pub mod error;
 pub mod model;
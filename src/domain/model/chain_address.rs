@@ -0,0 +1,297 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use alloy::primitives::Address;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The family of chains an address belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AddressKind {
+    /// A 20-byte EVM address, written as `0x`-prefixed hex.
+    Evm,
+
+    /// A 32-byte Solana public key, written in base58.
+    Solana,
+
+    /// A Cosmos SDK account, written in bech32.
+    Cosmos,
+
+    /// A Cosmos SDK bank denom, which identifies a token rather than an
+    /// account.
+    Denom,
+}
+
+impl Display for AddressKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AddressKind::Evm => write!(f, "EVM"),
+            AddressKind::Solana => write!(f, "Solana"),
+            AddressKind::Cosmos => write!(f, "Cosmos"),
+            AddressKind::Denom => write!(f, "Cosmos denom"),
+        }
+    }
+}
+
+/// An account or token address on any supported chain.
+///
+/// Serialized as its string representation: checksummed hex for EVM
+/// addresses, base58 for Solana public keys, lowercase bech32 for Cosmos
+/// accounts and the denom itself for Cosmos tokens.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ChainAddress {
+    /// An EVM address.
+    Evm(Address),
+
+    /// A Solana public key.
+    Solana([u8; 32]),
+
+    /// A Cosmos SDK account, in lowercase bech32.
+    Cosmos(String),
+
+    /// A Cosmos SDK bank denom, such as `uatom` or `ibc/27394FB0...`.
+    Denom(String),
+}
+
+impl ChainAddress {
+    /// Parses an address, requiring EVM addresses to be EIP-55 checksummed.
+    ///
+    /// Used where a mistyped address should be rejected rather than silently
+    /// pointing at the wrong account. Base58 carries no checksum, so Solana
+    /// public keys are only checked for length.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The address to parse.
+    ///
+    /// # Returns
+    ///
+    /// The parsed address, or a description of why it is invalid.
+    pub fn parse_checksummed(s: &str) -> Result<Self, String> {
+        if s.starts_with("0x") {
+            return Address::parse_checksummed(s, None)
+                .map(ChainAddress::Evm)
+                .map_err(|e| format!("not a checksummed address: {}", e));
+        }
+
+        s.parse()
+    }
+
+    /// Returns the family of chains the address belongs to.
+    pub fn kind(&self) -> AddressKind {
+        match self {
+            ChainAddress::Evm(_) => AddressKind::Evm,
+            ChainAddress::Solana(_) => AddressKind::Solana,
+            ChainAddress::Cosmos(_) => AddressKind::Cosmos,
+            ChainAddress::Denom(_) => AddressKind::Denom,
+        }
+    }
+
+    /// Returns the EVM address, or `None` for other chains.
+    pub fn as_evm(&self) -> Option<Address> {
+        match self {
+            ChainAddress::Evm(address) => Some(*address),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if every byte of the address is zero.
+    pub fn is_zero(&self) -> bool {
+        match self {
+            ChainAddress::Evm(address) => address.is_zero(),
+            ChainAddress::Solana(key) => key.iter().all(|b| *b == 0),
+            ChainAddress::Cosmos(address) => {
+                bech32::decode(address).is_ok_and(|(_, data)| data.iter().all(|b| *b == 0))
+            }
+            ChainAddress::Denom(_) => false,
+        }
+    }
+}
+
+impl From<Address> for ChainAddress {
+    fn from(address: Address) -> Self {
+        ChainAddress::Evm(address)
+    }
+}
+
+impl Display for ChainAddress {
+    /// Returns the checksummed hex of an EVM address, the base58 of a Solana
+    /// public key, or the bech32 of a Cosmos account.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use coingecko_supply::domain::model::ChainAddress;
+    ///
+    /// let evm = "0xc3d7a72ccd1ede897d83c8d768e624abb69c4118";
+    /// let solana = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+    /// let cosmos = "COSMOS1QYPQXPQ9QCRSSZG2PVXQ6RS0ZQG3YYC5LZV7XU";
+    ///
+    /// let evm: ChainAddress = evm.parse().unwrap();
+    /// let solana: ChainAddress = solana.parse().unwrap();
+    /// let cosmos: ChainAddress = cosmos.parse().unwrap();
+    ///
+    /// assert_eq!(evm.to_string(), "0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118");
+    /// assert_eq!(solana.to_string(), "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+    /// assert_eq!(cosmos.to_string(), "cosmos1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xu");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChainAddress::Evm(address) => write!(f, "{}", address.to_checksum(None)),
+            ChainAddress::Solana(key) => write!(f, "{}", bs58::encode(key).into_string()),
+            ChainAddress::Cosmos(address) | ChainAddress::Denom(address) => {
+                write!(f, "{}", address)
+            }
+        }
+    }
+}
+
+impl FromStr for ChainAddress {
+    type Err = String;
+
+    /// Parses `0x`-prefixed hex as an EVM address, valid bech32 as a Cosmos
+    /// account, and base58 that decodes to 32 bytes as a Solana public key.
+    /// Anything else that is a valid Cosmos denom is parsed as one.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("0x") {
+            return s
+                .parse::<Address>()
+                .map(ChainAddress::Evm)
+                .map_err(|e| format!("not an address: {}", e));
+        }
+
+        if let Ok((hrp, data)) = bech32::decode(s) {
+            return match data.len() {
+                20 | 32 => bech32::encode::<bech32::Bech32>(hrp, &data)
+                    .map(ChainAddress::Cosmos)
+                    .map_err(|e| format!("not an address: {}", e)),
+                len => Err(format!(
+                    "not an account: decodes to {} bytes, not 20 or 32",
+                    len
+                )),
+            };
+        }
+
+        let mut key = [0u8; 32];
+        let solana = bs58::decode(s).onto(&mut key);
+
+        if let Ok(32) = solana {
+            return Ok(ChainAddress::Solana(key));
+        }
+
+        if is_denom(s) {
+            return Ok(ChainAddress::Denom(s.to_string()));
+        }
+
+        match solana {
+            Ok(len) => Err(format!(
+                "not a public key: decodes to {} bytes, not 32",
+                len
+            )),
+            Err(e) => Err(format!("not an address: {}", e)),
+        }
+    }
+}
+
+/// Returns `true` if `s` matches the Cosmos SDK denom format: a letter
+/// followed by 2 to 127 letters, digits or `/:._-`.
+fn is_denom(s: &str) -> bool {
+    let mut chars = s.chars();
+
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && (3..=128).contains(&s.len())
+        && chars.all(|c| c.is_ascii_alphanumeric() || "/:._-".contains(c))
+}
+
+impl Serialize for ChainAddress {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ChainAddress {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use super::*;
+
+    #[test]
+    fn parses_evm_and_solana_addresses() {
+        // Act.
+        let evm: ChainAddress = "0xb1a932a665fb0a1d5d7979cd63e80a59edce31b4"
+            .parse()
+            .unwrap();
+        let solana: ChainAddress = "11111111111111111111111111111111".parse().unwrap();
+
+        // Assert.
+        let expected = address!("0xB1a932A665FB0A1D5d7979cd63e80a59EDCe31B4");
+        assert_eq!(evm, ChainAddress::Evm(expected));
+        assert_eq!(solana, ChainAddress::Solana([0; 32]));
+        assert!(solana.is_zero());
+    }
+
+    #[test]
+    fn rejects_malformed_addresses() {
+        // Act & Assert.
+        assert!("0x1234".parse::<ChainAddress>().is_err());
+        assert!("3yZe7d".parse::<ChainAddress>().is_err());
+        assert!("not base58 0OIl".parse::<ChainAddress>().is_err());
+        assert!(
+            "cosmos1qypqxpq9qcrsszg2789qmz"
+                .parse::<ChainAddress>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn parses_cosmos_accounts_and_denoms() {
+        // Act.
+        let account: ChainAddress = "cosmos1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xu"
+            .parse()
+            .unwrap();
+        let native: ChainAddress = "uatom".parse().unwrap();
+        let ibc: ChainAddress =
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+                .parse()
+                .unwrap();
+
+        // Assert.
+        assert_eq!(account.kind(), AddressKind::Cosmos);
+        assert_eq!(native, ChainAddress::Denom("uatom".into()));
+        assert_eq!(ibc.kind(), AddressKind::Denom);
+        assert!(!account.is_zero());
+    }
+
+    #[test]
+    fn parse_checksummed_rejects_bad_evm_checksums() {
+        // Act.
+        let lowercase =
+            ChainAddress::parse_checksummed("0xb1a932a665fb0a1d5d7979cd63e80a59edce31b4");
+        let checksummed =
+            ChainAddress::parse_checksummed("0xB1a932A665FB0A1D5d7979cd63e80a59EDCe31B4");
+
+        // Assert.
+        assert!(lowercase.is_err());
+        assert!(checksummed.is_ok());
+    }
+
+    #[test]
+    fn serializes_as_a_string() {
+        // Arrange.
+        let address = ChainAddress::Solana([1; 32]);
+
+        // Act.
+        let json = serde_json::to_string(&address).unwrap();
+        let parsed: ChainAddress = serde_json::from_str(&json).unwrap();
+
+        // Assert.
+        assert_eq!(json, format!("\"{}\"", address));
+        assert_eq!(parsed, address);
+    }
+}
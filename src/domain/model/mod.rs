@@ -3,10 +3,22 @@
 //! This module defines core domain entities and value objects used in token
 //! supply calculations, including representations of sources and supply data.
 
+pub mod chain_address;
 pub mod source;
+pub mod source_balance;
+pub mod source_change;
+pub mod supply_adjustment;
+pub mod supply_event;
 pub mod token;
+pub mod token_metadata_overrides;
 pub mod token_supply;
 
+pub use chain_address::{AddressKind, ChainAddress};
 pub use source::Source;
+pub use source_balance::SourceBalance;
+pub use source_change::{SourceAction, SourceChange};
+pub use supply_adjustment::{AppliedAdjustment, SupplyAdjustment};
+pub use supply_event::{SupplyEvent, SupplyEventKind};
 pub use token::Token;
+pub use token_metadata_overrides::TokenMetadataOverrides;
 pub use token_supply::TokenSupply;
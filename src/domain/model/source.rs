@@ -1,12 +1,22 @@
-use alloy::primitives::Address;
-use serde::Deserialize;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::ChainAddress;
 
 /// Represents a named source with an associated blockchain address.
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Source {
     /// The name of the source.
     pub name: String,
 
     /// The blockchain address associated with the source.
-    pub address: Address,
+    pub address: ChainAddress,
+}
+
+impl Display for Source {
+    /// Returns the source name followed by its address.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.name, self.address)
+    }
 }
@@ -0,0 +1,80 @@
+use std::fmt::Display;
+
+use alloy::primitives::U256;
+use serde::{Deserialize, Serialize};
+
+use super::ChainAddress;
+
+/// Represents the balance held by an excluded source at the time of a fetch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceBalance {
+    /// The name of the source.
+    pub name: String,
+
+    /// The blockchain address associated with the source.
+    pub address: ChainAddress,
+
+    /// Formatted string representation of the balance held by the source.
+    pub balance: String,
+
+    /// The balance held by the source in the token's smallest unit.
+    #[serde(default)]
+    pub raw_balance: U256,
+}
+
+impl SourceBalance {
+    /// Creates a new [`SourceBalance`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the source.
+    /// * `address` - The blockchain address associated with the source.
+    /// * `balance` - Formatted string representation of the balance.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`SourceBalance`] instance.
+    pub fn new<T: Into<String>>(name: T, address: impl Into<ChainAddress>, balance: T) -> Self {
+        SourceBalance {
+            name: name.into(),
+            address: address.into(),
+            balance: balance.into(),
+            raw_balance: U256::ZERO,
+        }
+    }
+
+    /// Attaches the unformatted balance.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw_balance` - The balance in the token's smallest unit.
+    ///
+    /// # Returns
+    ///
+    /// * The [`SourceBalance`] instance with the raw balance attached.
+    pub fn with_raw(mut self, raw_balance: U256) -> Self {
+        self.raw_balance = raw_balance;
+        self
+    }
+}
+
+impl Display for SourceBalance {
+    /// Returns a human-readable representation of [`SourceBalance`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy::primitives::address;
+    /// use coingecko_supply::domain::model::SourceBalance;
+    ///
+    /// let address = address!("0xB1a932A665FB0A1D5d7979cd63e80a59EDCe31B4");
+    /// let balance = SourceBalance::new("Sink", address, "100000.00");
+    ///
+    /// let expected = "Sink (0xB1a932A665FB0A1D5d7979cd63e80a59EDCe31B4): 100000.00";
+    /// assert_eq!(format!("{}", balance), expected);
+    ///
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({}): {}", self.name, self.address, self.balance)
+    }
+}
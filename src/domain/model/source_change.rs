@@ -0,0 +1,67 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::Source;
+
+/// The kind of change made to the excluded sources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SourceAction {
+    /// The source was added.
+    Added,
+
+    /// The source was removed.
+    Removed,
+}
+
+/// An audit record of a change to the excluded sources.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceChange {
+    /// When the change was made.
+    pub at: DateTime<Utc>,
+
+    /// Who made the change.
+    pub actor: String,
+
+    /// What was done to the source.
+    pub action: SourceAction,
+
+    /// The source that was added or removed.
+    pub source: Source,
+}
+
+impl SourceChange {
+    /// Creates a new [`SourceChange`] instance timestamped now.
+    ///
+    /// # Arguments
+    ///
+    /// * `actor` - Who made the change.
+    /// * `action` - What was done to the source.
+    /// * `source` - The source that was added or removed.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`SourceChange`] instance.
+    pub fn now<T: Into<String>>(actor: T, action: SourceAction, source: Source) -> Self {
+        Self {
+            at: Utc::now(),
+            actor: actor.into(),
+            action,
+            source,
+        }
+    }
+}
+
+impl Display for SourceChange {
+    /// Returns a one-line description such as `ops added Sink (0x...)`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let action = match self.action {
+            SourceAction::Added => "added",
+            SourceAction::Removed => "removed",
+        };
+
+        write!(f, "{} {} {}", self.actor, action, self.source)
+    }
+}
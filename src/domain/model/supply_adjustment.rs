@@ -0,0 +1,196 @@
+use std::fmt::Display;
+
+use alloy::primitives::{I256, U256, utils};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// A manual change to the circulating supply, for holdings that cannot be
+/// read on chain, such as tokens held by a custodian or on an exchange.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct SupplyAdjustment {
+    /// Why the adjustment is made, such as who holds the tokens.
+    pub reason: String,
+
+    /// The amount in whole tokens, such as `"1500000.5"`.
+    ///
+    /// A positive amount is removed from the circulating supply, like the
+    /// balance of an excluded source. A negative amount is added back.
+    pub amount: String,
+
+    /// The first day, in UTC, on which the adjustment applies.
+    pub effective_from: NaiveDate,
+
+    /// The day, in UTC, from which the adjustment no longer applies, if any.
+    #[serde(default)]
+    pub expires_on: Option<NaiveDate>,
+}
+
+impl SupplyAdjustment {
+    /// Returns `true` if the adjustment applies on the given day.
+    ///
+    /// # Arguments
+    ///
+    /// * `day` - The day, in UTC.
+    pub fn is_active(&self, day: NaiveDate) -> bool {
+        self.effective_from <= day && self.expires_on.is_none_or(|expires| day < expires)
+    }
+
+    /// Converts the amount to the token's smallest unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `decimals` - The number of decimals the token uses.
+    ///
+    /// # Returns
+    ///
+    /// The signed amount in the token's smallest unit, or a description of why
+    /// the amount is invalid.
+    pub fn raw_amount(&self, decimals: u8) -> Result<I256, String> {
+        let (negative, digits, fraction) = self.parts()?;
+
+        if fraction.len() > decimals as usize {
+            return Err(format!(
+                "`{}` has more than the token's {} decimals",
+                self.amount, decimals
+            ));
+        }
+
+        let raw: U256 = utils::parse_units(digits, decimals)
+            .map_err(|e| format!("`{}` is not a valid amount: {}", self.amount, e))?
+            .into();
+        let raw = I256::try_from(raw).map_err(|_| format!("`{}` is too large", self.amount))?;
+
+        match negative {
+            true => Ok(-raw),
+            false => Ok(raw),
+        }
+    }
+
+    /// Returns `true` if the amount is a signed decimal number, such as
+    /// `"-1500000.5"`, regardless of the token's decimals.
+    pub fn is_well_formed(&self) -> bool {
+        self.parts().is_ok()
+    }
+
+    /// Splits the amount into its sign, its digits without the sign, and its
+    /// fractional digits.
+    fn parts(&self) -> Result<(bool, &str, &str), String> {
+        let amount = self.amount.trim();
+        let digits = amount.strip_prefix('-').unwrap_or(amount);
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+        let is_number = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+
+        if whole.is_empty() || !is_number(whole) || !is_number(fraction) {
+            return Err(format!("`{}` is not a decimal amount", self.amount));
+        }
+
+        Ok((digits.len() < amount.len(), digits, fraction))
+    }
+}
+
+/// A manual adjustment as it was applied to a fetched supply.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppliedAdjustment {
+    /// Why the adjustment was made.
+    pub reason: String,
+
+    /// Formatted string representation of the amount removed from the
+    /// circulating supply. Negative if it was added back.
+    pub amount: String,
+
+    /// The amount removed from the circulating supply in the token's smallest
+    /// unit.
+    #[serde(default)]
+    pub raw_amount: I256,
+
+    /// The first day on which the adjustment applies.
+    pub effective_from: NaiveDate,
+
+    /// The day from which the adjustment no longer applies, if any.
+    #[serde(default)]
+    pub expires_on: Option<NaiveDate>,
+}
+
+impl Display for AppliedAdjustment {
+    /// Returns a human-readable representation of [`AppliedAdjustment`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy::primitives::I256;
+    /// use chrono::NaiveDate;
+    /// use coingecko_supply::domain::model::AppliedAdjustment;
+    ///
+    /// let adjustment = AppliedAdjustment {
+    ///     reason: "Held by custodian".into(),
+    ///     amount: "-2500.0".into(),
+    ///     raw_amount: I256::try_from(-25000).unwrap(),
+    ///     effective_from: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+    ///     expires_on: None,
+    /// };
+    ///
+    /// assert_eq!(adjustment.to_string(), "Held by custodian (from 2025-01-01): -2500.0");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (from {}", self.reason, self.effective_from)?;
+
+        if let Some(expires_on) = self.expires_on {
+            write!(f, " until {}", expires_on)?;
+        }
+
+        write!(f, "): {}", self.amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Test Helper Functions
+
+    fn day(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    fn adjustment(amount: &str) -> SupplyAdjustment {
+        SupplyAdjustment {
+            reason: "Custodian".into(),
+            amount: amount.into(),
+            effective_from: day("2025-01-01"),
+            expires_on: Some(day("2025-07-01")),
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[test]
+    fn is_active_from_effective_day_until_expiry() {
+        // Arrange.
+        let adjustment = adjustment("1");
+
+        // Act & Assert.
+        assert!(!adjustment.is_active(day("2024-12-31")));
+        assert!(adjustment.is_active(day("2025-01-01")));
+        assert!(adjustment.is_active(day("2025-06-30")));
+        assert!(!adjustment.is_active(day("2025-07-01")));
+    }
+
+    #[test]
+    fn raw_amount_is_signed_in_smallest_unit() {
+        // Act & Assert.
+        assert_eq!(
+            adjustment("1.5").raw_amount(6),
+            Ok(I256::try_from(1_500_000).unwrap())
+        );
+        assert_eq!(
+            adjustment("-2").raw_amount(2),
+            Ok(I256::try_from(-200).unwrap())
+        );
+        assert!(adjustment("1.234").raw_amount(2).is_err());
+        assert!(adjustment("1e6").raw_amount(18).is_err());
+        assert!(adjustment("-.5").raw_amount(18).is_err());
+    }
+}
@@ -0,0 +1,162 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// The kinds of [`SupplyEvent`], used to choose which events a subscriber
+/// receives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SupplyEventKind {
+    /// See [`SupplyEvent::SupplyChanged`].
+    SupplyChanged,
+
+    /// See [`SupplyEvent::FetchFailing`].
+    FetchFailing,
+
+    /// See [`SupplyEvent::Recovered`].
+    Recovered,
+
+    /// See [`SupplyEvent::Stale`].
+    Stale,
+}
+
+impl SupplyEventKind {
+    /// Every kind, in declaration order.
+    pub const ALL: [SupplyEventKind; 4] = [
+        SupplyEventKind::SupplyChanged,
+        SupplyEventKind::FetchFailing,
+        SupplyEventKind::Recovered,
+        SupplyEventKind::Stale,
+    ];
+
+    /// Converts the kind to a string representation.
+    ///
+    /// # Returns
+    ///
+    /// A static string identifier for the kind.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SupplyEventKind::SupplyChanged => "supply_changed",
+            SupplyEventKind::FetchFailing => "fetch_failing",
+            SupplyEventKind::Recovered => "recovered",
+            SupplyEventKind::Stale => "stale",
+        }
+    }
+}
+
+/// Something operators should hear about in the life of the published supply.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum SupplyEvent {
+    /// A figure moved further between consecutive snapshots than the
+    /// configured threshold.
+    SupplyChanged {
+        /// The figure that moved, `total` or `circulating`.
+        field: &'static str,
+
+        /// The formatted figure in the previous snapshot.
+        previous: String,
+
+        /// The formatted figure in the new snapshot.
+        current: String,
+
+        /// The change, in hundredths of a percent.
+        change_bps: u64,
+    },
+
+    /// Refreshes have failed a number of times in a row.
+    FetchFailing {
+        /// How many refreshes failed in a row.
+        consecutive_failures: u32,
+
+        /// Why the most recent refresh failed.
+        error: String,
+    },
+
+    /// A refresh succeeded after failures or staleness were reported.
+    Recovered {
+        /// How many refreshes failed in a row before this one succeeded.
+        failures: u32,
+    },
+
+    /// The published snapshot has missed at least one refresh.
+    Stale {
+        /// When the published snapshot was fetched.
+        fetched_at: DateTime<Utc>,
+
+        /// How old the published snapshot is, in seconds.
+        age_seconds: u64,
+    },
+}
+
+impl SupplyEvent {
+    /// Returns the kind of the event.
+    pub fn kind(&self) -> SupplyEventKind {
+        match self {
+            SupplyEvent::SupplyChanged { .. } => SupplyEventKind::SupplyChanged,
+            SupplyEvent::FetchFailing { .. } => SupplyEventKind::FetchFailing,
+            SupplyEvent::Recovered { .. } => SupplyEventKind::Recovered,
+            SupplyEvent::Stale { .. } => SupplyEventKind::Stale,
+        }
+    }
+}
+
+impl Display for SupplyEvent {
+    /// Returns a one-line description suitable for a chat message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use coingecko_supply::domain::model::SupplyEvent;
+    ///
+    /// let event = SupplyEvent::FetchFailing {
+    ///     consecutive_failures: 3,
+    ///     error: "connection refused".into(),
+    /// };
+    ///
+    /// let expected = "Supply refresh failed 3 times in a row: connection refused";
+    /// assert_eq!(event.to_string(), expected);
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SupplyEvent::SupplyChanged {
+                field,
+                previous,
+                current,
+                change_bps,
+            } => write!(
+                f,
+                "{}{} supply changed by {}.{:02}%, from {} to {}",
+                field[..1].to_uppercase(),
+                &field[1..],
+                change_bps / 100,
+                change_bps % 100,
+                previous,
+                current
+            ),
+            SupplyEvent::FetchFailing {
+                consecutive_failures,
+                error,
+            } => write!(
+                f,
+                "Supply refresh failed {} times in a row: {}",
+                consecutive_failures, error
+            ),
+            SupplyEvent::Recovered { failures } => write!(
+                f,
+                "Supply refresh recovered after {} failed attempts",
+                failures
+            ),
+            SupplyEvent::Stale {
+                fetched_at,
+                age_seconds,
+            } => write!(
+                f,
+                "Published supply is stale: last fetched at {} ({} seconds ago)",
+                fetched_at.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+                age_seconds
+            ),
+        }
+    }
+}
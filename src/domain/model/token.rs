@@ -1,9 +1,11 @@
 use std::fmt::Display;
 
-use alloy::primitives::Address;
+use serde::{Deserialize, Serialize};
 
-/// Represents an ERC20 token.
-#[derive(Debug, Clone)]
+use super::ChainAddress;
+
+/// Represents a fungible token, such as an ERC-20 token or an SPL mint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Token {
     /// The name of the token.
     pub name: String,
@@ -12,7 +14,7 @@ pub struct Token {
     pub symbol: String,
 
     /// The address of the token.
-    pub address: Address,
+    pub address: ChainAddress,
 
     /// The decimal places of the token.
     pub decimals: u8,
@@ -31,11 +33,16 @@ impl Token {
     /// # Returns
     ///
     /// * A new [`Token`] instance.
-    pub fn new<T: Into<String>>(name: T, symbol: T, address: Address, decimals: u8) -> Self {
+    pub fn new<T: Into<String>>(
+        name: T,
+        symbol: T,
+        address: impl Into<ChainAddress>,
+        decimals: u8,
+    ) -> Self {
         Token {
             name: name.into(),
             symbol: symbol.into(),
-            address,
+            address: address.into(),
             decimals,
         }
     }
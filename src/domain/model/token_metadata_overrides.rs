@@ -0,0 +1,117 @@
+use serde::Deserialize;
+
+use super::{ChainAddress, Token};
+
+/// Token metadata values that take precedence over fetched metadata.
+///
+/// Name, symbol and decimals never change for a deployed token, so providing
+/// all three removes the need to look them up at all.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TokenMetadataOverrides {
+    /// The name of the token.
+    pub name: Option<String>,
+
+    /// The symbol of the token.
+    pub symbol: Option<String>,
+
+    /// The decimal places of the token.
+    pub decimals: Option<u8>,
+}
+
+impl TokenMetadataOverrides {
+    /// Builds a [`Token`] purely from the overrides.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the token.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Token)` if name, symbol and decimals are all overridden.
+    /// * `None` if any of them is missing.
+    pub fn to_token(&self, address: ChainAddress) -> Option<Token> {
+        match (&self.name, &self.symbol, self.decimals) {
+            (Some(name), Some(symbol), Some(decimals)) => Some(Token::new(
+                name.as_str(),
+                symbol.as_str(),
+                address,
+                decimals,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Replaces the fields of a fetched [`Token`] with any overridden values.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The fetched token.
+    ///
+    /// # Returns
+    ///
+    /// * The token with the overrides applied.
+    pub fn apply(&self, mut token: Token) -> Token {
+        if let Some(name) = &self.name {
+            token.name = name.clone();
+        }
+
+        if let Some(symbol) = &self.symbol {
+            token.symbol = symbol.clone();
+        }
+
+        if let Some(decimals) = self.decimals {
+            token.decimals = decimals;
+        }
+
+        token
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use super::*;
+
+    const TOKEN: ChainAddress =
+        ChainAddress::Evm(address!("0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118"));
+
+    #[test]
+    fn to_token_requires_every_field() {
+        // Arrange.
+        let partial = TokenMetadataOverrides {
+            name: Some("Supply".into()),
+            symbol: Some("SUPPLY".into()),
+            decimals: None,
+        };
+
+        let complete = TokenMetadataOverrides {
+            decimals: Some(18),
+            ..partial.clone()
+        };
+
+        // Act & Assert.
+        assert_eq!(partial.to_token(TOKEN), None);
+        assert_eq!(
+            complete.to_token(TOKEN),
+            Some(Token::new("Supply", "SUPPLY", TOKEN, 18))
+        );
+    }
+
+    #[test]
+    fn apply_only_replaces_overridden_fields() {
+        // Arrange.
+        let overrides = TokenMetadataOverrides {
+            symbol: Some("SPLY".into()),
+            ..Default::default()
+        };
+
+        let fetched = Token::new("Supply", "SUPPLY", TOKEN, 18);
+
+        // Act.
+        let token = overrides.apply(fetched);
+
+        // Assert.
+        assert_eq!(token, Token::new("Supply", "SPLY", TOKEN, 18));
+    }
+}
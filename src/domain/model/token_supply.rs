@@ -1,13 +1,56 @@
 use std::fmt::Display;
 
+use alloy::primitives::U256;
+use alloy::primitives::utils::{self, UnitsError};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{AppliedAdjustment, SourceBalance, Token};
+
 /// Represents token supply information.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TokenSupply {
     /// Formatted string representation of the token's total supply.
     pub total_supply: String,
 
     /// Formatted string representation of the token's circulating supply.
     pub circulating_supply: String,
+
+    /// The token's total supply in its smallest unit.
+    #[serde(default)]
+    pub raw_total_supply: U256,
+
+    /// The token's circulating supply in its smallest unit.
+    #[serde(default)]
+    pub raw_circulating_supply: U256,
+
+    /// The balances of the excluded sources that were subtracted from the
+    /// total supply to derive the circulating supply.
+    #[serde(default)]
+    pub breakdown: Vec<SourceBalance>,
+
+    /// The manual adjustments applied after the excluded balances were
+    /// subtracted.
+    #[serde(default)]
+    pub adjustments: Vec<AppliedAdjustment>,
+
+    /// Identifies the stored snapshot. Increases by one with every update, and
+    /// is zero for a supply that has never been stored.
+    #[serde(default)]
+    pub version: u64,
+
+    /// When the supply was fetched from the chain.
+    #[serde(default)]
+    pub fetched_at: DateTime<Utc>,
+
+    /// The block the supply was read at, if the provider reports one.
+    #[serde(default)]
+    pub block_number: Option<u64>,
+
+    /// The token the supply was fetched for. Absent from snapshots stored
+    /// before it was recorded.
+    #[serde(default)]
+    pub token: Option<Token>,
 }
 
 impl TokenSupply {
@@ -25,8 +68,132 @@ impl TokenSupply {
         TokenSupply {
             total_supply: total_supply.into(),
             circulating_supply: circulating_supply.into(),
+            raw_total_supply: U256::ZERO,
+            raw_circulating_supply: U256::ZERO,
+            breakdown: Vec::new(),
+            adjustments: Vec::new(),
+            version: 0,
+            fetched_at: DateTime::default(),
+            block_number: None,
+            token: None,
         }
     }
+
+    /// Creates a new [`TokenSupply`] instance from the amounts read from a
+    /// chain, subtracting the excluded balances from the total.
+    ///
+    /// Excluded balances larger than the total are implausible. The
+    /// circulating supply saturates at zero, and the use case rejects the
+    /// snapshot.
+    ///
+    /// # Arguments
+    ///
+    /// * `total_supply` - The total supply in the token's smallest unit.
+    /// * `excluded` - The sum of the excluded balances in the token's smallest unit.
+    /// * `decimals` - The decimal places of the token.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`TokenSupply`] instance with formatted and raw amounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `decimals` is out of range.
+    pub fn from_raw(total_supply: U256, excluded: U256, decimals: u8) -> Result<Self, UnitsError> {
+        let circulating_supply = total_supply.saturating_sub(excluded);
+
+        let formatted_total = utils::format_units(total_supply, decimals)?;
+        let formatted_circulating = utils::format_units(circulating_supply, decimals)?;
+
+        Ok(TokenSupply::new(formatted_total, formatted_circulating)
+            .with_raw(total_supply, circulating_supply))
+    }
+
+    /// Attaches the balances of the excluded sources to the supply.
+    ///
+    /// # Arguments
+    ///
+    /// * `breakdown` - The balances of the excluded sources.
+    ///
+    /// # Returns
+    ///
+    /// * The [`TokenSupply`] instance with the breakdown attached.
+    pub fn with_breakdown(mut self, breakdown: Vec<SourceBalance>) -> Self {
+        self.breakdown = breakdown;
+        self
+    }
+
+    /// Attaches the manual adjustments applied to the supply.
+    ///
+    /// # Arguments
+    ///
+    /// * `adjustments` - The manual adjustments that were applied.
+    ///
+    /// # Returns
+    ///
+    /// * The [`TokenSupply`] instance with the adjustments attached.
+    pub fn with_adjustments(mut self, adjustments: Vec<AppliedAdjustment>) -> Self {
+        self.adjustments = adjustments;
+        self
+    }
+
+    /// Attaches the unformatted amounts to the supply.
+    ///
+    /// # Arguments
+    ///
+    /// * `total_supply` - The total supply in the token's smallest unit.
+    /// * `circulating_supply` - The circulating supply in the token's smallest unit.
+    ///
+    /// # Returns
+    ///
+    /// * The [`TokenSupply`] instance with the raw amounts attached.
+    pub fn with_raw(mut self, total_supply: U256, circulating_supply: U256) -> Self {
+        self.raw_total_supply = total_supply;
+        self.raw_circulating_supply = circulating_supply;
+        self
+    }
+
+    /// Records the block the supply was read at.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_number` - The block the supply was read at.
+    ///
+    /// # Returns
+    ///
+    /// * The [`TokenSupply`] instance with the block number attached.
+    pub fn with_block_number(mut self, block_number: u64) -> Self {
+        self.block_number = Some(block_number);
+        self
+    }
+
+    /// Records the token the supply was fetched for.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The token the supply was fetched for.
+    ///
+    /// # Returns
+    ///
+    /// * The [`TokenSupply`] instance with the token attached.
+    pub fn with_token(mut self, token: Token) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    /// Records when the supply was fetched.
+    ///
+    /// # Arguments
+    ///
+    /// * `fetched_at` - When the supply was fetched from the chain.
+    ///
+    /// # Returns
+    ///
+    /// * The [`TokenSupply`] instance with the fetch time attached.
+    pub fn with_fetched_at(mut self, fetched_at: DateTime<Utc>) -> Self {
+        self.fetched_at = fetched_at;
+        self
+    }
 }
 
 impl Default for TokenSupply {
@@ -61,3 +228,33 @@ impl Display for TokenSupply {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[test]
+    fn from_raw_subtracts_excluded_balances() {
+        // Act.
+        let supply = TokenSupply::from_raw(U256::from(4242), U256::from(42), 2).unwrap();
+
+        // Assert.
+        assert_eq!(supply.total_supply, "42.42");
+        assert_eq!(supply.circulating_supply, "42.00");
+        assert_eq!(supply.raw_total_supply, U256::from(4242));
+        assert_eq!(supply.raw_circulating_supply, U256::from(4200));
+    }
+
+    #[test]
+    fn from_raw_saturates_when_excluded_exceeds_total() {
+        // Act.
+        let supply = TokenSupply::from_raw(U256::from(100), U256::from(200), 0).unwrap();
+
+        // Assert.
+        assert_eq!(supply.raw_total_supply, U256::from(100));
+        assert_eq!(supply.raw_circulating_supply, U256::ZERO);
+    }
+}
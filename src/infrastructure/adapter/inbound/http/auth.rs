@@ -0,0 +1,133 @@
+use std::future::{Ready, ready};
+
+use actix_web::dev::Payload;
+use actix_web::http::header::{self, HeaderMap};
+use actix_web::web::Data;
+use actix_web::{FromRequest, HttpRequest};
+use tracing::warn;
+
+use crate::infrastructure::configuration::admin::AdminConfig;
+
+use super::error::{ApiError, ErrorCode};
+
+/// The header that carries an API key, as an alternative to a bearer token.
+const API_KEY_HEADER: &str = "x-api-key";
+
+/// An authenticated caller of the admin endpoints.
+///
+/// Extracting this from a request checks the `Authorization: Bearer` or
+/// `X-API-Key` header against the configured [`AdminConfig`], and rejects the
+/// request with `401 Unauthorized` if neither matches.
+#[derive(Debug, Clone)]
+pub struct Admin {
+    /// The name of the credential that was presented.
+    pub name: String,
+}
+
+impl FromRequest for Admin {
+    type Error = ApiError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(authenticate(req))
+    }
+}
+
+fn authenticate(req: &HttpRequest) -> Result<Admin, ApiError> {
+    let config = req.app_data::<Data<AdminConfig>>();
+    let presented = presented_token(req.headers());
+
+    let name = match (config, presented) {
+        (Some(config), Some(token)) => find_credential(config, token),
+        _ => None,
+    };
+
+    match name {
+        Some(name) => Ok(Admin { name }),
+        None => {
+            warn!("Rejected unauthenticated admin request to {}", req.path());
+
+            Err(ApiError::new(
+                ErrorCode::Unauthorized,
+                "missing or invalid admin credentials",
+            ))
+        }
+    }
+}
+
+fn presented_token(headers: &HeaderMap) -> Option<&str> {
+    let bearer = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "));
+
+    let api_key = headers.get(API_KEY_HEADER).and_then(|v| v.to_str().ok());
+
+    bearer.or(api_key).map(str::trim)
+}
+
+/// Finds the credential with the given token.
+///
+/// Every credential is compared in full, so the time taken does not reveal
+/// which credential, or how much of one, matched.
+fn find_credential(config: &AdminConfig, token: &str) -> Option<String> {
+    config.credentials.iter().fold(None, |found, credential| {
+        match constant_time_eq(credential.token.as_bytes(), token.as_bytes()) {
+            true => Some(credential.name.clone()),
+            false => found,
+        }
+    })
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::configuration::admin::AdminCredential;
+
+    use super::*;
+
+    fn create_config() -> AdminConfig {
+        AdminConfig {
+            credentials: vec![
+                AdminCredential {
+                    name: "ops".into(),
+                    token: "ops-0123456789abcdef".into(),
+                },
+                AdminCredential {
+                    name: "deploy".into(),
+                    token: "deploy-0123456789abcdef".into(),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn finds_the_matching_credential() {
+        // Arrange.
+        let config = create_config();
+
+        // Act.
+        let name = find_credential(&config, "deploy-0123456789abcdef");
+
+        // Assert.
+        assert_eq!(name.as_deref(), Some("deploy"));
+    }
+
+    #[test]
+    fn rejects_prefixes_and_unknown_tokens() {
+        // Arrange.
+        let config = create_config();
+
+        // Act & Assert.
+        assert_eq!(find_credential(&config, "ops-0123456789abcde"), None);
+        assert_eq!(find_credential(&config, "ops-0123456789abcdeg"), None);
+        assert_eq!(find_credential(&config, ""), None);
+    }
+}
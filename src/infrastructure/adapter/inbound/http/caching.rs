@@ -0,0 +1,277 @@
+use std::time::{Duration, SystemTime};
+
+use actix_web::http::header::{
+    CacheControl, CacheDirective, ETag, EntityTag, HttpDate, IfModifiedSince, IfNoneMatch,
+    LastModified,
+};
+use actix_web::{HttpMessage, HttpRequest, HttpResponse, HttpResponseBuilder};
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::Serialize;
+
+use crate::domain::model::TokenSupply;
+
+/// How long clients and shared caches may keep supply responses.
+///
+/// Responses stay fresh until the worker is expected to replace the snapshot
+/// they were built from, so caches expire in step with the refresh interval.
+#[derive(Debug, Clone, Copy)]
+pub struct CachePolicy {
+    update_interval: Duration,
+}
+
+impl CachePolicy {
+    /// Creates a new [`CachePolicy`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `update_interval` - Interval between supply updates.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`CachePolicy`] instance.
+    pub fn new(update_interval: Duration) -> Self {
+        Self { update_interval }
+    }
+
+    /// Returns the number of seconds until the snapshot is due to be
+    /// replaced, or zero if the refresh is overdue.
+    ///
+    /// # Arguments
+    ///
+    /// * `supply` - The snapshot being served.
+    /// * `now` - The current time.
+    pub fn max_age(&self, supply: &TokenSupply, now: DateTime<Utc>) -> u64 {
+        let interval = TimeDelta::from_std(self.update_interval).unwrap_or(TimeDelta::MAX);
+        let next_refresh = supply.fetched_at.checked_add_signed(interval);
+
+        next_refresh
+            .map(|at| (at - now).num_seconds().max(0) as u64)
+            .unwrap_or(0)
+            .min(self.update_interval.as_secs())
+    }
+
+    /// Returns whether the snapshot has missed at least one refresh, which
+    /// suggests the worker is failing to fetch.
+    ///
+    /// # Arguments
+    ///
+    /// * `supply` - The snapshot being served.
+    /// * `now` - The current time.
+    pub fn is_stale(&self, supply: &TokenSupply, now: DateTime<Utc>) -> bool {
+        let interval = TimeDelta::from_std(self.update_interval).unwrap_or(TimeDelta::MAX);
+
+        match interval.checked_mul(2) {
+            Some(limit) => now - supply.fetched_at > limit,
+            None => false,
+        }
+    }
+
+    /// Builds the response for a supply snapshot, honouring conditional
+    /// requests.
+    ///
+    /// The `ETag` is derived from the snapshot's version and fetch time, and
+    /// `Last-Modified` is its fetch time. A request whose `If-None-Match` or
+    /// `If-Modified-Since` shows it already has the snapshot gets an empty
+    /// `304 Not Modified`. A snapshot that was never stored carries no
+    /// validators and must not be cached.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The request being answered.
+    /// * `supply` - The snapshot the body was built from.
+    /// * `body` - The response body.
+    pub fn respond(
+        &self,
+        req: &HttpRequest,
+        supply: &TokenSupply,
+        body: impl Serialize,
+    ) -> HttpResponse {
+        if supply.version == 0 {
+            return HttpResponse::Ok()
+                .insert_header(CacheControl(vec![CacheDirective::NoCache]))
+                .json(body);
+        }
+
+        let etag = entity_tag(supply);
+        let last_modified = last_modified(supply);
+        let max_age = self.max_age(supply, Utc::now());
+
+        let headers = |mut builder: HttpResponseBuilder| {
+            builder
+                .insert_header(ETag(etag.clone()))
+                .insert_header(LastModified(HttpDate::from(last_modified)))
+                .insert_header(CacheControl(vec![
+                    CacheDirective::Public,
+                    CacheDirective::MaxAge(max_age as u32),
+                ]));
+            builder
+        };
+
+        match is_not_modified(req, &etag, last_modified) {
+            true => headers(HttpResponse::NotModified()).finish(),
+            false => headers(HttpResponse::Ok()).json(body),
+        }
+    }
+}
+
+fn entity_tag(supply: &TokenSupply) -> EntityTag {
+    EntityTag::new_strong(format!(
+        "{}-{}",
+        supply.version,
+        supply.fetched_at.timestamp_millis()
+    ))
+}
+
+/// The fetch time truncated to whole seconds, the precision of HTTP dates.
+fn last_modified(supply: &TokenSupply) -> SystemTime {
+    let seconds = supply.fetched_at.timestamp().max(0) as u64;
+    SystemTime::UNIX_EPOCH + Duration::from_secs(seconds)
+}
+
+/// Evaluates the request's preconditions against the current snapshot.
+///
+/// `If-Modified-Since` is only considered when `If-None-Match` is absent, as
+/// required by RFC 9110.
+fn is_not_modified(req: &HttpRequest, etag: &EntityTag, last_modified: SystemTime) -> bool {
+    if let Some(if_none_match) = req.get_header::<IfNoneMatch>() {
+        return match if_none_match {
+            IfNoneMatch::Any => true,
+            IfNoneMatch::Items(tags) => tags.iter().any(|tag| tag.weak_eq(etag)),
+        };
+    }
+
+    match req.get_header::<IfModifiedSince>() {
+        Some(IfModifiedSince(since)) => last_modified <= SystemTime::from(since),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::http::StatusCode;
+    use actix_web::http::header;
+    use actix_web::test::TestRequest;
+
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Test Helper Functions
+
+    fn create_policy() -> CachePolicy {
+        CachePolicy::new(Duration::from_secs(600))
+    }
+
+    fn create_supply(fetched_at: DateTime<Utc>) -> TokenSupply {
+        let mut supply = TokenSupply::new("1000.00", "500.00").with_fetched_at(fetched_at);
+        supply.version = 7;
+        supply
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[test]
+    fn max_age_counts_down_to_next_refresh() {
+        // Arrange.
+        let now = Utc::now();
+        let supply = create_supply(now - TimeDelta::seconds(200));
+
+        // Act.
+        let max_age = create_policy().max_age(&supply, now);
+
+        // Assert.
+        assert_eq!(max_age, 400);
+    }
+
+    #[test]
+    fn max_age_is_zero_when_refresh_is_overdue() {
+        // Arrange.
+        let now = Utc::now();
+        let supply = create_supply(now - TimeDelta::seconds(900));
+
+        // Act.
+        let max_age = create_policy().max_age(&supply, now);
+
+        // Assert.
+        assert_eq!(max_age, 0);
+    }
+
+    #[test]
+    fn is_stale_once_a_refresh_is_missed() {
+        // Arrange.
+        let now = Utc::now();
+        let overdue = create_supply(now - TimeDelta::seconds(900));
+        let missed = create_supply(now - TimeDelta::seconds(1201));
+
+        // Act & Assert.
+        assert!(!create_policy().is_stale(&overdue, now));
+        assert!(create_policy().is_stale(&missed, now));
+    }
+
+    #[test]
+    fn respond_sets_validators() {
+        // Arrange.
+        let supply = create_supply(Utc::now());
+        let req = TestRequest::default().to_http_request();
+
+        // Act.
+        let response = create_policy().respond(&req, &supply, "body");
+
+        // Assert.
+        let etag = response.headers().get(header::ETAG).unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            etag.to_str().unwrap(),
+            format!("\"7-{}\"", supply.fetched_at.timestamp_millis())
+        );
+        assert!(response.headers().contains_key(header::LAST_MODIFIED));
+    }
+
+    #[test]
+    fn respond_returns_not_modified_for_matching_etag() {
+        // Arrange.
+        let supply = create_supply(Utc::now());
+        let etag = format!("W/\"7-{}\"", supply.fetched_at.timestamp_millis());
+        let req = TestRequest::default()
+            .insert_header((header::IF_NONE_MATCH, etag))
+            .to_http_request();
+
+        // Act.
+        let response = create_policy().respond(&req, &supply, "body");
+
+        // Assert.
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    }
+
+    #[test]
+    fn respond_ignores_if_modified_since_when_etag_does_not_match() {
+        // Arrange.
+        let supply = create_supply(Utc::now());
+        let req = TestRequest::default()
+            .insert_header((header::IF_NONE_MATCH, "\"6-0\""))
+            .insert_header(IfModifiedSince(HttpDate::from(SystemTime::now())))
+            .to_http_request();
+
+        // Act.
+        let response = create_policy().respond(&req, &supply, "body");
+
+        // Assert.
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[test]
+    fn respond_does_not_cache_unversioned_supply() {
+        // Arrange.
+        let supply = TokenSupply::default();
+        let req = TestRequest::default()
+            .insert_header((header::IF_NONE_MATCH, "*"))
+            .to_http_request();
+
+        // Act.
+        let response = create_policy().respond(&req, &supply, "body");
+
+        // Assert.
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(!response.headers().contains_key(header::ETAG));
+    }
+}
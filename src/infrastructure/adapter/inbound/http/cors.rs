@@ -0,0 +1,40 @@
+use actix_cors::Cors;
+
+use crate::infrastructure::configuration::cors::CorsConfig;
+
+/// Builds the CORS middleware described by the configuration.
+///
+/// A `*` entry allows any origin, method or header. Requests from origins that
+/// are not allowed are still served, but without CORS headers, so browsers
+/// block them while non-browser clients are unaffected.
+///
+/// # Arguments
+///
+/// * `config` - The CORS configuration, which should have passed validation.
+///
+/// # Returns
+///
+/// The CORS middleware.
+pub fn cors(config: &CorsConfig) -> Cors {
+    let mut cors = Cors::default();
+
+    cors = match config.allowed_origins.iter().any(|o| o == "*") {
+        true => cors.allow_any_origin().send_wildcard(),
+        false => config
+            .allowed_origins
+            .iter()
+            .fold(cors, |cors, origin| cors.allowed_origin(origin)),
+    };
+
+    cors = match config.allowed_methods.iter().any(|m| m == "*") {
+        true => cors.allow_any_method(),
+        false => cors.allowed_methods(config.allowed_methods.iter().map(String::as_str)),
+    };
+
+    cors = match config.allowed_headers.iter().any(|h| h == "*") {
+        true => cors.allow_any_header(),
+        false => cors.allowed_headers(config.allowed_headers.iter().map(String::as_str)),
+    };
+
+    cors.max_age(config.max_age)
+}
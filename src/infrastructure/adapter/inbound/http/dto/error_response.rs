@@ -0,0 +1,31 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::infrastructure::adapter::inbound::http::error::{ApiError, ErrorCode};
+
+#[derive(Serialize, ToSchema)]
+pub struct ErrorResponse {
+    code: ErrorCode,
+
+    #[schema(example = "token supply has not been fetched yet")]
+    message: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "0b6a3ae4-5c0e-4bba-9e2b-2f8d9c0d2a51")]
+    request_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = 30)]
+    retry_after: Option<u64>,
+}
+
+impl ErrorResponse {
+    pub fn new(error: &ApiError, request_id: Option<String>) -> Self {
+        Self {
+            code: error.code,
+            message: error.message.clone(),
+            request_id,
+            retry_after: error.retry_after,
+        }
+    }
+}
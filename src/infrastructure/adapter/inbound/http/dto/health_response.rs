@@ -0,0 +1,20 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::infrastructure::worker::WorkerStatus;
+
+#[derive(Serialize, ToSchema)]
+pub struct HealthResponse {
+    #[schema(example = "unhealthy")]
+    status: &'static str,
+    worker: WorkerStatus,
+}
+
+impl HealthResponse {
+    pub fn unhealthy(worker: WorkerStatus) -> Self {
+        Self {
+            status: "unhealthy",
+            worker,
+        }
+    }
+}
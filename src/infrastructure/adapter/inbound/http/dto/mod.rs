@@ -3,6 +3,22 @@
 //! DTOs handle the serialization and deserialization of HTTP request and
 //! response payloads. They provide a stable external API contract while
 //! allowing internal domain models to evolve independently.
+pub mod error_response;
+pub mod health_response;
+pub mod refresh_response;
+pub mod source_request;
+pub mod source_response;
+pub mod supply_document_response;
 pub mod supply_response;
+pub mod token_details_response;
+pub mod token_response;
 
+pub use error_response::ErrorResponse;
+pub use health_response::HealthResponse;
+pub use refresh_response::RefreshResponse;
+pub use source_request::SourceRequest;
+pub use source_response::SourceResponse;
+pub use supply_document_response::{AmountResponse, SupplyDocumentResponse};
 pub use supply_response::SupplyResponse;
+pub use token_details_response::TokenDetailsResponse;
+pub use token_response::TokenResponse;
@@ -0,0 +1,36 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::domain::model::TokenSupply;
+
+#[derive(Serialize, ToSchema)]
+pub struct RefreshResponse {
+    #[schema(example = "refreshed")]
+    status: &'static str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "2000000000.00")]
+    total_supply: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "1500000000.00")]
+    circulating_supply: Option<String>,
+}
+
+impl RefreshResponse {
+    pub fn accepted() -> Self {
+        Self {
+            status: "accepted",
+            total_supply: None,
+            circulating_supply: None,
+        }
+    }
+
+    pub fn refreshed(supply: TokenSupply) -> Self {
+        Self {
+            status: "refreshed",
+            total_supply: Some(supply.total_supply),
+            circulating_supply: Some(supply.circulating_supply),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct SourceRequest {
+    #[schema(example = "Treasury")]
+    pub name: String,
+
+    #[schema(example = "0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118")]
+    pub address: String,
+}
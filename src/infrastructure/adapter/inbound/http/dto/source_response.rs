@@ -0,0 +1,22 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::domain::model::Source;
+
+#[derive(Serialize, ToSchema)]
+pub struct SourceResponse {
+    #[schema(example = "Treasury")]
+    name: String,
+
+    #[schema(example = "0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118")]
+    address: String,
+}
+
+impl From<Source> for SourceResponse {
+    fn from(source: Source) -> Self {
+        Self {
+            name: source.name,
+            address: source.address.to_string(),
+        }
+    }
+}
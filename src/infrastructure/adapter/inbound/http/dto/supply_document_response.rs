@@ -0,0 +1,64 @@
+use alloy::primitives::U256;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::domain::model::{Token, TokenSupply};
+
+use super::TokenResponse;
+
+#[derive(Serialize, ToSchema)]
+pub struct AmountResponse {
+    #[schema(example = "1500000000000000000000000000")]
+    raw: String,
+
+    #[schema(example = "1500000000.000000000000000000")]
+    formatted: String,
+}
+
+impl AmountResponse {
+    fn new(raw: U256, formatted: String) -> Self {
+        Self {
+            raw: raw.to_string(),
+            formatted,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SupplyDocumentResponse {
+    token: TokenResponse,
+
+    total_supply: AmountResponse,
+
+    circulating_supply: AmountResponse,
+
+    #[schema(example = 8123456)]
+    block_number: Option<u64>,
+
+    #[schema(example = "2025-09-01T12:00:00Z")]
+    fetched_at: String,
+
+    #[schema(example = 42)]
+    age_seconds: u64,
+
+    #[schema(example = false)]
+    stale: bool,
+}
+
+impl SupplyDocumentResponse {
+    pub fn new(supply: TokenSupply, token: Token, now: DateTime<Utc>, stale: bool) -> Self {
+        Self {
+            token: TokenResponse::from(token),
+            total_supply: AmountResponse::new(supply.raw_total_supply, supply.total_supply),
+            circulating_supply: AmountResponse::new(
+                supply.raw_circulating_supply,
+                supply.circulating_supply,
+            ),
+            block_number: supply.block_number,
+            fetched_at: supply.fetched_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+            age_seconds: (now - supply.fetched_at).num_seconds().max(0) as u64,
+            stale,
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use serde::Serialize;
+use utoipa::ToSchema;
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct SupplyResponse {
+    #[schema(example = "1500000000.00")]
     result: String,
 }
 
@@ -0,0 +1,28 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::TokenResponse;
+
+#[derive(Serialize, ToSchema)]
+pub struct TokenDetailsResponse {
+    #[serde(flatten)]
+    token: TokenResponse,
+
+    #[schema(example = 11155111)]
+    chain_id: u64,
+
+    #[schema(
+        example = "https://sepolia.etherscan.io/token/0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118"
+    )]
+    explorer_url: Option<String>,
+}
+
+impl TokenDetailsResponse {
+    pub fn new(token: TokenResponse, chain_id: u64, explorer_url: Option<String>) -> Self {
+        Self {
+            token,
+            chain_id,
+            explorer_url,
+        }
+    }
+}
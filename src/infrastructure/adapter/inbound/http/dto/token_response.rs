@@ -0,0 +1,30 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::domain::model::Token;
+
+#[derive(Serialize, ToSchema)]
+pub struct TokenResponse {
+    #[schema(example = "Supply")]
+    name: String,
+
+    #[schema(example = "SUPPLY")]
+    symbol: String,
+
+    #[schema(example = "0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118")]
+    address: String,
+
+    #[schema(example = 18)]
+    decimals: u8,
+}
+
+impl From<Token> for TokenResponse {
+    fn from(token: Token) -> Self {
+        Self {
+            name: token.name,
+            symbol: token.symbol,
+            address: token.address.to_string(),
+            decimals: token.decimals,
+        }
+    }
+}
@@ -0,0 +1,322 @@
+use std::fmt::Display;
+
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::StatusCode;
+use actix_web::http::header;
+use actix_web::middleware::Next;
+use actix_web::{HttpMessage, HttpResponse, ResponseError};
+use serde::Serialize;
+use tracing_actix_web::RequestId;
+use utoipa::ToSchema;
+
+use super::dto::ErrorResponse;
+
+/// Stable, machine-readable error codes.
+///
+/// Clients should branch on the code rather than the message, which is meant
+/// for people and may change. Each code always comes with the same status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    /// The request is malformed, such as a body that is not valid JSON.
+    InvalidRequest,
+
+    /// An address is not valid, or not checksummed where required.
+    InvalidAddress,
+
+    /// A source is invalid, such as one without a name.
+    InvalidSource,
+
+    /// The admin credential is missing or invalid.
+    Unauthorized,
+
+    /// The admin endpoints require a client certificate.
+    ClientCertificateRequired,
+
+    /// The request is not allowed.
+    Forbidden,
+
+    /// No route matches the path.
+    NotFound,
+
+    /// The route does not support the method.
+    MethodNotAllowed,
+
+    /// No excluded source has the address.
+    SourceNotFound,
+
+    /// A source with the address is already excluded.
+    SourceExists,
+
+    /// The client exceeded its rate limit.
+    RateLimited,
+
+    /// An unexpected error occurred. The details are logged.
+    InternalError,
+
+    /// A requested refresh failed.
+    RefreshFailed,
+
+    /// No token supply has been fetched or restored yet.
+    SupplyNotInitialised,
+
+    /// The service cannot handle the request right now.
+    ServiceUnavailable,
+
+    /// A requested refresh did not complete in time.
+    RefreshTimeout,
+}
+
+impl ErrorCode {
+    /// Returns the HTTP status that accompanies the code.
+    pub fn status(&self) -> StatusCode {
+        match self {
+            ErrorCode::InvalidRequest | ErrorCode::InvalidAddress | ErrorCode::InvalidSource => {
+                StatusCode::BAD_REQUEST
+            }
+            ErrorCode::Unauthorized => StatusCode::UNAUTHORIZED,
+            ErrorCode::ClientCertificateRequired | ErrorCode::Forbidden => StatusCode::FORBIDDEN,
+            ErrorCode::NotFound | ErrorCode::SourceNotFound => StatusCode::NOT_FOUND,
+            ErrorCode::MethodNotAllowed => StatusCode::METHOD_NOT_ALLOWED,
+            ErrorCode::SourceExists => StatusCode::CONFLICT,
+            ErrorCode::RateLimited => StatusCode::TOO_MANY_REQUESTS,
+            ErrorCode::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+            ErrorCode::RefreshFailed => StatusCode::BAD_GATEWAY,
+            ErrorCode::SupplyNotInitialised | ErrorCode::ServiceUnavailable => {
+                StatusCode::SERVICE_UNAVAILABLE
+            }
+            ErrorCode::RefreshTimeout => StatusCode::GATEWAY_TIMEOUT,
+        }
+    }
+
+    /// Returns the generic code for an error status produced outside the
+    /// handlers, such as by the router or a failed extractor.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The status of the response.
+    pub fn from_status(status: StatusCode) -> Self {
+        match status {
+            StatusCode::UNAUTHORIZED => ErrorCode::Unauthorized,
+            StatusCode::FORBIDDEN => ErrorCode::Forbidden,
+            StatusCode::NOT_FOUND => ErrorCode::NotFound,
+            StatusCode::METHOD_NOT_ALLOWED => ErrorCode::MethodNotAllowed,
+            StatusCode::TOO_MANY_REQUESTS => ErrorCode::RateLimited,
+            StatusCode::SERVICE_UNAVAILABLE => ErrorCode::ServiceUnavailable,
+            s if s.is_client_error() => ErrorCode::InvalidRequest,
+            _ => ErrorCode::InternalError,
+        }
+    }
+}
+
+/// An error returned to an API client.
+///
+/// Rendered as an [`ErrorResponse`] envelope with the status of its code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+    /// The machine-readable code.
+    pub code: ErrorCode,
+
+    /// A description for people.
+    pub message: String,
+
+    /// Seconds the client should wait before retrying, if known.
+    pub retry_after: Option<u64>,
+}
+
+impl ApiError {
+    /// Creates a new [`ApiError`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The machine-readable code.
+    /// * `message` - A description for people.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`ApiError`] instance.
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            retry_after: None,
+        }
+    }
+
+    /// Creates an [`ErrorCode::InternalError`] without leaking its cause,
+    /// which the caller is expected to log.
+    pub fn internal() -> Self {
+        Self::new(ErrorCode::InternalError, "an unexpected error occurred")
+    }
+
+    /// Advises the client when to retry.
+    ///
+    /// # Arguments
+    ///
+    /// * `seconds` - Seconds the client should wait before retrying.
+    ///
+    /// # Returns
+    ///
+    /// * The [`ApiError`] instance with the retry advice attached.
+    pub fn with_retry_after(mut self, seconds: u64) -> Self {
+        self.retry_after = Some(seconds);
+        self
+    }
+
+    /// Renders the error as a response.
+    ///
+    /// # Arguments
+    ///
+    /// * `request_id` - The ID of the request being answered, if known.
+    pub fn to_response(&self, request_id: Option<String>) -> HttpResponse {
+        let mut response = HttpResponse::build(self.code.status());
+
+        if let Some(seconds) = self.retry_after {
+            response.insert_header((header::RETRY_AFTER, seconds.to_string()));
+        }
+
+        if self.code == ErrorCode::Unauthorized {
+            response.insert_header((header::WWW_AUTHENTICATE, "Bearer"));
+        }
+
+        response.json(ErrorResponse::new(self, request_id))
+    }
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl ResponseError for ApiError {
+    fn status_code(&self) -> StatusCode {
+        self.code.status()
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        self.to_response(None)
+    }
+}
+
+/// Middleware that renders every error response as an [`ErrorResponse`]
+/// envelope carrying the request ID.
+///
+/// Errors raised as [`ApiError`] keep their code. Errors from the framework,
+/// such as unknown routes, unsupported methods and malformed bodies, get the
+/// generic code for their status. Error responses that already have a JSON
+/// body of their own, such as the unhealthy `/healthz` report, are left alone.
+///
+/// # Arguments
+///
+/// * `req` - The request.
+/// * `next` - The rest of the service chain.
+pub async fn error_envelope<B: MessageBody>(
+    req: ServiceRequest,
+    next: Next<B>,
+) -> Result<ServiceResponse<EitherBody<B>>, actix_web::Error> {
+    let request_id = req.extensions().get::<RequestId>().map(ToString::to_string);
+
+    let res = next.call(req).await?;
+    let status = res.status();
+
+    if !status.is_client_error() && !status.is_server_error() {
+        return Ok(res.map_into_left_body());
+    }
+
+    let error = match res.response().error() {
+        Some(e) => match e.as_error::<ApiError>() {
+            Some(api_error) => api_error.clone(),
+            None if status.is_client_error() => {
+                ApiError::new(ErrorCode::from_status(status), e.to_string())
+            }
+            None => ApiError::new(ErrorCode::from_status(status), reason(status)),
+        },
+        None if has_json_body(&res) => return Ok(res.map_into_left_body()),
+        None => ApiError::new(ErrorCode::from_status(status), reason(status)),
+    };
+
+    // Replace the body in place so the original error stays attached to the
+    // response for the request logger.
+    let rendered = error.to_response(request_id);
+
+    Ok(res
+        .map_body(|head, _| {
+            head.status = rendered.status();
+            for (name, value) in rendered.headers() {
+                head.headers.insert(name.clone(), value.clone());
+            }
+            rendered.into_body()
+        })
+        .map_into_right_body())
+}
+
+fn reason(status: StatusCode) -> String {
+    status.canonical_reason().unwrap_or("error").to_lowercase()
+}
+
+fn has_json_body<B>(res: &ServiceResponse<B>) -> bool {
+    res.headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("application/json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_code_maps_back_from_its_status() {
+        // Arrange.
+        let generic = [
+            ErrorCode::InvalidRequest,
+            ErrorCode::Unauthorized,
+            ErrorCode::Forbidden,
+            ErrorCode::NotFound,
+            ErrorCode::MethodNotAllowed,
+            ErrorCode::RateLimited,
+            ErrorCode::InternalError,
+            ErrorCode::ServiceUnavailable,
+        ];
+
+        // Act & Assert.
+        for code in generic {
+            assert_eq!(ErrorCode::from_status(code.status()), code);
+        }
+    }
+
+    #[test]
+    fn to_response_sets_retry_after_header() {
+        // Arrange.
+        let error = ApiError::new(ErrorCode::RateLimited, "slow down").with_retry_after(7);
+
+        // Act.
+        let response = error.to_response(Some("abc".into()));
+
+        // Assert.
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers().get(header::RETRY_AFTER).unwrap(), "7");
+    }
+
+    #[test]
+    fn error_response_serializes_envelope() {
+        // Arrange.
+        let error = ApiError::new(ErrorCode::RateLimited, "slow down").with_retry_after(7);
+
+        // Act.
+        let body = serde_json::to_value(ErrorResponse::new(&error, Some("abc".into()))).unwrap();
+
+        // Assert.
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "code": "rate_limited",
+                "message": "slow down",
+                "request_id": "abc",
+                "retry_after": 7
+            })
+        );
+    }
+}
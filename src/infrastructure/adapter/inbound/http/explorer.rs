@@ -0,0 +1,67 @@
+use crate::domain::model::ChainAddress;
+
+/// Builds links to a block explorer.
+#[derive(Debug, Clone, Default)]
+pub struct Explorer {
+    base_url: Option<String>,
+}
+
+impl Explorer {
+    /// Creates a new [`Explorer`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - Base URL of the explorer, or `None` if there is none.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`Explorer`] instance.
+    pub fn new(base_url: Option<String>) -> Self {
+        Self {
+            base_url: base_url.map(|url| url.trim_end_matches('/').to_owned()),
+        }
+    }
+
+    /// Returns the explorer's page for a token, if there is an explorer.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the token.
+    pub fn token_url(&self, address: &ChainAddress) -> Option<String> {
+        self.base_url
+            .as_ref()
+            .map(|base| format!("{}/token/{}", base, address))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use super::*;
+
+    #[test]
+    fn token_url_links_checksummed_address() {
+        // Arrange.
+        let explorer = Explorer::new(Some("https://sepolia.etherscan.io/".into()));
+        let token = address!("0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118");
+
+        // Act.
+        let url = explorer.token_url(&token.into());
+
+        // Assert.
+        assert_eq!(
+            url.as_deref(),
+            Some("https://sepolia.etherscan.io/token/0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118")
+        );
+    }
+
+    #[test]
+    fn token_url_is_none_without_explorer() {
+        // Arrange.
+        let token = address!("0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118");
+
+        // Act & Assert.
+        assert_eq!(Explorer::new(None).token_url(&token.into()), None);
+    }
+}
@@ -4,8 +4,17 @@
 //! API. This adapter handles HTTP-specific concerns while delegating business
 //! logic to application services.
 
+pub mod auth;
+pub mod caching;
+pub mod cors;
 pub mod dto;
+pub mod error;
+pub mod explorer;
+pub mod openapi;
+pub mod rate_limit;
+pub mod resolved_token;
 pub mod route;
 pub mod server;
+pub mod tls;
 
 pub use server::HttpApplication;
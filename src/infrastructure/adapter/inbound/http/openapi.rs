@@ -0,0 +1,57 @@
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+use super::route::{
+    circulating_supply, health, metrics, refresh, sources, supply, token, total_supply,
+};
+
+/// The OpenAPI document of the HTTP API.
+///
+/// Generated from the route handlers and DTOs, so it cannot drift from the
+/// code. A copy is committed as `openapi.json` so that changes to the contract
+/// show up in review.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "CoinGecko Supply API",
+        description = "Total and circulating token supply in the format expected by CoinGecko.",
+        license(name = "MIT")
+    ),
+    paths(
+        total_supply::total_supply,
+        circulating_supply::circulating_supply,
+        token::token,
+        supply::supply,
+        health::health,
+        metrics::metrics,
+        refresh::refresh,
+        sources::list_sources,
+        sources::add_source,
+        sources::remove_source,
+    ),
+    tags(
+        (name = "supply", description = "Token supply for aggregators"),
+        (name = "admin", description = "Authenticated operations, served when admin credentials are configured"),
+        (name = "operations", description = "Health checks and metrics"),
+    ),
+    modifiers(&AdminSecurity)
+)]
+pub struct ApiDoc;
+
+/// Registers the credentials accepted by the admin endpoints.
+struct AdminSecurity;
+
+impl Modify for AdminSecurity {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+
+        components.add_security_scheme(
+            "bearer",
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+        );
+        components.add_security_scheme(
+            "api_key",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("X-API-Key"))),
+        );
+    }
+}
@@ -0,0 +1,534 @@
+use std::collections::{BTreeSet, HashMap};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::header::HeaderMap;
+use actix_web::middleware::Next;
+use actix_web::web::Data;
+use tracing::warn;
+
+use crate::infrastructure::configuration::rate_limit::{RateLimit, RateLimitConfig, TrustedProxy};
+
+use super::error::{ApiError, ErrorCode};
+
+/// The header a reverse proxy uses to pass on the client's address.
+const FORWARDED_FOR_HEADER: &str = "x-forwarded-for";
+
+/// At most this many clients are tracked; the least recently seen is evicted
+/// to make room for a new one.
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+/// A group of routes that share a rate limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteGroup {
+    /// The supply endpoints.
+    Public,
+
+    /// The admin endpoints.
+    Admin,
+}
+
+impl RouteGroup {
+    /// Converts the group to a string representation.
+    ///
+    /// # Returns
+    ///
+    /// A static string identifier for the group.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RouteGroup::Public => "public",
+            RouteGroup::Admin => "admin",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+/// The buckets of every tracked client, indexed by when each was last seen so
+/// idle and least recently seen clients can be found without a full scan.
+#[derive(Debug, Default)]
+struct Buckets {
+    by_client: HashMap<IpAddr, Bucket>,
+    by_last_seen: BTreeSet<(Instant, IpAddr)>,
+}
+
+impl Buckets {
+    fn len(&self) -> usize {
+        self.by_client.len()
+    }
+
+    fn take(&mut self, client: IpAddr) -> Option<Bucket> {
+        let bucket = self.by_client.remove(&client)?;
+        self.by_last_seen.remove(&(bucket.updated_at, client));
+        Some(bucket)
+    }
+
+    fn put(&mut self, client: IpAddr, bucket: Bucket) {
+        self.by_last_seen.insert((bucket.updated_at, client));
+        self.by_client.insert(client, bucket);
+    }
+
+    fn least_recently_seen(&self) -> Option<(IpAddr, Bucket)> {
+        let (_, client) = self.by_last_seen.first()?;
+        Some((*client, self.by_client[client]))
+    }
+}
+
+/// A token-bucket rate limiter with one bucket per client address.
+///
+/// Each bucket holds up to `burst` tokens and refills at the sustained rate.
+/// Every request takes one token, and a request that finds the bucket empty
+/// is rejected.
+#[derive(Debug)]
+pub struct RateLimiter {
+    group: RouteGroup,
+    capacity: f64,
+    refill_per_second: f64,
+    max_clients: usize,
+    buckets: Mutex<Buckets>,
+    rejected: AtomicU64,
+}
+
+impl RateLimiter {
+    /// Creates a new [`RateLimiter`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The route group the limiter applies to.
+    /// * `limit` - The limit applied to each client.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`RateLimiter`] instance.
+    pub fn new(group: RouteGroup, limit: RateLimit) -> Self {
+        Self {
+            group,
+            capacity: f64::from(limit.burst),
+            refill_per_second: f64::from(limit.requests_per_minute) / 60.0,
+            max_clients: MAX_TRACKED_CLIENTS,
+            buckets: Mutex::new(Buckets::default()),
+            rejected: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the route group the limiter applies to.
+    pub fn group(&self) -> RouteGroup {
+        self.group
+    }
+
+    /// Returns how many requests have been rejected.
+    pub fn rejected(&self) -> u64 {
+        self.rejected.load(Ordering::Relaxed)
+    }
+
+    /// Takes a token from the client's bucket.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client's address.
+    /// * `now` - The current time.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the request may proceed, or how long the client must wait
+    /// before its next request would be allowed.
+    pub fn acquire(&self, client: IpAddr, now: Instant) -> Result<(), Duration> {
+        let mut buckets = self.buckets.lock().unwrap();
+
+        // A full bucket behaves like a new one, so idle clients are dropped,
+        // oldest first. Each is dropped once, which keeps this cheap.
+        while let Some((idle, bucket)) = buckets.least_recently_seen() {
+            if self.refill(bucket, now).tokens < self.capacity {
+                break;
+            }
+            buckets.take(idle);
+        }
+
+        let bucket = match buckets.take(client) {
+            Some(bucket) => bucket,
+            None => {
+                if buckets.len() >= self.max_clients
+                    && let Some((evicted, _)) = buckets.least_recently_seen()
+                {
+                    buckets.take(evicted);
+                }
+
+                Bucket {
+                    tokens: self.capacity,
+                    updated_at: now,
+                }
+            }
+        };
+
+        let mut bucket = self.refill(bucket, now);
+        let allowed = bucket.tokens >= 1.0;
+
+        if allowed {
+            bucket.tokens -= 1.0;
+        }
+
+        buckets.put(client, bucket);
+
+        if allowed {
+            return Ok(());
+        }
+
+        self.rejected.fetch_add(1, Ordering::Relaxed);
+
+        let wait = (1.0 - bucket.tokens) / self.refill_per_second;
+        Err(Duration::from_secs_f64(wait))
+    }
+
+    fn refill(&self, bucket: Bucket, now: Instant) -> Bucket {
+        let elapsed = now.saturating_duration_since(bucket.updated_at);
+        let tokens = bucket.tokens + elapsed.as_secs_f64() * self.refill_per_second;
+
+        Bucket {
+            tokens: tokens.min(self.capacity),
+            updated_at: now,
+        }
+    }
+}
+
+/// The rate limiters of every route group, and how clients are identified.
+#[derive(Debug)]
+pub struct RateLimits {
+    trusted_proxies: Vec<TrustedProxy>,
+    public: Option<RateLimiter>,
+    admin: Option<RateLimiter>,
+}
+
+impl RateLimits {
+    /// Creates a new [`RateLimits`] instance from the configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The rate limit configuration.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`RateLimits`] instance.
+    pub fn new(config: &RateLimitConfig) -> Self {
+        Self {
+            trusted_proxies: config.trusted_proxies.clone(),
+            public: config
+                .public
+                .map(|l| RateLimiter::new(RouteGroup::Public, l)),
+            admin: config.admin.map(|l| RateLimiter::new(RouteGroup::Admin, l)),
+        }
+    }
+
+    /// Returns the limiter of a route group, if the group is limited.
+    pub fn limiter(&self, group: RouteGroup) -> Option<&RateLimiter> {
+        match group {
+            RouteGroup::Public => self.public.as_ref(),
+            RouteGroup::Admin => self.admin.as_ref(),
+        }
+    }
+
+    /// Returns the limiters of every limited route group.
+    pub fn limiters(&self) -> impl Iterator<Item = &RateLimiter> {
+        self.public.iter().chain(self.admin.iter())
+    }
+
+    /// Identifies the client that made a request.
+    ///
+    /// The peer address is used unless it is a trusted proxy. In that case the
+    /// `X-Forwarded-For` chain is walked from the nearest hop back, and the
+    /// first address that is not a trusted proxy is the client. Entries further
+    /// back are ignored, because the client could have forged them.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` - The request headers.
+    /// * `peer` - The address of the connected peer.
+    ///
+    /// # Returns
+    ///
+    /// The client's address, or `None` if it is unknown.
+    pub fn client_ip(&self, headers: &HeaderMap, peer: Option<SocketAddr>) -> Option<IpAddr> {
+        let peer = peer?.ip();
+
+        if !self.is_trusted(&peer) {
+            return Some(peer);
+        }
+
+        let forwarded: Vec<IpAddr> = headers
+            .get_all(FORWARDED_FOR_HEADER)
+            .filter_map(|v| v.to_str().ok())
+            .flat_map(|v| v.split(','))
+            .filter_map(|ip| ip.trim().parse().ok())
+            .collect();
+
+        let client = forwarded
+            .iter()
+            .rev()
+            .find(|ip| !self.is_trusted(ip))
+            .or(forwarded.first())
+            .copied();
+
+        Some(client.unwrap_or(peer))
+    }
+
+    fn is_trusted(&self, address: &IpAddr) -> bool {
+        self.trusted_proxies.iter().any(|p| p.contains(address))
+    }
+}
+
+/// Middleware that applies the rate limit of a route group.
+///
+/// Requests over the limit are answered with `429 Too Many Requests` and a
+/// `Retry-After` header, and counted as rejections of the group.
+///
+/// # Arguments
+///
+/// * `limits` - The rate limiters.
+/// * `group` - The route group the request belongs to.
+/// * `req` - The request.
+/// * `next` - The rest of the service chain.
+pub async fn rate_limit<B: MessageBody>(
+    limits: Data<RateLimits>,
+    group: RouteGroup,
+    req: ServiceRequest,
+    next: Next<B>,
+) -> Result<ServiceResponse<EitherBody<B>>, actix_web::Error> {
+    let Some(limiter) = limits.limiter(group) else {
+        return next
+            .call(req)
+            .await
+            .map(ServiceResponse::map_into_left_body);
+    };
+
+    let Some(client) = limits.client_ip(req.headers(), req.peer_addr()) else {
+        return next
+            .call(req)
+            .await
+            .map(ServiceResponse::map_into_left_body);
+    };
+
+    match limiter.acquire(client, Instant::now()) {
+        Ok(()) => next
+            .call(req)
+            .await
+            .map(ServiceResponse::map_into_left_body),
+        Err(wait) => {
+            let retry_after = wait.as_secs_f64().ceil().max(1.0) as u64;
+
+            warn!(
+                "Rate limited {} on the {} routes for {} seconds",
+                client,
+                group.as_str(),
+                retry_after
+            );
+
+            let error = ApiError::new(
+                ErrorCode::RateLimited,
+                format!("rate limit exceeded, retry in {} seconds", retry_after),
+            )
+            .with_retry_after(retry_after);
+
+            Ok(req.error_response(error).map_into_right_body())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::http::header::{self, HeaderValue};
+
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Test Helper Functions
+
+    fn create_limits(trusted_proxies: &[&str]) -> RateLimits {
+        RateLimits::new(&RateLimitConfig {
+            trusted_proxies: trusted_proxies
+                .iter()
+                .map(|p| TrustedProxy::try_from(p.to_string()).unwrap())
+                .collect(),
+            public: None,
+            admin: None,
+        })
+    }
+
+    fn forwarded_for(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::HeaderName::from_static(FORWARDED_FOR_HEADER),
+            HeaderValue::from_str(value).unwrap(),
+        );
+        headers
+    }
+
+    fn peer(ip: &str) -> Option<SocketAddr> {
+        Some(SocketAddr::new(ip.parse().unwrap(), 40000))
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[test]
+    fn bucket_allows_burst_then_rejects_with_wait() {
+        // Arrange.
+        let limit = RateLimit {
+            requests_per_minute: 60,
+            burst: 2,
+        };
+        let limiter = RateLimiter::new(RouteGroup::Public, limit);
+        let client = "203.0.113.7".parse().unwrap();
+        let now = Instant::now();
+
+        // Act.
+        let first = limiter.acquire(client, now);
+        let second = limiter.acquire(client, now);
+        let third = limiter.acquire(client, now);
+
+        // Assert.
+        assert!(first.is_ok() && second.is_ok());
+        assert_eq!(third, Err(Duration::from_secs(1)));
+        assert_eq!(limiter.rejected(), 1);
+    }
+
+    #[test]
+    fn bucket_refills_at_the_sustained_rate() {
+        // Arrange.
+        let limit = RateLimit {
+            requests_per_minute: 60,
+            burst: 1,
+        };
+        let limiter = RateLimiter::new(RouteGroup::Public, limit);
+        let client = "203.0.113.7".parse().unwrap();
+        let now = Instant::now();
+        limiter.acquire(client, now).unwrap();
+
+        // Act.
+        let too_soon = limiter.acquire(client, now + Duration::from_millis(500));
+        let refilled = limiter.acquire(client, now + Duration::from_millis(1500));
+
+        // Assert.
+        assert!(too_soon.is_err());
+        assert!(refilled.is_ok());
+    }
+
+    #[test]
+    fn clients_have_separate_buckets() {
+        // Arrange.
+        let limit = RateLimit {
+            requests_per_minute: 60,
+            burst: 1,
+        };
+        let limiter = RateLimiter::new(RouteGroup::Public, limit);
+        let now = Instant::now();
+
+        // Act.
+        let first = limiter.acquire("203.0.113.7".parse().unwrap(), now);
+        let second = limiter.acquire("203.0.113.8".parse().unwrap(), now);
+
+        // Assert.
+        assert!(first.is_ok() && second.is_ok());
+    }
+
+    #[test]
+    fn idle_clients_are_no_longer_tracked() {
+        // Arrange.
+        let limit = RateLimit {
+            requests_per_minute: 60,
+            burst: 1,
+        };
+        let limiter = RateLimiter::new(RouteGroup::Public, limit);
+        let now = Instant::now();
+        limiter
+            .acquire("203.0.113.7".parse().unwrap(), now)
+            .unwrap();
+
+        // Act.
+        let later = now + Duration::from_secs(2);
+        limiter
+            .acquire("203.0.113.8".parse().unwrap(), later)
+            .unwrap();
+
+        // Assert.
+        let buckets = limiter.buckets.lock().unwrap();
+        assert_eq!(buckets.len(), 1);
+        assert_eq!(buckets.by_last_seen.len(), 1);
+    }
+
+    #[test]
+    fn least_recently_seen_client_is_evicted_at_the_cap() {
+        // Arrange.
+        let limit = RateLimit {
+            requests_per_minute: 1,
+            burst: 1,
+        };
+        let mut limiter = RateLimiter::new(RouteGroup::Public, limit);
+        limiter.max_clients = 2;
+        let now = Instant::now();
+        let clients: Vec<IpAddr> = ["203.0.113.7", "203.0.113.8", "203.0.113.9"]
+            .iter()
+            .map(|ip| ip.parse().unwrap())
+            .collect();
+        limiter.acquire(clients[0], now).unwrap();
+        limiter
+            .acquire(clients[1], now + Duration::from_millis(1))
+            .unwrap();
+
+        // Act.
+        limiter
+            .acquire(clients[2], now + Duration::from_millis(2))
+            .unwrap();
+
+        // Assert.
+        let buckets = limiter.buckets.lock().unwrap();
+        assert_eq!(buckets.len(), 2);
+        assert!(!buckets.by_client.contains_key(&clients[0]));
+        assert!(buckets.by_client.contains_key(&clients[1]));
+        assert!(buckets.by_client.contains_key(&clients[2]));
+    }
+
+    #[test]
+    fn client_ip_ignores_forwarded_for_from_untrusted_peer() {
+        // Arrange.
+        let limits = create_limits(&["10.0.0.0/8"]);
+        let headers = forwarded_for("198.51.100.1");
+
+        // Act.
+        let client = limits.client_ip(&headers, peer("203.0.113.7"));
+
+        // Assert.
+        assert_eq!(client, Some("203.0.113.7".parse().unwrap()));
+    }
+
+    #[test]
+    fn client_ip_takes_first_untrusted_hop_from_trusted_proxy() {
+        // Arrange.
+        let limits = create_limits(&["10.0.0.0/8"]);
+        let headers = forwarded_for("192.0.2.99, 198.51.100.1, 10.0.0.5");
+
+        // Act.
+        let client = limits.client_ip(&headers, peer("10.0.0.2"));
+
+        // Assert.
+        assert_eq!(client, Some("198.51.100.1".parse().unwrap()));
+    }
+
+    #[test]
+    fn client_ip_falls_back_to_proxy_without_forwarded_for() {
+        // Arrange.
+        let limits = create_limits(&["10.0.0.2"]);
+
+        // Act.
+        let client = limits.client_ip(&HeaderMap::new(), peer("10.0.0.2"));
+
+        // Assert.
+        assert_eq!(client, Some("10.0.0.2".parse().unwrap()));
+    }
+}
@@ -0,0 +1,76 @@
+use tokio::sync::watch;
+
+use crate::domain::model::Token;
+
+use super::explorer::Explorer;
+
+/// The token resolved at startup and the explorer it is linked to, served by
+/// `/v1/token`.
+///
+/// The server starts before the token metadata is resolved, so the token is
+/// set once startup completes and is unavailable until then.
+#[derive(Debug)]
+pub struct ResolvedToken {
+    token: watch::Sender<Option<Token>>,
+    explorer: Explorer,
+}
+
+impl ResolvedToken {
+    /// Creates a new [`ResolvedToken`] instance, with no token yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `explorer` - Links the token to a block explorer.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`ResolvedToken`] instance.
+    pub fn new(explorer: Explorer) -> Self {
+        Self {
+            token: watch::Sender::new(None),
+            explorer,
+        }
+    }
+
+    /// Records the token resolved at startup.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The resolved token.
+    pub fn set(&self, token: Token) {
+        self.token.send_replace(Some(token));
+    }
+
+    /// Returns the resolved token, or `None` if startup has not completed.
+    pub fn get(&self) -> Option<Token> {
+        self.token.borrow().clone()
+    }
+
+    /// Returns the explorer the token is linked to.
+    pub fn explorer(&self) -> &Explorer {
+        &self.explorer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use super::*;
+
+    #[test]
+    fn token_is_none_until_set() {
+        // Arrange.
+        let resolved = ResolvedToken::new(Explorer::default());
+        let address = address!("0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118");
+        let token = Token::new("Supply", "SUPPLY", address, 18);
+
+        // Act.
+        let before = resolved.get();
+        resolved.set(token.clone());
+
+        // Assert.
+        assert_eq!(before, None);
+        assert_eq!(resolved.get(), Some(token));
+    }
+}
@@ -0,0 +1,49 @@
+use std::sync::LazyLock;
+
+use actix_web::HttpResponse;
+use actix_web::http::header::ContentType;
+use utoipa::OpenApi;
+
+use crate::infrastructure::adapter::inbound::http::openapi::ApiDoc;
+
+/// The OpenAPI document, generated once on first use.
+static SPEC: LazyLock<String> = LazyLock::new(|| {
+    ApiDoc::openapi()
+        .to_pretty_json()
+        .expect("OpenAPI document must serialize")
+});
+
+/// Renders `/openapi.json` with Redoc, loaded from its CDN.
+const DOCS_PAGE: &str = r#"<!DOCTYPE html>
+<html>
+  <head>
+    <title>CoinGecko Supply API</title>
+    <meta charset="utf-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1" />
+  </head>
+  <body>
+    <redoc spec-url="/openapi.json"></redoc>
+    <script src="https://cdn.redoc.ly/redoc/v2.5.0/bundles/redoc.standalone.js"></script>
+  </body>
+</html>
+"#;
+
+/// OpenAPI document endpoint.
+///
+/// Serves the OpenAPI 3 document describing every endpoint, for generating
+/// clients and detecting breaking changes.
+pub async fn openapi() -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(ContentType::json())
+        .body(SPEC.as_str())
+}
+
+/// Interactive API reference.
+///
+/// Serves a page that renders the OpenAPI document. Only registered when
+/// `server.api_docs` is enabled.
+pub async fn docs() -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(ContentType::html())
+        .body(DOCS_PAGE)
+}
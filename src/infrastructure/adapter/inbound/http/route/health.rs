@@ -1,9 +1,36 @@
 use actix_web::HttpResponse;
+use actix_web::web::Data;
+
+use crate::infrastructure::adapter::inbound::http::dto::HealthResponse;
+use crate::infrastructure::worker::WorkerMonitor;
 
 /// Health check endpoint.
 ///
 /// Provides a simple way to verify that the service is running and responding
-/// to requests. Returns a 200 OK status with an empty body.
-pub async fn health() -> HttpResponse {
-    HttpResponse::Ok().finish()
+/// to requests. Returns a 200 OK status with an empty body while the supply
+/// worker is starting or running.
+///
+/// If the worker has crashed or stopped, returns 503 Service Unavailable with
+/// the worker's state, restart count and last error as JSON.
+///
+/// # Arguments
+///
+/// * `monitor` - The supply worker's monitor.
+#[utoipa::path(
+    get,
+    path = "/healthz",
+    tag = "operations",
+    summary = "Reports whether the supply worker is healthy",
+    responses(
+        (status = 200, description = "The worker is starting or running"),
+        (status = 503, description = "The worker has crashed or stopped", body = HealthResponse),
+    )
+)]
+pub async fn health(monitor: Data<WorkerMonitor>) -> HttpResponse {
+    let status = monitor.status();
+
+    match status.state.is_healthy() {
+        true => HttpResponse::Ok().finish(),
+        false => HttpResponse::ServiceUnavailable().json(HealthResponse::unhealthy(status)),
+    }
 }
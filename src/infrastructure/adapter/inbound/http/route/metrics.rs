@@ -0,0 +1,84 @@
+use std::fmt::Write;
+
+use actix_web::HttpResponse;
+use actix_web::web::Data;
+
+use crate::infrastructure::adapter::inbound::http::rate_limit::RateLimits;
+use crate::infrastructure::worker::{WorkerMonitor, WorkerState};
+
+/// The content type of the Prometheus text exposition format.
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// Metrics endpoint.
+///
+/// Exposes the supply worker's state, restart count and rejected updates, and
+/// the number of rate limited requests per route group, in the Prometheus
+/// text format.
+///
+/// # Arguments
+///
+/// * `monitor` - The supply worker's monitor.
+/// * `rate_limits` - The rate limiters of the route groups.
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "operations",
+    summary = "Exposes metrics in the Prometheus text format",
+    responses(
+        (status = 200, description = "The metrics", body = String, content_type = "text/plain"),
+    )
+)]
+pub async fn metrics(monitor: Data<WorkerMonitor>, rate_limits: Data<RateLimits>) -> HttpResponse {
+    let status = monitor.status();
+    let mut body = String::new();
+
+    let _ = writeln!(
+        body,
+        "# HELP supply_worker_state Current state of the supply worker; 1 for the active state."
+    );
+    let _ = writeln!(body, "# TYPE supply_worker_state gauge");
+
+    for state in WorkerState::ALL {
+        let _ = writeln!(
+            body,
+            "supply_worker_state{{state=\"{}\"}} {}",
+            state.as_str(),
+            u8::from(state == status.state)
+        );
+    }
+
+    let _ = writeln!(
+        body,
+        "# HELP supply_worker_restarts_total Number of times the supply worker was restarted after a crash."
+    );
+    let _ = writeln!(body, "# TYPE supply_worker_restarts_total counter");
+    let _ = writeln!(body, "supply_worker_restarts_total {}", status.restarts);
+
+    let _ = writeln!(
+        body,
+        "# HELP supply_rejected_updates_total Number of fetched supplies rejected as implausible."
+    );
+    let _ = writeln!(body, "# TYPE supply_rejected_updates_total counter");
+    let _ = writeln!(
+        body,
+        "supply_rejected_updates_total {}",
+        status.rejected_updates
+    );
+
+    let _ = writeln!(
+        body,
+        "# HELP supply_rate_limited_requests_total Number of requests rejected by the rate limiter."
+    );
+    let _ = writeln!(body, "# TYPE supply_rate_limited_requests_total counter");
+
+    for limiter in rate_limits.limiters() {
+        let _ = writeln!(
+            body,
+            "supply_rate_limited_requests_total{{group=\"{}\"}} {}",
+            limiter.group().as_str(),
+            limiter.rejected()
+        );
+    }
+
+    HttpResponse::Ok().content_type(CONTENT_TYPE).body(body)
+}
@@ -3,9 +3,21 @@
 //! This module contains HTTP route handlers for the token supply API.
 
 pub mod circulating_supply;
+pub mod docs;
 pub mod health;
+pub mod metrics;
+pub mod refresh;
+pub mod sources;
+pub mod supply;
+pub mod token;
 pub mod total_supply;
 
 pub use circulating_supply::circulating_supply;
+pub use docs::{docs, openapi};
 pub use health::health;
+pub use metrics::metrics;
+pub use refresh::refresh;
+pub use sources::{add_source, list_sources, remove_source};
+pub use supply::supply;
+pub use token::token;
 pub use total_supply::total_supply;
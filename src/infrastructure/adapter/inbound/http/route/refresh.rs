@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use actix_web::HttpResponse;
+use actix_web::web::{Data, Query};
+use serde::Deserialize;
+use tokio::time::timeout;
+use tracing::{info, instrument, warn};
+use utoipa::IntoParams;
+
+use crate::infrastructure::adapter::inbound::http::auth::Admin;
+use crate::infrastructure::adapter::inbound::http::dto::{ErrorResponse, RefreshResponse};
+use crate::infrastructure::adapter::inbound::http::error::{ApiError, ErrorCode};
+use crate::infrastructure::worker::RefreshTrigger;
+
+/// The longest a caller can wait for a requested refresh to complete.
+const MAX_WAIT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct RefreshParams {
+    /// Wait for the refresh to complete and return its result.
+    #[serde(default)]
+    wait: bool,
+}
+
+/// Forces the supply worker to refresh immediately.
+///
+/// Returns `202 Accepted` once the worker has been woken. With `?wait=true`,
+/// waits for the refresh and returns the new supply, `502 Bad Gateway` if it
+/// failed, or `504 Gateway Timeout` if it did not complete in time.
+///
+/// Requests that arrive before the worker starts fetching share that fetch.
+///
+/// # Arguments
+///
+/// * `admin` - The authenticated caller.
+/// * `trigger` - The trigger that wakes the supply worker.
+/// * `params` - The query parameters.
+#[utoipa::path(
+    post,
+    path = "/admin/refresh",
+    tag = "admin",
+    summary = "Refreshes the supply immediately",
+    description = "Wakes the supply worker. Requests that arrive before the worker starts \
+                   fetching share that fetch.",
+    params(RefreshParams),
+    responses(
+        (status = 200, description = "The refresh completed", body = RefreshResponse),
+        (status = 202, description = "The worker was woken", body = RefreshResponse),
+        (status = 401, description = "Missing or invalid admin credential", body = ErrorResponse),
+        (status = 502, description = "The refresh failed", body = ErrorResponse),
+        (status = 504, description = "The refresh did not complete in time", body = ErrorResponse),
+    ),
+    security(("bearer" = []), ("api_key" = []))
+)]
+#[instrument(skip(trigger))]
+pub async fn refresh(
+    admin: Admin,
+    trigger: Data<RefreshTrigger>,
+    params: Query<RefreshParams>,
+) -> Result<HttpResponse, ApiError> {
+    let generation = trigger.request();
+    info!("{} requested a supply refresh", admin.name);
+
+    if !params.wait {
+        return Ok(HttpResponse::Accepted().json(RefreshResponse::accepted()));
+    }
+
+    match timeout(MAX_WAIT, trigger.wait_for(generation)).await {
+        Ok(outcome) => match outcome.result {
+            Ok(supply) => Ok(HttpResponse::Ok().json(RefreshResponse::refreshed(supply))),
+            Err(e) => {
+                warn!("Requested refresh failed: {}", e);
+                Err(ApiError::new(ErrorCode::RefreshFailed, e))
+            }
+        },
+        Err(_) => Err(ApiError::new(
+            ErrorCode::RefreshTimeout,
+            format!(
+                "the refresh did not complete within {} seconds",
+                MAX_WAIT.as_secs()
+            ),
+        )),
+    }
+}
@@ -0,0 +1,161 @@
+use actix_web::HttpResponse;
+use actix_web::web::{Data, Json, Path};
+use anyhow::Error;
+use tracing::{error, instrument, warn};
+
+use crate::application::port::inbound::SourceService;
+use crate::domain::error::SourceError;
+use crate::domain::model::{ChainAddress, Source};
+use crate::infrastructure::adapter::inbound::http::auth::Admin;
+use crate::infrastructure::adapter::inbound::http::dto::{
+    ErrorResponse, SourceRequest, SourceResponse,
+};
+use crate::infrastructure::adapter::inbound::http::error::{ApiError, ErrorCode};
+
+/// Lists the sources excluded from the circulating supply.
+///
+/// # Arguments
+///
+/// * `_admin` - The authenticated caller.
+/// * `service` - The service holding the live list of sources.
+#[utoipa::path(
+    get,
+    path = "/admin/sources",
+    tag = "admin",
+    summary = "Lists the sources excluded from the circulating supply",
+    responses(
+        (status = 200, description = "The excluded sources", body = [SourceResponse]),
+        (status = 401, description = "Missing or invalid admin credential", body = ErrorResponse),
+    ),
+    security(("bearer" = []), ("api_key" = []))
+)]
+#[instrument(skip(service))]
+pub async fn list_sources<L: SourceService>(_admin: Admin, service: Data<L>) -> HttpResponse {
+    let sources: Vec<SourceResponse> = service
+        .list_sources()
+        .await
+        .into_iter()
+        .map(SourceResponse::from)
+        .collect();
+
+    HttpResponse::Ok().json(sources)
+}
+
+/// Adds a source to the excluded sources.
+///
+/// EVM addresses must be EIP-55 checksummed, so that a mistyped address is
+/// rejected rather than silently excluding the wrong wallet. Solana addresses
+/// are base58 public keys and Cosmos addresses are bech32 accounts. Returns
+/// `201 Created` with the source, or `409 Conflict` if it is already excluded.
+///
+/// # Arguments
+///
+/// * `admin` - The authenticated caller.
+/// * `service` - The service holding the live list of sources.
+/// * `body` - The source to add.
+#[utoipa::path(
+    post,
+    path = "/admin/sources",
+    tag = "admin",
+    summary = "Excludes a source from the circulating supply",
+    description = "EVM addresses must be EIP-55 checksummed. Takes effect from the next refresh.",
+    request_body = SourceRequest,
+    responses(
+        (status = 201, description = "The source was added", body = SourceResponse),
+        (status = 400, description = "The source is invalid", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid admin credential", body = ErrorResponse),
+        (status = 409, description = "The source is already excluded", body = ErrorResponse),
+    ),
+    security(("bearer" = []), ("api_key" = []))
+)]
+#[instrument(skip(service))]
+pub async fn add_source<L: SourceService>(
+    admin: Admin,
+    service: Data<L>,
+    body: Json<SourceRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let SourceRequest { name, address } = body.into_inner();
+
+    let address = match ChainAddress::parse_checksummed(address.trim()) {
+        Ok(address) => address,
+        Err(e) => {
+            return Err(ApiError::new(
+                ErrorCode::InvalidAddress,
+                format!("`{}` is {}", address, e),
+            ));
+        }
+    };
+
+    let source = Source { name, address };
+
+    match service.add_source(source.clone(), &admin.name).await {
+        Ok(()) => Ok(HttpResponse::Created().json(SourceResponse::from(source))),
+        Err(e) => Err(api_error(e)),
+    }
+}
+
+/// Removes a source from the excluded sources.
+///
+/// Returns the removed source, or `404 Not Found` if no source has the
+/// address.
+///
+/// # Arguments
+///
+/// * `admin` - The authenticated caller.
+/// * `service` - The service holding the live list of sources.
+/// * `address` - The address of the source to remove.
+#[utoipa::path(
+    delete,
+    path = "/admin/sources/{address}",
+    tag = "admin",
+    summary = "Stops excluding a source",
+    params(("address" = String, Path, description = "The address of the source")),
+    responses(
+        (status = 200, description = "The removed source", body = SourceResponse),
+        (status = 400, description = "The address is invalid", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid admin credential", body = ErrorResponse),
+        (status = 404, description = "No source has the address", body = ErrorResponse),
+    ),
+    security(("bearer" = []), ("api_key" = []))
+)]
+#[instrument(skip(service))]
+pub async fn remove_source<L: SourceService>(
+    admin: Admin,
+    service: Data<L>,
+    address: Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let address = match address.parse::<ChainAddress>() {
+        Ok(address) => address,
+        Err(e) => {
+            return Err(ApiError::new(
+                ErrorCode::InvalidAddress,
+                format!("`{}` is {}", address, e),
+            ));
+        }
+    };
+
+    match service.remove_source(address, &admin.name).await {
+        Ok(source) => Ok(HttpResponse::Ok().json(SourceResponse::from(source))),
+        Err(e) => Err(api_error(e)),
+    }
+}
+
+fn api_error(e: Error) -> ApiError {
+    let Some(source_error) = e.downcast_ref::<SourceError>() else {
+        error!("Failed to change excluded sources: {}", e);
+        return ApiError::internal();
+    };
+
+    warn!("Rejected excluded source change: {}", source_error);
+
+    let code = match source_error {
+        SourceError::EmptyName | SourceError::ZeroAddress(_) | SourceError::TokenAddress(_) => {
+            ErrorCode::InvalidSource
+        }
+        SourceError::Duplicate(_) => ErrorCode::SourceExists,
+        SourceError::NotFound(_) => ErrorCode::SourceNotFound,
+        SourceError::UnsupportedAddress { .. } => ErrorCode::InvalidAddress,
+    };
+
+    ApiError::new(code, source_error.to_string())
+}
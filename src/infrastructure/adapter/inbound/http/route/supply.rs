@@ -0,0 +1,75 @@
+use actix_web::web::Data;
+use actix_web::{HttpRequest, HttpResponse};
+use chrono::Utc;
+use tracing::{error, instrument, warn};
+
+use crate::application::port::inbound::TokenSupplyService;
+use crate::domain::error::SupplyError;
+use crate::infrastructure::adapter::inbound::http::caching::CachePolicy;
+use crate::infrastructure::adapter::inbound::http::dto::{ErrorResponse, SupplyDocumentResponse};
+use crate::infrastructure::adapter::inbound::http::error::{ApiError, ErrorCode};
+
+/// Retrieves the full supply document.
+///
+/// Returns the token metadata, the total and circulating supply as both raw
+/// and formatted amounts, the block they were read at, and how old the
+/// snapshot is, in a single response.
+///
+/// # Arguments
+///
+/// * `req` - The request, checked for `If-None-Match` and `If-Modified-Since`.
+/// * `state` - The repository containing token supply data.
+/// * `cache` - How long the response may be cached, and when it is stale.
+#[utoipa::path(
+    get,
+    path = "/v2/supply",
+    tag = "supply",
+    summary = "Returns the supply document",
+    description = "Returns the token metadata, the total and circulating supply as raw and \
+                   formatted amounts, the block they were read at, and the snapshot's age. \
+                   `stale` is `true` once the snapshot has missed a refresh. Responses carry the \
+                   same caching headers as the `/v1` endpoints.",
+    responses(
+        (status = 200, description = "The supply document", body = SupplyDocumentResponse),
+        (status = 304, description = "The client's cached copy is current"),
+        (status = 429, description = "Rate limit exceeded", body = ErrorResponse),
+        (status = 503, description = "The supply has not been fetched yet", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(req, state, cache))]
+pub async fn supply(
+    req: HttpRequest,
+    state: Data<impl TokenSupplyService>,
+    cache: Data<CachePolicy>,
+) -> Result<HttpResponse, ApiError> {
+    let supply = match state.get_token_supply().await {
+        Ok(x) => x,
+        Err(e) if e.is::<SupplyError>() => {
+            warn!("Unable to return supply document: {}", e);
+            return Err(ApiError::new(
+                ErrorCode::SupplyNotInitialised,
+                e.to_string(),
+            ));
+        }
+        Err(e) => {
+            error!("Failed to return supply document: {}", e);
+            return Err(ApiError::internal());
+        }
+    };
+
+    // Snapshots stored by earlier versions do not record the token, so the
+    // document is unavailable until the worker next refreshes.
+    let Some(token) = supply.token.clone() else {
+        warn!("Unable to return supply document: the snapshot has no token metadata");
+        return Err(ApiError::new(
+            ErrorCode::SupplyNotInitialised,
+            "the stored supply has no token metadata yet",
+        ));
+    };
+
+    let now = Utc::now();
+    let stale = cache.is_stale(&supply, now);
+    let body = SupplyDocumentResponse::new(supply.clone(), token, now, stale);
+
+    Ok(cache.respond(&req, &supply, body))
+}
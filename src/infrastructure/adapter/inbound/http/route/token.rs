@@ -0,0 +1,64 @@
+use actix_web::HttpResponse;
+use actix_web::web::Data;
+use tracing::{instrument, warn};
+
+use crate::application::port::outbound::ChainIdProvider;
+use crate::infrastructure::adapter::inbound::http::dto::{
+    ErrorResponse, TokenDetailsResponse, TokenResponse,
+};
+use crate::infrastructure::adapter::inbound::http::error::{ApiError, ErrorCode};
+use crate::infrastructure::adapter::inbound::http::resolved_token::ResolvedToken;
+use crate::infrastructure::adapter::outbound::chain::ChainVerifier;
+
+/// Retrieves the token the service reports on.
+///
+/// Returns the metadata of the token resolved at startup, the verified chain
+/// ID, and a link to the token on a block explorer.
+///
+/// # Arguments
+///
+/// * `resolved` - The token resolved at startup and its block explorer.
+/// * `chain` - The verifier that confirms the connected chain.
+#[utoipa::path(
+    get,
+    path = "/v1/token",
+    tag = "supply",
+    summary = "Returns the token being reported on",
+    description = "Returns the token's name, symbol, address and decimals, the chain ID it was \
+                   verified on, and a link to the token on a block explorer. `explorer_url` is \
+                   `null` when no explorer is configured or known for the chain.",
+    responses(
+        (status = 200, description = "The token", body = TokenDetailsResponse),
+        (status = 429, description = "Rate limit exceeded", body = ErrorResponse),
+        (status = 503, description = "The token has not been resolved or the chain verified yet", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(resolved, chain))]
+pub async fn token<C>(
+    resolved: Data<ResolvedToken>,
+    chain: Data<ChainVerifier<C>>,
+) -> Result<HttpResponse, ApiError>
+where
+    C: ChainIdProvider,
+{
+    let Some(token) = resolved.get() else {
+        warn!("Unable to return token: the token has not been resolved");
+        return Err(ApiError::new(
+            ErrorCode::ServiceUnavailable,
+            "the token has not been resolved yet",
+        ));
+    };
+
+    let Some(chain_id) = chain.verified_chain_id() else {
+        warn!("Unable to return token: the chain has not been verified");
+        return Err(ApiError::new(
+            ErrorCode::ServiceUnavailable,
+            "the chain has not been verified yet",
+        ));
+    };
+
+    let explorer_url = resolved.explorer().token_url(&token.address);
+    let body = TokenDetailsResponse::new(TokenResponse::from(token), chain_id, explorer_url);
+
+    Ok(HttpResponse::Ok().json(body))
+}
@@ -1,9 +1,12 @@
-use actix_web::HttpResponse;
 use actix_web::web::Data;
-use tracing::{error, instrument};
+use actix_web::{HttpRequest, HttpResponse};
+use tracing::{error, instrument, warn};
 
 use crate::application::port::inbound::TokenSupplyService;
-use crate::infrastructure::adapter::inbound::http::dto::SupplyResponse;
+use crate::domain::error::SupplyError;
+use crate::infrastructure::adapter::inbound::http::caching::CachePolicy;
+use crate::infrastructure::adapter::inbound::http::dto::{ErrorResponse, SupplyResponse};
+use crate::infrastructure::adapter::inbound::http::error::{ApiError, ErrorCode};
 
 /// Retrieves the total supply of tokens.
 ///
@@ -12,17 +15,45 @@ use crate::infrastructure::adapter::inbound::http::dto::SupplyResponse;
 ///
 /// # Arguments
 ///
+/// * `req` - The request, checked for `If-None-Match` and `If-Modified-Since`.
 /// * `state` - The repository containing token supply data.
-#[instrument(skip(state))]
-pub async fn total_supply(state: Data<impl TokenSupplyService>) -> HttpResponse {
+/// * `cache` - How long the response may be cached.
+#[utoipa::path(
+    get,
+    path = "/v1/total",
+    tag = "supply",
+    summary = "Returns the total supply",
+    description = "Returns the formatted total supply. Responses carry `ETag`, `Last-Modified` \
+                   and `Cache-Control` headers, and conditional requests are answered with \
+                   `304 Not Modified` while the snapshot is unchanged.",
+    responses(
+        (status = 200, description = "The total supply", body = SupplyResponse),
+        (status = 304, description = "The client's cached copy is current"),
+        (status = 429, description = "Rate limit exceeded", body = ErrorResponse),
+        (status = 503, description = "The supply has not been fetched yet", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(req, state, cache))]
+pub async fn total_supply(
+    req: HttpRequest,
+    state: Data<impl TokenSupplyService>,
+    cache: Data<CachePolicy>,
+) -> Result<HttpResponse, ApiError> {
     match state.get_token_supply().await {
         Ok(x) => {
-            let b = SupplyResponse::new(x.total_supply);
-            HttpResponse::Ok().json(b)
+            let b = SupplyResponse::new(x.total_supply.clone());
+            Ok(cache.respond(&req, &x, b))
+        }
+        Err(e) if e.is::<SupplyError>() => {
+            warn!("Unable to return total supply: {}", e);
+            Err(ApiError::new(
+                ErrorCode::SupplyNotInitialised,
+                e.to_string(),
+            ))
         }
         Err(e) => {
             error!("Failed to return total supply: {}", e);
-            HttpResponse::InternalServerError().finish()
+            Err(ApiError::internal())
         }
     }
 }
@@ -1,15 +1,35 @@
 use std::net::TcpListener;
+use std::time::Duration;
 
-use actix_web::dev::Server;
+use actix_web::dev::{Server, ServerHandle};
+use actix_web::middleware::{Condition, from_fn};
 use actix_web::web::Data;
-use actix_web::{App, HttpServer, web};
+use actix_web::{App, HttpRequest, HttpServer, web};
 use anyhow::Error;
 use tracing_actix_web::TracingLogger;
 
-use crate::application::port::inbound::TokenSupplyService;
+use crate::application::port::inbound::{SourceService, TokenSupplyService};
+use crate::application::port::outbound::ChainIdProvider;
+use crate::infrastructure::adapter::outbound::chain::ChainVerifier;
 use crate::infrastructure::configuration::server::ServerConfig;
+use crate::infrastructure::worker::{RefreshTrigger, WorkerMonitor};
 
-use super::route::{circulating_supply, health, total_supply};
+use super::caching::CachePolicy;
+use super::cors::cors;
+use super::error::error_envelope;
+use super::rate_limit::{RateLimits, RouteGroup, rate_limit};
+use super::resolved_token::ResolvedToken;
+use super::route::{
+    add_source, circulating_supply, docs, health, list_sources, metrics, openapi, refresh,
+    remove_source, supply, token, total_supply,
+};
+use super::tls::{
+    capture_client_certificate, require_client_certificate, server_config, watch_certificates,
+};
+
+/// The extractors taken by the supply handlers, which `Route::to` cannot infer
+/// through their `impl TokenSupplyService` argument.
+type SupplyArgs<T> = (HttpRequest, Data<T>, Data<CachePolicy>);
 
 /// Main application structure responsible for server initialization.
 pub struct HttpApplication {
@@ -25,35 +45,144 @@ impl HttpApplication {
     ///
     /// # Arguments
     ///
-    /// * `config` - Server configuration
+    /// * `config` - Server configuration. Serves HTTPS when `tls` is set.
     /// * `data` - Application data.
+    /// * `monitor` - The supply worker's monitor, reported by `/healthz` and
+    ///   `/metrics`.
+    /// * `refresh_trigger` - Wakes the supply worker on `POST /admin/refresh`.
+    /// * `sources` - The live list of excluded sources managed under
+    ///   `/admin/sources`.
+    /// * `chain_verifier` - Confirms the chain reported by `/v1/token`.
+    /// * `resolved_token` - The token resolved at startup and its block
+    ///   explorer, served by `/v1/token`.
     ///
     /// # Returns
     ///
     /// `Result<Self, Error>` - The built application or an error.
-    pub async fn build<T>(config: ServerConfig, data: Data<T>) -> Result<Self, Error>
+    pub async fn build<T, L, C>(
+        config: ServerConfig,
+        data: Data<T>,
+        monitor: Data<WorkerMonitor>,
+        refresh_trigger: Data<RefreshTrigger>,
+        sources: Data<L>,
+        chain_verifier: Data<ChainVerifier<C>>,
+        resolved_token: Data<ResolvedToken>,
+    ) -> Result<Self, Error>
     where
         T: TokenSupplyService + Send + Sync + 'static,
+        L: SourceService + Send + Sync + 'static,
+        C: ChainIdProvider + Send + Sync + 'static,
     {
         let address = format!("{}:{}", config.host, config.port);
         let listener = TcpListener::bind(address)?;
         let port = listener.local_addr().unwrap().port();
+        let admin = Data::new(config.admin.clone());
+        let rate_limits = Data::new(RateLimits::new(&config.rate_limit));
+        let cache_policy = Data::new(CachePolicy::new(Duration::from_secs(
+            config.update_interval,
+        )));
+        let cors_config = config.cors.clone();
+        let api_docs = config.api_docs;
+        let require_client_cert = config
+            .tls
+            .as_ref()
+            .is_some_and(|tls| tls.client_ca_path.is_some());
 
         let server = HttpServer::new(move || {
             App::new()
                 .app_data(data.clone())
+                .app_data(monitor.clone())
+                .app_data(admin.clone())
+                .app_data(refresh_trigger.clone())
+                .app_data(sources.clone())
+                .app_data(rate_limits.clone())
+                .app_data(cache_policy.clone())
+                .app_data(chain_verifier.clone())
+                .app_data(resolved_token.clone())
+                .wrap(from_fn(error_envelope))
                 .wrap(TracingLogger::default())
                 .service(
                     web::scope("/v1")
-                        .route(
-                            "/circulating",
-                            web::get().to::<_, (Data<T>,)>(circulating_supply),
+                        .wrap(from_fn({
+                            let limits = rate_limits.clone();
+                            move |req, next| {
+                                rate_limit(limits.clone(), RouteGroup::Public, req, next)
+                            }
+                        }))
+                        .wrap(Condition::new(cors_config.is_enabled(), cors(&cors_config)))
+                        .service(
+                            web::resource("/circulating")
+                                .route(web::get().to::<_, SupplyArgs<T>>(circulating_supply)),
+                        )
+                        .service(
+                            web::resource("/total")
+                                .route(web::get().to::<_, SupplyArgs<T>>(total_supply)),
                         )
-                        .route("/total", web::get().to::<_, (Data<T>,)>(total_supply)),
+                        .service(web::resource("/token").route(web::get().to(token::<C>))),
+                )
+                .service(
+                    web::scope("/v2")
+                        .wrap(from_fn({
+                            let limits = rate_limits.clone();
+                            move |req, next| {
+                                rate_limit(limits.clone(), RouteGroup::Public, req, next)
+                            }
+                        }))
+                        .wrap(Condition::new(cors_config.is_enabled(), cors(&cors_config)))
+                        .service(
+                            web::resource("/supply")
+                                .route(web::get().to::<_, SupplyArgs<T>>(supply)),
+                        ),
                 )
-                .route("/healthz", web::get().to(health))
+                .service(web::resource("/healthz").route(web::get().to(health)))
+                .service(web::resource("/metrics").route(web::get().to(metrics)))
+                .service(web::resource("/openapi.json").route(web::get().to(openapi)))
+                .configure(|cfg| {
+                    if api_docs {
+                        cfg.service(web::resource("/docs").route(web::get().to(docs)));
+                    }
+                })
+                .configure(|cfg| {
+                    if admin.is_enabled() {
+                        cfg.service(
+                            web::scope("/admin")
+                                .wrap(Condition::new(
+                                    require_client_cert,
+                                    from_fn(require_client_certificate),
+                                ))
+                                .wrap(from_fn({
+                                    let limits = rate_limits.clone();
+                                    move |req, next| {
+                                        rate_limit(limits.clone(), RouteGroup::Admin, req, next)
+                                    }
+                                }))
+                                .service(web::resource("/refresh").route(web::post().to(refresh)))
+                                .service(
+                                    web::resource("/sources")
+                                        .route(web::get().to(list_sources::<L>))
+                                        .route(web::post().to(add_source::<L>)),
+                                )
+                                .service(
+                                    web::resource("/sources/{address}")
+                                        .route(web::delete().to(remove_source::<L>)),
+                                ),
+                        );
+                    }
+                })
         })
-        .listen(listener)?
+        .on_connect(capture_client_certificate)
+        .shutdown_timeout(config.shutdown_timeout)
+        .disable_signals();
+
+        let server = match &config.tls {
+            Some(tls) => {
+                let (tls_config, certificates) = server_config(tls)?;
+                watch_certificates(&certificates, Duration::from_secs(tls.reload_interval));
+
+                server.listen_rustls_0_23(listener, tls_config)?
+            }
+            None => server.listen(listener)?,
+        }
         .run();
 
         Ok(Self { port, server })
@@ -70,6 +199,19 @@ impl HttpApplication {
         self.port
     }
 
+    /// Returns a handle that can stop the server.
+    ///
+    /// The server does not listen for OS signals itself, so the caller decides
+    /// when to stop it. A graceful stop closes the listener and waits up to
+    /// `shutdown_timeout` seconds for in-flight requests to finish.
+    ///
+    /// # Returns
+    ///
+    /// The server handle.
+    pub fn handle(&self) -> ServerHandle {
+        self.server.handle()
+    }
+
     /// Runs the application until it is stopped.
     ///
     /// This method starts the HTTP server and blocks until the server shuts
@@ -0,0 +1,396 @@
+use std::any::Any;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock, Weak};
+use std::time::{Duration, SystemTime};
+
+use actix_tls::accept::rustls_0_23::TlsStream;
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{Extensions, ServiceRequest, ServiceResponse};
+use actix_web::middleware::Next;
+use actix_web::rt::net::TcpStream;
+use anyhow::{Context, Result, anyhow};
+use rustls::RootCertStore;
+use rustls::crypto::CryptoProvider;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::server::{ClientHello, ResolvesServerCert, WebPkiClientVerifier};
+use rustls::sign::CertifiedKey;
+use tracing::{error, info, warn};
+
+use crate::infrastructure::configuration::tls::TlsConfig;
+
+use super::error::{ApiError, ErrorCode};
+
+/// The modification times of the certificate and key files.
+type FileTimes = (SystemTime, SystemTime);
+
+/// Serves the current certificate, and swaps in a new one when the files on
+/// disk change.
+#[derive(Debug)]
+pub struct CertificateStore {
+    cert_path: PathBuf,
+    key_path: PathBuf,
+    provider: Arc<CryptoProvider>,
+    current: RwLock<Arc<CertifiedKey>>,
+    modified: Mutex<Option<FileTimes>>,
+}
+
+impl CertificateStore {
+    /// Loads the certificate and key.
+    ///
+    /// # Arguments
+    ///
+    /// * `cert_path` - PEM file holding the certificate chain, leaf first.
+    /// * `key_path` - PEM file holding the private key.
+    /// * `provider` - The crypto provider used to load the key.
+    ///
+    /// # Returns
+    ///
+    /// A new [`CertificateStore`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either file cannot be read, or the key does not
+    /// belong to the certificate.
+    pub fn load(
+        cert_path: impl Into<PathBuf>,
+        key_path: impl Into<PathBuf>,
+        provider: Arc<CryptoProvider>,
+    ) -> Result<Self> {
+        let cert_path = cert_path.into();
+        let key_path = key_path.into();
+
+        let modified = file_times(&cert_path, &key_path).ok();
+        let current = certified_key(&cert_path, &key_path, &provider)?;
+
+        Ok(Self {
+            cert_path,
+            key_path,
+            provider,
+            current: RwLock::new(Arc::new(current)),
+            modified: Mutex::new(modified),
+        })
+    }
+
+    /// Reloads the certificate and key if either file has changed.
+    ///
+    /// A pair that fails to load is not used, and the previous certificate
+    /// stays in service. The files are tried again on the next call, so a
+    /// rotation caught half way through is picked up once it completes.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a new certificate was loaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the files changed but could not be loaded.
+    pub fn reload_if_changed(&self) -> Result<bool> {
+        let modified = file_times(&self.cert_path, &self.key_path)?;
+
+        if *self.modified.lock().unwrap() == Some(modified) {
+            return Ok(false);
+        }
+
+        let key = certified_key(&self.cert_path, &self.key_path, &self.provider)?;
+
+        *self.current.write().unwrap() = Arc::new(key);
+        *self.modified.lock().unwrap() = Some(modified);
+
+        Ok(true)
+    }
+
+    /// Returns the certificate currently being served.
+    pub fn current(&self) -> Arc<CertifiedKey> {
+        self.current.read().unwrap().clone()
+    }
+}
+
+impl ResolvesServerCert for CertificateStore {
+    fn resolve(&self, _client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+        Some(self.current())
+    }
+}
+
+/// Builds the rustls configuration described by the TLS configuration.
+///
+/// When a client CA is configured, clients may present a certificate, which
+/// must then be issued by that CA. Clients without one are still accepted, and
+/// [`require_client_certificate`] decides which routes need it.
+///
+/// # Arguments
+///
+/// * `tls` - The TLS configuration.
+///
+/// # Returns
+///
+/// The rustls configuration, and the store serving its certificate.
+///
+/// # Errors
+///
+/// Returns an error if the certificate, key or client CAs cannot be loaded.
+pub fn server_config(tls: &TlsConfig) -> Result<(rustls::ServerConfig, Arc<CertificateStore>)> {
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let store = Arc::new(CertificateStore::load(
+        &tls.cert_path,
+        &tls.key_path,
+        provider.clone(),
+    )?);
+
+    let builder = rustls::ServerConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()?;
+
+    let builder = match &tls.client_ca_path {
+        Some(path) => {
+            let mut roots = RootCertStore::empty();
+
+            for cert in read_certificates(path)? {
+                roots.add(cert)?;
+            }
+
+            let verifier = WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider)
+                .allow_unauthenticated()
+                .build()?;
+
+            builder.with_client_cert_verifier(verifier)
+        }
+        None => builder.with_no_client_auth(),
+    };
+
+    Ok((builder.with_cert_resolver(store.clone()), store))
+}
+
+/// Checks the certificate files for changes at a fixed interval.
+///
+/// The task holds only a weak reference to the store, and ends once the
+/// server that uses the store is gone.
+///
+/// # Arguments
+///
+/// * `store` - The store to reload.
+/// * `interval` - How often to check the files.
+pub fn watch_certificates(store: &Arc<CertificateStore>, interval: Duration) {
+    let store: Weak<CertificateStore> = Arc::downgrade(store);
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.tick().await;
+
+        loop {
+            ticker.tick().await;
+
+            let Some(store) = store.upgrade() else {
+                break;
+            };
+
+            match store.reload_if_changed() {
+                Ok(true) => info!("Reloaded TLS certificate from {:?}", store.cert_path),
+                Ok(false) => {}
+                Err(e) => error!(
+                    "Failed to reload TLS certificate, keeping the old one: {:#}",
+                    e
+                ),
+            }
+        }
+    });
+}
+
+/// Marks a connection whose client presented a certificate that passed
+/// verification.
+#[derive(Debug, Clone, Copy)]
+pub struct VerifiedClientCertificate;
+
+/// Records on each connection whether the client presented a certificate.
+///
+/// Used as the server's `on_connect` callback. The certificate has already
+/// been checked against the client CAs during the handshake.
+///
+/// # Arguments
+///
+/// * `connection` - The accepted connection.
+/// * `data` - The connection's data, visible to every request on it.
+pub fn capture_client_certificate(connection: &dyn Any, data: &mut Extensions) {
+    let Some(stream) = connection.downcast_ref::<TlsStream<TcpStream>>() else {
+        return;
+    };
+
+    let (_, session) = stream.get_ref();
+
+    if session.peer_certificates().is_some_and(|c| !c.is_empty()) {
+        data.insert(VerifiedClientCertificate);
+    }
+}
+
+/// Middleware that rejects requests made without a verified client
+/// certificate.
+///
+/// # Arguments
+///
+/// * `req` - The request.
+/// * `next` - The rest of the service chain.
+pub async fn require_client_certificate<B: MessageBody>(
+    req: ServiceRequest,
+    next: Next<B>,
+) -> Result<ServiceResponse<EitherBody<B>>, actix_web::Error> {
+    if req.conn_data::<VerifiedClientCertificate>().is_some() {
+        return next
+            .call(req)
+            .await
+            .map(ServiceResponse::map_into_left_body);
+    }
+
+    warn!("Rejected {} without a client certificate", req.path());
+
+    let error = ApiError::new(
+        ErrorCode::ClientCertificateRequired,
+        "a valid client certificate is required",
+    );
+
+    Ok(req.error_response(error).map_into_right_body())
+}
+
+fn certified_key(
+    cert_path: &Path,
+    key_path: &Path,
+    provider: &CryptoProvider,
+) -> Result<CertifiedKey> {
+    let certs = read_certificates(cert_path)?;
+
+    let key = PrivateKeyDer::from_pem_file(key_path)
+        .with_context(|| format!("failed to read private key from {:?}", key_path))?;
+
+    CertifiedKey::from_der(certs, key, provider).with_context(|| {
+        format!(
+            "private key {:?} does not match certificate {:?}",
+            key_path, cert_path
+        )
+    })
+}
+
+fn read_certificates(path: &Path) -> Result<Vec<CertificateDer<'static>>> {
+    let certs = CertificateDer::pem_file_iter(path)
+        .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+        .with_context(|| format!("failed to read certificates from {:?}", path))?;
+
+    match certs.is_empty() {
+        true => Err(anyhow!("no certificates found in {:?}", path)),
+        false => Ok(certs),
+    }
+}
+
+fn file_times(cert_path: &Path, key_path: &Path) -> Result<FileTimes> {
+    let modified = |path: &Path| {
+        std::fs::metadata(path)
+            .and_then(|m| m.modified())
+            .with_context(|| format!("failed to read {:?}", path))
+    };
+
+    Ok((modified(cert_path)?, modified(key_path)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use tempfile::TempDir;
+
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Test Helper Functions
+
+    fn provider() -> Arc<CryptoProvider> {
+        Arc::new(rustls::crypto::ring::default_provider())
+    }
+
+    /// Writes a fresh self-signed certificate and key, and moves their
+    /// modification times forward so the change is always visible.
+    fn write_certificate(dir: &TempDir, age: u64) -> (PathBuf, PathBuf, Vec<u8>) {
+        let generated = rcgen::generate_simple_self_signed(vec!["localhost".into()]).unwrap();
+        let cert_path = dir.path().join("cert.pem");
+        let key_path = dir.path().join("key.pem");
+
+        std::fs::write(&cert_path, generated.cert.pem()).unwrap();
+        std::fs::write(&key_path, generated.key_pair.serialize_pem()).unwrap();
+
+        let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(age);
+        for path in [&cert_path, &key_path] {
+            File::options()
+                .write(true)
+                .open(path)
+                .and_then(|f| f.set_modified(modified))
+                .unwrap();
+        }
+
+        (cert_path, key_path, generated.cert.der().to_vec())
+    }
+
+    fn served_certificate(store: &CertificateStore) -> Vec<u8> {
+        store.current().cert[0].to_vec()
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[test]
+    fn reload_picks_up_rotated_files() {
+        // Arrange.
+        let dir = tempfile::tempdir().unwrap();
+        let (cert_path, key_path, _) = write_certificate(&dir, 1);
+        let store = CertificateStore::load(&cert_path, &key_path, provider()).unwrap();
+        let (_, _, rotated) = write_certificate(&dir, 2);
+
+        // Act.
+        let reloaded = store.reload_if_changed().unwrap();
+
+        // Assert.
+        assert!(reloaded);
+        assert_eq!(served_certificate(&store), rotated);
+    }
+
+    #[test]
+    fn reload_skips_unchanged_files() {
+        // Arrange.
+        let dir = tempfile::tempdir().unwrap();
+        let (cert_path, key_path, _) = write_certificate(&dir, 1);
+        let store = CertificateStore::load(&cert_path, &key_path, provider()).unwrap();
+
+        // Act.
+        let reloaded = store.reload_if_changed().unwrap();
+
+        // Assert.
+        assert!(!reloaded);
+    }
+
+    #[test]
+    fn reload_keeps_serving_old_certificate_when_new_files_are_invalid() {
+        // Arrange.
+        let dir = tempfile::tempdir().unwrap();
+        let (cert_path, key_path, original) = write_certificate(&dir, 1);
+        let store = CertificateStore::load(&cert_path, &key_path, provider()).unwrap();
+        std::fs::write(&key_path, "not a key").unwrap();
+
+        // Act.
+        let result = store.reload_if_changed();
+
+        // Assert.
+        assert!(result.is_err(), "Expected error for an invalid key");
+        assert_eq!(served_certificate(&store), original);
+    }
+
+    #[test]
+    fn load_rejects_key_that_does_not_match_certificate() {
+        // Arrange.
+        let dir = tempfile::tempdir().unwrap();
+        let (cert_path, _, _) = write_certificate(&dir, 1);
+        let other = rcgen::KeyPair::generate().unwrap();
+        let key_path = dir.path().join("other.pem");
+        std::fs::write(&key_path, other.serialize_pem()).unwrap();
+
+        // Act.
+        let result = CertificateStore::load(&cert_path, &key_path, provider());
+
+        // Assert.
+        assert!(result.is_err(), "Expected error for a mismatched key");
+    }
+}
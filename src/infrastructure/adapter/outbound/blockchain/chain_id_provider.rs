@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use alloy::providers::Provider;
+use anyhow::Result;
+
+use crate::application::port::outbound::ChainIdProvider;
+
+/// Provider for retrieving the chain ID from the blockchain.
+pub struct BlockchainChainIdProvider<P: Provider> {
+    provider: Arc<P>,
+}
+
+impl<P: Provider> BlockchainChainIdProvider<P> {
+    /// Creates a new [`BlockchainChainIdProvider`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The Alloy [`Provider`] to use for connections.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`BlockchainChainIdProvider`] instance.
+    pub fn new(provider: Arc<P>) -> Self {
+        Self { provider }
+    }
+}
+
+impl<P: Provider> ChainIdProvider for BlockchainChainIdProvider<P> {
+    async fn fetch_chain_id(&self) -> Result<u64> {
+        Ok(self.provider.get_chain_id().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::Address;
+    use alloy::providers::ProviderBuilder;
+
+    use super::super::mock_rpc::{Erc20State, MockRpc};
+    use super::*;
+
+    #[tokio::test]
+    async fn fetches_the_chain_id() {
+        // Arrange.
+        let rpc = MockRpc::start(
+            Erc20State::new(Address::ZERO, "Supply", "SUPPLY", 18).with_chain_id(11155111),
+        );
+        let provider = ProviderBuilder::new().on_http(rpc.url.parse().unwrap());
+
+        // Act.
+        let chain_id = BlockchainChainIdProvider::new(Arc::new(provider))
+            .fetch_chain_id()
+            .await;
+
+        // Assert.
+        assert_eq!(chain_id.unwrap(), 11155111);
+    }
+}
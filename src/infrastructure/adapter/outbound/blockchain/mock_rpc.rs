@@ -0,0 +1,387 @@
+//! A local stand-in for an EVM JSON-RPC node, for tests.
+//!
+//! The node answers `eth_chainId`, `eth_blockNumber` and `eth_call` against a
+//! single ERC-20 token whose state can be changed while it runs. Faults such as
+//! latency, RPC errors and reverts can be injected per call, so the adapters
+//! and the service built on them can be tested without network access.
+
+use std::collections::HashMap;
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use actix_web::{App, HttpResponse, HttpServer, web};
+use alloy::primitives::{Address, Bytes, FixedBytes, U256};
+use alloy::rpc::types::TransactionRequest;
+use alloy::sol_types::{SolInterface, SolValue};
+use serde_json::{Value, json};
+
+use super::contracts::IERC20;
+
+/// The JSON-RPC error code nodes use for reverted calls.
+const REVERT_CODE: i64 = 3;
+
+/// The state of the token served by a [`MockRpc`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Erc20State {
+    /// The chain ID reported by `eth_chainId`.
+    pub chain_id: u64,
+
+    /// The block number reported by `eth_blockNumber`.
+    pub block_number: u64,
+
+    /// The address of the token contract. Calls to any other address return
+    /// no data, as calls to an account without code do.
+    pub address: Address,
+
+    /// The name of the token, or `None` if `name()` reverts.
+    pub name: Option<String>,
+
+    /// The symbol of the token.
+    pub symbol: String,
+
+    /// The decimal places of the token.
+    pub decimals: u8,
+
+    /// Whether `name()` and `symbol()` return `bytes32`, as MKR does, instead
+    /// of `string`.
+    pub bytes32_metadata: bool,
+
+    /// The total supply, in the token's smallest unit.
+    pub total_supply: U256,
+
+    /// The balance of each holder, in the token's smallest unit.
+    pub balances: HashMap<Address, U256>,
+}
+
+impl Erc20State {
+    /// Creates a new [`Erc20State`] with no supply, on chain `1` at block `1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the token contract.
+    /// * `name` - The name of the token.
+    /// * `symbol` - The symbol of the token.
+    /// * `decimals` - The decimal places of the token.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`Erc20State`] instance.
+    pub fn new(
+        address: Address,
+        name: impl Into<String>,
+        symbol: impl Into<String>,
+        decimals: u8,
+    ) -> Self {
+        Self {
+            chain_id: 1,
+            block_number: 1,
+            address,
+            name: Some(name.into()),
+            symbol: symbol.into(),
+            decimals,
+            bytes32_metadata: false,
+            total_supply: U256::ZERO,
+            balances: HashMap::new(),
+        }
+    }
+
+    /// Sets the chain ID.
+    ///
+    /// # Returns
+    ///
+    /// The state, on the given chain.
+    pub fn with_chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    /// Sets the latest block number.
+    ///
+    /// # Returns
+    ///
+    /// The state, at the given block.
+    pub fn with_block_number(mut self, block_number: u64) -> Self {
+        self.block_number = block_number;
+        self
+    }
+
+    /// Sets the total supply.
+    ///
+    /// # Returns
+    ///
+    /// The state, with the given total supply.
+    pub fn with_total_supply(mut self, total_supply: U256) -> Self {
+        self.total_supply = total_supply;
+        self
+    }
+
+    /// Sets the balance of a holder.
+    ///
+    /// # Returns
+    ///
+    /// The state, with the holder's balance set.
+    pub fn with_balance(mut self, holder: Address, balance: U256) -> Self {
+        self.balances.insert(holder, balance);
+        self
+    }
+
+    /// Answers a call to the token contract.
+    fn call(&self, call: &IERC20::IERC20Calls) -> Option<Vec<u8>> {
+        let data = match call {
+            IERC20::IERC20Calls::name(_) => self.text(self.name.as_deref()?),
+            IERC20::IERC20Calls::symbol(_) => self.text(&self.symbol),
+            IERC20::IERC20Calls::decimals(_) => U256::from(self.decimals).abi_encode(),
+            IERC20::IERC20Calls::totalSupply(_) => self.total_supply.abi_encode(),
+            IERC20::IERC20Calls::balanceOf(c) => self
+                .balances
+                .get(&c._owner)
+                .copied()
+                .unwrap_or_default()
+                .abi_encode(),
+            _ => return None,
+        };
+
+        Some(data)
+    }
+
+    fn text(&self, text: &str) -> Vec<u8> {
+        match self.bytes32_metadata {
+            true => FixedBytes::<32>::right_padding_from(text.as_bytes()).abi_encode(),
+            false => text.to_string().abi_encode(),
+        }
+    }
+}
+
+/// A call a [`Fault`] can be injected into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RpcCall {
+    /// `eth_chainId`.
+    ChainId,
+
+    /// `eth_blockNumber`.
+    BlockNumber,
+
+    /// `eth_call` to `name()`.
+    Name,
+
+    /// `eth_call` to `symbol()`.
+    Symbol,
+
+    /// `eth_call` to `decimals()`.
+    Decimals,
+
+    /// `eth_call` to `totalSupply()`.
+    TotalSupply,
+
+    /// `eth_call` to `balanceOf(address)`.
+    BalanceOf,
+}
+
+impl RpcCall {
+    fn of(method: &str, call: Option<&IERC20::IERC20Calls>) -> Option<Self> {
+        match (method, call) {
+            ("eth_chainId", _) => Some(RpcCall::ChainId),
+            ("eth_blockNumber", _) => Some(RpcCall::BlockNumber),
+            (_, Some(IERC20::IERC20Calls::name(_))) => Some(RpcCall::Name),
+            (_, Some(IERC20::IERC20Calls::symbol(_))) => Some(RpcCall::Symbol),
+            (_, Some(IERC20::IERC20Calls::decimals(_))) => Some(RpcCall::Decimals),
+            (_, Some(IERC20::IERC20Calls::totalSupply(_))) => Some(RpcCall::TotalSupply),
+            (_, Some(IERC20::IERC20Calls::balanceOf(_))) => Some(RpcCall::BalanceOf),
+            _ => None,
+        }
+    }
+}
+
+/// A fault injected into the answers of a [`MockRpc`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fault {
+    /// The answer is delayed by the given duration.
+    Delay(Duration),
+
+    /// The call fails with the given JSON-RPC error.
+    Error {
+        /// The JSON-RPC error code.
+        code: i64,
+
+        /// The error message.
+        message: String,
+    },
+
+    /// The call reverts.
+    Revert,
+}
+
+struct Inner {
+    state: Erc20State,
+    faults: Vec<(RpcCall, Fault)>,
+    calls: Vec<(String, Value)>,
+}
+
+/// A local EVM JSON-RPC node serving a single ERC-20 token.
+///
+/// Every request is recorded, and can be inspected with [`MockRpc::calls`].
+#[derive(Clone)]
+pub struct MockRpc {
+    /// The URL the node listens on.
+    pub url: String,
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl MockRpc {
+    /// Starts a node serving the given token state.
+    ///
+    /// Must be called within a Tokio runtime; the node runs until the runtime
+    /// shuts down.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The initial state of the token.
+    ///
+    /// # Returns
+    ///
+    /// * A handle to the running node.
+    pub fn start(state: Erc20State) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind the mock RPC");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let inner = Arc::new(Mutex::new(Inner {
+            state,
+            faults: Vec::new(),
+            calls: Vec::new(),
+        }));
+
+        let server = HttpServer::new({
+            let inner = inner.clone();
+
+            move || {
+                let inner = inner.clone();
+
+                App::new().route(
+                    "/",
+                    web::post().to(move |request: web::Json<Value>| {
+                        let inner = inner.clone();
+
+                        async move {
+                            let (delay, body) = answer(&inner, &request);
+
+                            if !delay.is_zero() {
+                                tokio::time::sleep(delay).await;
+                            }
+
+                            HttpResponse::Ok().json(body)
+                        }
+                    }),
+                )
+            }
+        })
+        .workers(1)
+        .listen(listener)
+        .expect("Failed to start the mock RPC")
+        .run();
+
+        tokio::spawn(server);
+
+        Self { url, inner }
+    }
+
+    /// Changes the state of the token.
+    ///
+    /// # Arguments
+    ///
+    /// * `update` - Applied to the current state.
+    pub fn update(&self, update: impl FnOnce(&mut Erc20State)) {
+        update(&mut self.inner.lock().unwrap().state);
+    }
+
+    /// Injects a fault into every following matching call, until cleared.
+    ///
+    /// # Arguments
+    ///
+    /// * `call` - The call to fail.
+    /// * `fault` - How it fails.
+    pub fn inject(&self, call: RpcCall, fault: Fault) {
+        self.inner.lock().unwrap().faults.push((call, fault));
+    }
+
+    /// Removes every injected fault.
+    pub fn clear_faults(&self) {
+        self.inner.lock().unwrap().faults.clear();
+    }
+
+    /// Returns the method and parameters of every request received so far.
+    pub fn calls(&self) -> Vec<(String, Value)> {
+        self.inner.lock().unwrap().calls.clone()
+    }
+}
+
+/// Answers a JSON-RPC request, returning how long to delay the answer by.
+fn answer(inner: &Mutex<Inner>, request: &Value) -> (Duration, Value) {
+    let mut inner = inner.lock().unwrap();
+
+    let method = request["method"].as_str().unwrap_or_default();
+    let params = request["params"].clone();
+    inner.calls.push((method.to_string(), params.clone()));
+
+    let state = &inner.state;
+
+    let tx = match method {
+        "eth_call" => serde_json::from_value::<TransactionRequest>(params[0].clone()).ok(),
+        _ => None,
+    };
+    let to_token = tx
+        .as_ref()
+        .and_then(|tx| tx.to)
+        .and_then(|to| to.to().copied())
+        .is_some_and(|to| to == state.address);
+    let call = tx
+        .as_ref()
+        .and_then(|tx| tx.input.input())
+        .filter(|_| to_token)
+        .and_then(|input| IERC20::IERC20Calls::abi_decode(input, true).ok());
+
+    let mut delay = Duration::ZERO;
+    let mut failure = None;
+
+    if let Some(kind) = RpcCall::of(method, call.as_ref()) {
+        for (_, fault) in inner.faults.iter().filter(|(c, _)| *c == kind) {
+            match fault {
+                Fault::Delay(d) => delay += *d,
+                Fault::Error { code, message } => {
+                    failure.get_or_insert_with(|| json!({ "code": code, "message": message }));
+                }
+                Fault::Revert => {
+                    failure.get_or_insert_with(revert);
+                }
+            }
+        }
+    }
+
+    let result = match failure {
+        Some(error) => Err(error),
+        None => match method {
+            "eth_chainId" => Ok(json!(format!("0x{:x}", state.chain_id))),
+            "eth_blockNumber" => Ok(json!(format!("0x{:x}", state.block_number))),
+            "eth_call" if tx.is_none() => {
+                Err(json!({ "code": -32602, "message": "invalid params" }))
+            }
+            // Accounts without code return no data.
+            "eth_call" if !to_token => Ok(json!(Bytes::new())),
+            "eth_call" => match call.as_ref().and_then(|c| state.call(c)) {
+                Some(data) => Ok(json!(Bytes::from(data))),
+                None => Err(revert()),
+            },
+            _ => Err(json!({ "code": -32601, "message": "Method not found" })),
+        },
+    };
+
+    let body = match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": request["id"], "result": result }),
+        Err(error) => json!({ "jsonrpc": "2.0", "id": request["id"], "error": error }),
+    };
+
+    (delay, body)
+}
+
+fn revert() -> Value {
+    json!({ "code": REVERT_CODE, "message": "execution reverted", "data": "0x" })
+}
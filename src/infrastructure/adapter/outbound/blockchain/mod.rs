@@ -4,9 +4,30 @@
 //! networks and smart contracts. They handle the technical details of blockchain
 //! communication while providing a clean interface to the application layer.
 
+pub mod chain_id_provider;
 pub mod contracts;
+#[cfg(any(test, feature = "test-support"))]
+pub mod mock_rpc;
 pub mod token_metadata_provider;
 pub mod token_supply_provider;
 
+pub use chain_id_provider::BlockchainChainIdProvider;
 pub use token_metadata_provider::BlockchainTokenMetadataProvider;
 pub use token_supply_provider::BlockchainTokenSupplyProvider;
+
+use alloy::primitives::Address;
+use anyhow::{Result, anyhow};
+
+use crate::domain::model::ChainAddress;
+
+/// Returns the EVM address, or an error if the address belongs to another
+/// chain.
+fn evm_address(address: &ChainAddress) -> Result<Address> {
+    address.as_evm().ok_or_else(|| {
+        anyhow!(
+            "{} is a {} address, not an EVM address",
+            address,
+            address.kind()
+        )
+    })
+}
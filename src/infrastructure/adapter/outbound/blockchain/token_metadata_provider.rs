@@ -1,15 +1,25 @@
 use std::sync::Arc;
 
-use alloy::primitives::Address;
+use alloy::primitives::{Address, Bytes};
 use alloy::providers::Provider;
-use anyhow::Result;
+use alloy::rpc::types::TransactionRequest;
+use alloy::sol_types::{SolCall, SolValue};
+use anyhow::{Result, anyhow};
 use tracing::info;
 
 use crate::application::port::outbound::TokenMetadataProvider;
-use crate::domain::model::Token;
+use crate::domain::model::{ChainAddress, Token};
 use crate::infrastructure::adapter::outbound::blockchain::contracts::IERC20;
 
+use super::evm_address;
+
+/// The JSON-RPC error code nodes use for reverted calls.
+const REVERT_CODE: i64 = 3;
+
 /// Provider for retreiving token metadata from the blockchain.
+///
+/// Supports both standard tokens, which return `name()` and `symbol()` as
+/// `string`, and legacy tokens such as MKR, which return them as `bytes32`.
 pub struct BlockchainTokenMetadataProvider<P: Provider> {
     provider: Arc<P>,
 }
@@ -27,22 +37,58 @@ impl<P: Provider> BlockchainTokenMetadataProvider<P> {
     pub fn new(provider: Arc<P>) -> Self {
         Self { provider }
     }
+
+    /// Calls a parameterless text getter such as `name()` or `symbol()`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(String))` if the call returned a `string` or `bytes32`.
+    /// * `Ok(None)` if the call reverted or returned nothing usable.
+    /// * `Err` if the node could not be reached or failed to run the call.
+    async fn call_text(&self, token_address: Address, calldata: Vec<u8>) -> Result<Option<String>> {
+        let tx = TransactionRequest::default()
+            .to(token_address)
+            .input(Bytes::from(calldata).into());
+
+        match self.provider.call(&tx).await {
+            Ok(data) => Ok(decode_text(&data)),
+            // Only a revert means the getter is missing. Other error responses,
+            // such as rate limits, must not be cached as a missing name.
+            Err(e)
+                if e.as_error_resp()
+                    .is_some_and(|r| is_revert(r.code, &r.message)) =>
+            {
+                Ok(None)
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
 }
 
 impl<P: Provider> TokenMetadataProvider for BlockchainTokenMetadataProvider<P> {
-    async fn fetch_token_metadata(&self, token_address: Address) -> Result<Token> {
+    async fn fetch_token_metadata(&self, token_address: ChainAddress) -> Result<Token> {
         info!("Fetching token metadata for address: {}", token_address);
 
+        let token_address = evm_address(&token_address)?;
+
         let c = IERC20::new(token_address, &self.provider);
 
-        let n = c.name();
-        let s = c.symbol();
+        let n = self.call_text(token_address, IERC20::nameCall {}.abi_encode());
+        let s = self.call_text(token_address, IERC20::symbolCall {}.abi_encode());
         let d = c.decimals();
 
-        let (name_result, symbol_result, dec_result) = tokio::join!(n.call(), s.call(), d.call());
+        let (name_result, symbol_result, dec_result) = tokio::join!(n, s, d.call());
+
+        let symbol = symbol_result?
+            .ok_or_else(|| anyhow!("token {} does not implement symbol()", token_address))?;
+
+        let name = name_result?.ok_or_else(|| {
+            anyhow!(
+                "token {} does not implement name(); set token.name, token.symbol and token.decimals",
+                token_address
+            )
+        })?;
 
-        let name = name_result?._0;
-        let symbol = symbol_result?._0;
         let decimals = dec_result?._0;
 
         let token = Token::new(name, symbol, token_address, decimals);
@@ -52,3 +98,191 @@ impl<P: Provider> TokenMetadataProvider for BlockchainTokenMetadataProvider<P> {
         Ok(token)
     }
 }
+
+/// Returns `true` if an error response reports a reverted call.
+fn is_revert(code: i64, message: &str) -> bool {
+    code == REVERT_CODE || message.contains("execution reverted")
+}
+
+/// Decodes the return data of a text getter.
+///
+/// The data is first decoded as an ABI `string`. If that fails and exactly one
+/// word was returned, it is treated as a `bytes32` with trailing null padding.
+///
+/// # Returns
+///
+/// * The decoded text, or `None` if the data is empty or cannot be decoded.
+fn decode_text(data: &[u8]) -> Option<String> {
+    let text = match String::abi_decode(data, true) {
+        Ok(text) => text,
+        Err(_) if data.len() == 32 => {
+            let end = data.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+            String::from_utf8_lossy(&data[..end]).into_owned()
+        }
+        Err(_) => return None,
+    };
+
+    let text = text.trim_end_matches('\0').trim().to_string();
+
+    (!text.is_empty()).then_some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use alloy::primitives::{FixedBytes, address};
+    use alloy::providers::ProviderBuilder;
+
+    use super::super::mock_rpc::{Erc20State, Fault, MockRpc, RpcCall};
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Test Helper Functions
+
+    const TOKEN: Address = address!("0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118");
+
+    fn create_provider(rpc: &MockRpc) -> BlockchainTokenMetadataProvider<impl Provider> {
+        let provider = ProviderBuilder::new().on_http(rpc.url.parse().unwrap());
+
+        BlockchainTokenMetadataProvider::new(Arc::new(provider))
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[tokio::test]
+    async fn fetches_metadata_from_the_contract() {
+        // Arrange.
+        let rpc = MockRpc::start(Erc20State::new(TOKEN, "Supply", "SUPPLY", 18));
+
+        // Act.
+        let token = create_provider(&rpc)
+            .fetch_token_metadata(TOKEN.into())
+            .await
+            .unwrap();
+
+        // Assert.
+        assert_eq!(token, Token::new("Supply", "SUPPLY", TOKEN, 18));
+    }
+
+    #[tokio::test]
+    async fn supports_bytes32_metadata() {
+        // Arrange.
+        let mut state = Erc20State::new(TOKEN, "Maker", "MKR", 18);
+        state.bytes32_metadata = true;
+        let rpc = MockRpc::start(state);
+
+        // Act.
+        let token = create_provider(&rpc)
+            .fetch_token_metadata(TOKEN.into())
+            .await
+            .unwrap();
+
+        // Assert.
+        assert_eq!(token, Token::new("Maker", "MKR", TOKEN, 18));
+    }
+
+    #[tokio::test]
+    async fn rejects_tokens_without_a_name() {
+        // Arrange.
+        let rpc = MockRpc::start(Erc20State::new(TOKEN, "Supply", "SUPPLY", 18));
+        rpc.inject(RpcCall::Name, Fault::Revert);
+
+        // Act.
+        let result = create_provider(&rpc)
+            .fetch_token_metadata(TOKEN.into())
+            .await;
+
+        // Assert.
+        assert!(result.unwrap_err().to_string().contains("set token.name"));
+    }
+
+    #[tokio::test]
+    async fn propagates_node_errors_from_name() {
+        // Arrange.
+        let rpc = MockRpc::start(Erc20State::new(TOKEN, "Supply", "SUPPLY", 18));
+        rpc.inject(
+            RpcCall::Name,
+            Fault::Error {
+                code: -32603,
+                message: "internal error".into(),
+            },
+        );
+
+        // Act.
+        let result = create_provider(&rpc)
+            .fetch_token_metadata(TOKEN.into())
+            .await;
+
+        // Assert.
+        assert!(result.unwrap_err().to_string().contains("internal error"));
+    }
+
+    #[tokio::test]
+    async fn rejects_accounts_without_a_symbol() {
+        // Arrange.
+        let rpc = MockRpc::start(Erc20State::new(TOKEN, "Supply", "SUPPLY", 18));
+        let account = address!("0xB1a932A665FB0A1D5d7979cd63e80a59EDCe31B4");
+
+        // Act.
+        let result = create_provider(&rpc)
+            .fetch_token_metadata(account.into())
+            .await;
+
+        // Assert.
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("does not implement symbol()")
+        );
+    }
+
+    #[tokio::test]
+    async fn reads_metadata_concurrently() {
+        // Arrange.
+        let rpc = MockRpc::start(Erc20State::new(TOKEN, "Supply", "SUPPLY", 18));
+        for call in [RpcCall::Name, RpcCall::Symbol, RpcCall::Decimals] {
+            rpc.inject(call, Fault::Delay(Duration::from_millis(300)));
+        }
+        let provider = create_provider(&rpc);
+        let started = Instant::now();
+
+        // Act.
+        let token = provider.fetch_token_metadata(TOKEN.into()).await;
+
+        // Assert.
+        assert!(token.is_ok());
+        assert!(started.elapsed() < Duration::from_millis(900));
+    }
+
+    #[test]
+    fn decodes_abi_string() {
+        // Arrange.
+        let data = "Supply".to_string().abi_encode();
+
+        // Act & Assert.
+        assert_eq!(decode_text(&data).as_deref(), Some("Supply"));
+    }
+
+    #[test]
+    fn decodes_null_padded_bytes32() {
+        // Arrange.
+        let data = FixedBytes::<32>::right_padding_from(b"MKR").abi_encode();
+
+        // Act & Assert.
+        assert_eq!(decode_text(&data).as_deref(), Some("MKR"));
+    }
+
+    #[test]
+    fn rejects_empty_or_malformed_data() {
+        // Arrange.
+        let zeroed = FixedBytes::<32>::ZERO.abi_encode();
+
+        // Act & Assert.
+        assert_eq!(decode_text(&[]), None);
+        assert_eq!(decode_text(&[0x12, 0x34]), None);
+        assert_eq!(decode_text(&zeroed), None);
+    }
+}
@@ -1,14 +1,17 @@
 use std::sync::Arc;
 
-use alloy::primitives::utils;
+use alloy::eips::BlockId;
+use alloy::primitives::{U256, utils};
 use alloy::providers::Provider;
 use anyhow::Result;
 use tracing::info;
 
 use crate::application::port::outbound::TokenSupplyProvider;
-use crate::domain::model::{Source, Token, TokenSupply};
+use crate::domain::model::{Source, SourceBalance, Token, TokenSupply};
 use crate::infrastructure::adapter::outbound::blockchain::contracts::IERC20;
 
+use super::evm_address;
+
 /// Provider for retreiving token supply data from the blockchain.
 pub struct BlockchainTokenSupplyProvider<P: Provider> {
     provider: Arc<P>,
@@ -37,30 +40,172 @@ impl<P: Provider> TokenSupplyProvider for BlockchainTokenSupplyProvider<P> {
     ) -> Result<TokenSupply> {
         info!("Fetching token supply for: {}", token);
 
-        let c = IERC20::new(token.address, &self.provider);
+        // Read every balance at the same block so the numbers are consistent
+        // even if transfers land while the fetch is in progress.
+        let block_number = self.provider.get_block_number().await?;
+        let block = BlockId::number(block_number);
+
+        let c = IERC20::new(evm_address(&token.address)?, &self.provider);
 
-        let total_supply = c.totalSupply().call().await?._0;
-        let mut circulating_supply = total_supply;
+        let total_supply = c.totalSupply().block(block).call().await?._0;
+        let mut excluded = U256::ZERO;
+        let mut breakdown = Vec::with_capacity(excluded_sources.len());
 
         for i in excluded_sources.iter() {
             info!("Beginning check for {} at {}", i.name, i.address);
 
-            let bal = c.balanceOf(i.address).call().await?.balance;
+            let owner = evm_address(&i.address)?;
+            let bal = c.balanceOf(owner).block(block).call().await?.balance;
 
             info!("{}: {}", i.name, bal);
 
-            circulating_supply -= bal;
+            excluded = excluded.saturating_add(bal);
+
+            let formatted = utils::format_units(bal, token.decimals)?;
+            breakdown.push(
+                SourceBalance::new(i.name.clone(), i.address.clone(), formatted).with_raw(bal),
+            );
 
             info!("Finished check for {} at {}", i.name, i.address);
         }
 
-        let total_supply = utils::format_units(total_supply, token.decimals)?;
-        let circulating_supply = utils::format_units(circulating_supply, token.decimals)?;
-
-        let token_supply = TokenSupply::new(total_supply, circulating_supply);
+        let token_supply = TokenSupply::from_raw(total_supply, excluded, token.decimals)?
+            .with_block_number(block_number)
+            .with_breakdown(breakdown);
 
         info!("Token Supply: {}", token_supply);
 
         Ok(token_supply)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::{Address, address};
+    use alloy::providers::ProviderBuilder;
+
+    use super::super::mock_rpc::{Erc20State, Fault, MockRpc, RpcCall};
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Test Helper Functions
+
+    const TOKEN: Address = address!("0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118");
+    const SINK: Address = address!("0xB1a932A665FB0A1D5d7979cd63e80a59EDCe31B4");
+
+    fn start_rpc() -> MockRpc {
+        MockRpc::start(
+            Erc20State::new(TOKEN, "Supply", "SUPPLY", 6)
+                .with_block_number(8_123_456)
+                .with_total_supply(U256::from(10_000_000_000u64))
+                .with_balance(SINK, U256::from(2_500_000_000u64)),
+        )
+    }
+
+    fn create_provider(rpc: &MockRpc) -> BlockchainTokenSupplyProvider<impl Provider> {
+        let provider = ProviderBuilder::new().on_http(rpc.url.parse().unwrap());
+
+        BlockchainTokenSupplyProvider::new(Arc::new(provider))
+    }
+
+    fn create_token() -> Token {
+        Token::new("Supply", "SUPPLY", TOKEN, 6)
+    }
+
+    fn create_source(name: &str, address: Address) -> Source {
+        Source {
+            name: name.into(),
+            address: address.into(),
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[tokio::test]
+    async fn reads_every_balance_at_the_same_block() {
+        // Arrange.
+        let rpc = start_rpc();
+        let provider = create_provider(&rpc);
+        let sources = [
+            create_source("Sink", SINK),
+            create_source("Empty", Address::repeat_byte(0x11)),
+        ];
+
+        // Act.
+        let supply = provider
+            .fetch_token_supply(&create_token(), &sources)
+            .await
+            .unwrap();
+
+        // Assert.
+        assert_eq!(supply.total_supply, "10000.000000");
+        assert_eq!(supply.circulating_supply, "7500.000000");
+        assert_eq!(supply.raw_circulating_supply, U256::from(7_500_000_000u64));
+        assert_eq!(supply.block_number, Some(8_123_456));
+        assert_eq!(supply.breakdown[0].balance, "2500.000000");
+        assert_eq!(supply.breakdown[1].balance, "0.000000");
+
+        let calls = rpc.calls();
+        let eth_calls: Vec<_> = calls.iter().filter(|(m, _)| m == "eth_call").collect();
+        assert_eq!(eth_calls.len(), 3);
+        assert!(eth_calls.iter().all(|(_, params)| params[1] == "0x7bf440"));
+    }
+
+    #[tokio::test]
+    async fn surfaces_rpc_errors_and_reverts() {
+        // Arrange.
+        let rpc = start_rpc();
+        let provider = create_provider(&rpc);
+        let sources = [create_source("Sink", SINK)];
+
+        // Act.
+        rpc.inject(
+            RpcCall::BlockNumber,
+            Fault::Error {
+                code: -32005,
+                message: "rate limit exceeded".into(),
+            },
+        );
+        let unavailable = provider.fetch_token_supply(&create_token(), &sources).await;
+
+        rpc.clear_faults();
+        rpc.inject(RpcCall::BalanceOf, Fault::Revert);
+        let reverted = provider.fetch_token_supply(&create_token(), &sources).await;
+
+        // Assert.
+        assert!(
+            unavailable
+                .unwrap_err()
+                .to_string()
+                .contains("rate limit exceeded")
+        );
+        assert!(
+            reverted
+                .unwrap_err()
+                .to_string()
+                .contains("execution reverted")
+        );
+    }
+
+    #[tokio::test]
+    async fn reflects_state_changes() {
+        // Arrange.
+        let rpc = start_rpc();
+        let provider = create_provider(&rpc);
+
+        // Act.
+        rpc.update(|s| {
+            s.block_number += 1;
+            s.total_supply = U256::from(20_000_000_000u64);
+        });
+        let supply = provider
+            .fetch_token_supply(&create_token(), &[])
+            .await
+            .unwrap();
+
+        // Assert.
+        assert_eq!(supply.total_supply, "20000.000000");
+        assert_eq!(supply.block_number, Some(8_123_457));
+    }
+}
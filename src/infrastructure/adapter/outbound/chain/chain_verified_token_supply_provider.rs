@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::application::port::outbound::{ChainIdProvider, TokenSupplyProvider};
+use crate::domain::model::{Source, Token, TokenSupply};
+
+use super::chain_verifier::ChainVerifier;
+
+/// Token supply provider that refuses to fetch from an unverified chain.
+///
+/// Wraps another [`TokenSupplyProvider`] and checks the chain ID through a
+/// shared [`ChainVerifier`] before every fetch, so numbers from the wrong
+/// network are never published.
+pub struct ChainVerifiedTokenSupplyProvider<S, C> {
+    inner: S,
+    verifier: Arc<ChainVerifier<C>>,
+}
+
+impl<S, C> ChainVerifiedTokenSupplyProvider<S, C>
+where
+    S: TokenSupplyProvider,
+    C: ChainIdProvider,
+{
+    /// Creates a new [`ChainVerifiedTokenSupplyProvider`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The provider that fetches the token supply.
+    /// * `verifier` - The verifier used to confirm the connected chain.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`ChainVerifiedTokenSupplyProvider`] instance.
+    pub fn new(inner: S, verifier: Arc<ChainVerifier<C>>) -> Self {
+        Self { inner, verifier }
+    }
+}
+
+impl<S, C> TokenSupplyProvider for ChainVerifiedTokenSupplyProvider<S, C>
+where
+    S: TokenSupplyProvider + Sync,
+    C: ChainIdProvider + Send + Sync,
+{
+    async fn fetch_token_supply(
+        &self,
+        token: &Token,
+        excluded_sources: &[Source],
+    ) -> Result<TokenSupply> {
+        self.verifier.ensure_verified().await?;
+        self.inner.fetch_token_supply(token, excluded_sources).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+
+    use alloy::primitives::address;
+
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Mock Implementations for Testing
+
+    struct MockChainIdProvider(u64);
+
+    impl ChainIdProvider for MockChainIdProvider {
+        async fn fetch_chain_id(&self) -> Result<u64> {
+            Ok(self.0)
+        }
+    }
+
+    struct MockProvider {
+        fetch_count: Arc<AtomicU32>,
+    }
+
+    impl TokenSupplyProvider for MockProvider {
+        async fn fetch_token_supply(&self, _t: &Token, _s: &[Source]) -> Result<TokenSupply> {
+            self.fetch_count.fetch_add(1, Ordering::SeqCst);
+            Ok(TokenSupply::new("1000.00", "500.00"))
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Test Helper Functions
+
+    fn create_token() -> Token {
+        let name = "Supply";
+        let symbol = "SUPPLY";
+        let address = address!("0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118");
+        let decimals = 18;
+
+        Token::new(name, symbol, address, decimals)
+    }
+
+    fn create_provider(
+        reported_chain_id: u64,
+    ) -> (
+        ChainVerifiedTokenSupplyProvider<MockProvider, MockChainIdProvider>,
+        Arc<AtomicU32>,
+    ) {
+        let fetch_count = Arc::new(AtomicU32::new(0));
+        let inner = MockProvider {
+            fetch_count: fetch_count.clone(),
+        };
+
+        let chain = MockChainIdProvider(reported_chain_id);
+        let verifier = Arc::new(ChainVerifier::new(chain, 1, Duration::ZERO));
+
+        (
+            ChainVerifiedTokenSupplyProvider::new(inner, verifier),
+            fetch_count,
+        )
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[tokio::test]
+    async fn fetches_when_chain_matches() {
+        // Arrange.
+        let (provider, fetch_count) = create_provider(1);
+
+        // Act.
+        let result = provider.fetch_token_supply(&create_token(), &[]).await;
+
+        // Assert.
+        assert!(result.is_ok(), "Expected successful supply fetch");
+        assert_eq!(fetch_count.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn refuses_to_fetch_from_wrong_chain() {
+        // Arrange.
+        let (provider, fetch_count) = create_provider(11155111);
+
+        // Act.
+        let result = provider.fetch_token_supply(&create_token(), &[]).await;
+
+        // Assert.
+        assert!(result.is_err(), "Expected error on chain mismatch");
+        assert_eq!(fetch_count.load(Ordering::SeqCst), 0);
+    }
+}
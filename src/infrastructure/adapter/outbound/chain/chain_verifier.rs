@@ -0,0 +1,291 @@
+use std::fmt::Display;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use tracing::{error, info};
+
+use crate::application::port::outbound::ChainIdProvider;
+
+/// Error returned when the connected node serves a different chain than the
+/// one configured.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainMismatch {
+    /// The configured chain ID.
+    pub expected: u64,
+
+    /// The chain ID reported by the node.
+    pub actual: u64,
+}
+
+impl Display for ChainMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "chain ID mismatch: configured {} but the RPC reports {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl std::error::Error for ChainMismatch {}
+
+/// Confirms that the connected node serves the configured chain.
+///
+/// A successful verification is remembered for `recheck_interval`, after which
+/// [`ChainVerifier::ensure_verified`] asks the node again. This catches RPC
+/// endpoints that are silently re-pointed to a different network.
+pub struct ChainVerifier<C> {
+    provider: C,
+    expected_chain_id: u64,
+    recheck_interval: Duration,
+    verified_chain_id: AtomicU64,
+    last_verified: Mutex<Option<Instant>>,
+}
+
+impl<C: ChainIdProvider> ChainVerifier<C> {
+    /// Creates a new [`ChainVerifier`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - Component that fetches the chain ID from the node.
+    /// * `expected_chain_id` - The configured chain ID.
+    /// * `recheck_interval` - How long a successful verification stays valid.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`ChainVerifier`] instance.
+    pub fn new(provider: C, expected_chain_id: u64, recheck_interval: Duration) -> Self {
+        Self {
+            provider,
+            expected_chain_id,
+            recheck_interval,
+            verified_chain_id: AtomicU64::new(0),
+            last_verified: Mutex::new(None),
+        }
+    }
+
+    /// Returns the configured chain ID.
+    pub fn expected_chain_id(&self) -> u64 {
+        self.expected_chain_id
+    }
+
+    /// Returns the chain ID confirmed by the most recent verification, or
+    /// `None` if the chain has not been verified or the last check failed.
+    pub fn verified_chain_id(&self) -> Option<u64> {
+        match self.verified_chain_id.load(Ordering::Acquire) {
+            0 => None,
+            id => Some(id),
+        }
+    }
+
+    /// Asks the node for its chain ID and compares it to the expected value.
+    ///
+    /// # Returns
+    ///
+    /// The verified chain ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node could not be reached, or a
+    /// [`ChainMismatch`] if it reports a different chain. Either way the
+    /// chain is no longer considered verified.
+    pub async fn verify(&self) -> Result<u64> {
+        let actual = match self.provider.fetch_chain_id().await {
+            Ok(actual) => actual,
+            Err(e) => {
+                self.clear();
+                return Err(e);
+            }
+        };
+
+        if actual != self.expected_chain_id {
+            self.clear();
+
+            let mismatch = ChainMismatch {
+                expected: self.expected_chain_id,
+                actual,
+            };
+
+            error!("{}", mismatch);
+
+            return Err(mismatch.into());
+        }
+
+        self.verified_chain_id.store(actual, Ordering::Release);
+        *self.last_verified.lock().unwrap() = Some(Instant::now());
+
+        info!("Verified chain ID: {}", actual);
+
+        Ok(actual)
+    }
+
+    /// Verifies the chain unless a previous verification is still valid.
+    ///
+    /// # Returns
+    ///
+    /// The verified chain ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a re-check was due and failed.
+    pub async fn ensure_verified(&self) -> Result<u64> {
+        let due = match *self.last_verified.lock().unwrap() {
+            Some(at) => at.elapsed() >= self.recheck_interval,
+            None => true,
+        };
+
+        match due {
+            true => self.verify().await,
+            false => Ok(self.expected_chain_id),
+        }
+    }
+
+    /// Forgets the last successful verification.
+    fn clear(&self) {
+        self.verified_chain_id.store(0, Ordering::Release);
+        *self.last_verified.lock().unwrap() = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use anyhow::anyhow;
+
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Mock Implementations for Testing
+
+    struct MockChainIdProvider {
+        chain_id: Arc<AtomicU64>,
+        call_count: Arc<AtomicU64>,
+    }
+
+    impl MockChainIdProvider {
+        fn new(chain_id: u64) -> Self {
+            Self {
+                chain_id: Arc::new(AtomicU64::new(chain_id)),
+                call_count: Arc::new(AtomicU64::new(0)),
+            }
+        }
+    }
+
+    impl ChainIdProvider for MockChainIdProvider {
+        async fn fetch_chain_id(&self) -> Result<u64> {
+            self.call_count.fetch_add(1, Ordering::SeqCst);
+
+            match self.chain_id.load(Ordering::SeqCst) {
+                0 => Err(anyhow!("Simulated RPC failure")),
+                id => Ok(id),
+            }
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[tokio::test]
+    async fn verify_accepts_matching_chain() {
+        // Arrange.
+        let verifier = ChainVerifier::new(MockChainIdProvider::new(1), 1, Duration::ZERO);
+
+        // Act.
+        let result = verifier.verify().await;
+
+        // Assert.
+        assert_eq!(result.unwrap(), 1);
+        assert_eq!(verifier.verified_chain_id(), Some(1));
+    }
+
+    #[tokio::test]
+    async fn verify_rejects_mismatched_chain() {
+        // Arrange.
+        let verifier = ChainVerifier::new(MockChainIdProvider::new(11155111), 1, Duration::ZERO);
+
+        // Act.
+        let result = verifier.verify().await;
+
+        // Assert.
+        let err = result.expect_err("Expected error on chain mismatch");
+        assert_eq!(
+            err.downcast_ref::<ChainMismatch>(),
+            Some(&ChainMismatch {
+                expected: 1,
+                actual: 11155111
+            })
+        );
+        assert_eq!(verifier.verified_chain_id(), None);
+    }
+
+    #[tokio::test]
+    async fn ensure_verified_skips_rpc_within_recheck_interval() {
+        // Arrange.
+        let provider = MockChainIdProvider::new(1);
+        let call_count = provider.call_count.clone();
+        let verifier = ChainVerifier::new(provider, 1, Duration::from_secs(3600));
+
+        // Act.
+        verifier.ensure_verified().await.unwrap();
+        verifier.ensure_verified().await.unwrap();
+
+        // Assert.
+        assert_eq!(call_count.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn ensure_verified_detects_repointed_rpc() {
+        // Arrange.
+        let provider = MockChainIdProvider::new(1);
+        let chain_id = provider.chain_id.clone();
+        let verifier = ChainVerifier::new(provider, 1, Duration::ZERO);
+        verifier.ensure_verified().await.unwrap();
+
+        // Act.
+        chain_id.store(11155111, Ordering::SeqCst);
+        let result = verifier.ensure_verified().await;
+
+        // Assert.
+        assert!(
+            result.is_err(),
+            "Expected error after the RPC was re-pointed"
+        );
+        assert_eq!(verifier.verified_chain_id(), None);
+    }
+
+    #[tokio::test]
+    async fn verify_propagates_provider_errors() {
+        // Arrange.
+        let verifier = ChainVerifier::new(MockChainIdProvider::new(0), 1, Duration::ZERO);
+
+        // Act.
+        let result = verifier.verify().await;
+
+        // Assert.
+        assert!(result.is_err(), "Expected error when the provider fails");
+    }
+
+    #[tokio::test]
+    async fn verify_clears_the_verified_chain_when_the_provider_fails() {
+        // Arrange.
+        let provider = MockChainIdProvider::new(1);
+        let chain_id = provider.chain_id.clone();
+        let verifier = ChainVerifier::new(provider, 1, Duration::from_secs(3600));
+        verifier.verify().await.unwrap();
+
+        // Act.
+        chain_id.store(0, Ordering::SeqCst);
+        let result = verifier.verify().await;
+
+        // Assert.
+        assert!(result.is_err(), "Expected error when the provider fails");
+        assert_eq!(verifier.verified_chain_id(), None);
+        assert!(
+            verifier.ensure_verified().await.is_err(),
+            "Expected a re-check after the failed verification"
+        );
+    }
+}
@@ -0,0 +1,10 @@
+//! Chain-Neutral Outbound Adapters
+//!
+//! Adapters that work with the providers of any supported chain, such as the
+//! verification that the connected node serves the configured chain.
+
+pub mod chain_verified_token_supply_provider;
+pub mod chain_verifier;
+
+pub use chain_verified_token_supply_provider::ChainVerifiedTokenSupplyProvider;
+pub use chain_verifier::{ChainMismatch, ChainVerifier};
@@ -0,0 +1,129 @@
+use std::fmt::Display;
+use std::sync::Arc;
+
+use anyhow::Result;
+use serde::Deserialize;
+
+use crate::application::port::outbound::ChainIdProvider;
+
+use super::lcd_client::{AtHeight, CosmosLcdClient};
+
+/// Error returned when the connected node serves a different Cosmos chain
+/// than the one configured.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkMismatch {
+    /// The configured network.
+    pub expected: String,
+
+    /// The network reported by the node.
+    pub actual: String,
+}
+
+impl Display for NetworkMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "network mismatch: configured {} but the LCD reports {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl std::error::Error for NetworkMismatch {}
+
+#[derive(Deserialize)]
+struct NodeInfoResponse {
+    default_node_info: NodeInfo,
+}
+
+#[derive(Deserialize)]
+struct NodeInfo {
+    network: String,
+}
+
+/// Provider for identifying the Cosmos chain a node belongs to.
+///
+/// Cosmos chain IDs are names such as `cosmoshub-4`. The node's name is
+/// checked against the configured network, and the configured numeric chain
+/// ID is reported when they match.
+pub struct CosmosChainIdProvider {
+    lcd: Arc<CosmosLcdClient>,
+    network: String,
+    chain_id: u64,
+}
+
+impl CosmosChainIdProvider {
+    /// Creates a new [`CosmosChainIdProvider`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `lcd` - The client used to reach the LCD endpoints.
+    /// * `network` - The expected chain ID, such as `cosmoshub-4`.
+    /// * `chain_id` - The numeric chain ID to report for it.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`CosmosChainIdProvider`] instance.
+    pub fn new(lcd: Arc<CosmosLcdClient>, network: impl Into<String>, chain_id: u64) -> Self {
+        Self {
+            lcd,
+            network: network.into(),
+            chain_id,
+        }
+    }
+}
+
+impl ChainIdProvider for CosmosChainIdProvider {
+    async fn fetch_chain_id(&self) -> Result<u64> {
+        let response: AtHeight<NodeInfoResponse> = self
+            .lcd
+            .get("/cosmos/base/tendermint/v1beta1/node_info", &[], None)
+            .await?;
+
+        let actual = response.value.default_node_info.network;
+
+        if actual != self.network {
+            return Err(NetworkMismatch {
+                expected: self.network.clone(),
+                actual,
+            }
+            .into());
+        }
+
+        Ok(self.chain_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::super::mock_lcd::MockLcd;
+    use super::*;
+
+    #[tokio::test]
+    async fn checks_the_network_name() {
+        // Arrange.
+        let lcd = MockLcd::start(|_, _| {
+            (
+                200,
+                json!({ "default_node_info": { "network": "theta-testnet-001" } }),
+            )
+        });
+        let lcd = Arc::new(CosmosLcdClient::new(&lcd.url).unwrap());
+
+        // Act.
+        let testnet = CosmosChainIdProvider::new(lcd.clone(), "theta-testnet-001", 118);
+        let mainnet = CosmosChainIdProvider::new(lcd, "cosmoshub-4", 118);
+
+        // Assert.
+        assert_eq!(testnet.fetch_chain_id().await.unwrap(), 118);
+        assert_eq!(
+            mainnet.fetch_chain_id().await.unwrap_err().downcast_ref(),
+            Some(&NetworkMismatch {
+                expected: "cosmoshub-4".into(),
+                actual: "theta-testnet-001".into(),
+            })
+        );
+    }
+}
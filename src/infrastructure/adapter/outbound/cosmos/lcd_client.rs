@@ -0,0 +1,151 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use reqwest::StatusCode;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Header that pins a query to a block height. The gRPC gateway echoes the
+/// height a query was answered at with a `grpc-metadata-` prefix.
+const BLOCK_HEIGHT_HEADER: &str = "x-cosmos-block-height";
+const RESPONSE_HEIGHT_HEADER: &str = "grpc-metadata-x-cosmos-block-height";
+
+/// An error returned by a Cosmos SDK LCD endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct CosmosLcdError {
+    /// The HTTP status of the response.
+    #[serde(skip)]
+    pub status: u16,
+
+    /// The gRPC status code.
+    #[serde(default)]
+    pub code: i64,
+
+    /// The error message.
+    #[serde(default)]
+    pub message: String,
+}
+
+impl CosmosLcdError {
+    /// Returns `true` if the queried item does not exist.
+    pub fn is_not_found(&self) -> bool {
+        self.status == StatusCode::NOT_FOUND.as_u16() || self.code == 5
+    }
+}
+
+impl Display for CosmosLcdError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LCD error {}: {}", self.code, self.message)
+    }
+}
+
+impl std::error::Error for CosmosLcdError {}
+
+/// A response together with the block height it was answered at.
+#[derive(Debug)]
+pub struct AtHeight<T> {
+    /// The response itself.
+    pub value: T,
+
+    /// The block height, if the node reported one.
+    pub height: Option<u64>,
+}
+
+/// Minimal client for the LCD (REST) endpoints of a Cosmos SDK node.
+pub struct CosmosLcdClient {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl CosmosLcdClient {
+    /// Creates a new [`CosmosLcdClient`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The base URL of the LCD endpoints.
+    ///
+    /// # Returns
+    ///
+    /// A new [`CosmosLcdClient`] instance, or an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be built.
+    pub fn new(url: impl Into<String>) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()?;
+
+        Ok(Self {
+            client,
+            url: url.into().trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Queries an endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the endpoint, starting with `/`.
+    /// * `query` - The query parameters.
+    /// * `height` - The block height to answer at, or `None` for the latest.
+    ///
+    /// # Returns
+    ///
+    /// The deserialized response and the height it was answered at, or an
+    /// error.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CosmosLcdError`] if the node responded with an error, or
+    /// another error if it could not be reached or the response was malformed.
+    pub async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+        height: Option<u64>,
+    ) -> Result<AtHeight<T>> {
+        let mut request = self
+            .client
+            .get(format!("{}{}", self.url, path))
+            .query(query);
+
+        if let Some(height) = height {
+            request = request.header(BLOCK_HEIGHT_HEADER, height);
+        }
+
+        let response = request.send().await.map_err(|e| anyhow!(e.without_url()))?;
+        let status = response.status();
+
+        let height = response
+            .headers()
+            .get(RESPONSE_HEIGHT_HEADER)
+            .and_then(|h| h.to_str().ok())
+            .and_then(|h| h.parse().ok());
+
+        if !status.is_success() {
+            let mut error =
+                response
+                    .json::<CosmosLcdError>()
+                    .await
+                    .unwrap_or_else(|_| CosmosLcdError {
+                        status: 0,
+                        code: 0,
+                        message: status.to_string(),
+                    });
+            error.status = status.as_u16();
+
+            return Err(error.into());
+        }
+
+        let value = response
+            .json()
+            .await
+            .map_err(|e| anyhow!("malformed {} response: {}", path, e))?;
+
+        Ok(AtHeight { value, height })
+    }
+}
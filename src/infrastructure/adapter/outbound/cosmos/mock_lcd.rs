@@ -0,0 +1,102 @@
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+
+use actix_web::http::StatusCode;
+use actix_web::{App, HttpRequest, HttpResponse, HttpServer, web};
+use serde_json::Value;
+
+type Handler = dyn Fn(&str, &str) -> (u16, Value) + Send + Sync;
+
+/// A request received by [`MockLcd`].
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub path: String,
+    pub query: String,
+    pub height: Option<String>,
+}
+
+/// A local stand-in for the LCD endpoints of a Cosmos SDK node that answers
+/// each `GET` with the given handler and records it.
+///
+/// Every response reports block height `500`, unless started with
+/// [`MockLcd::start_without_height`].
+#[derive(Clone)]
+pub struct MockLcd {
+    pub url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockLcd {
+    pub fn start(handler: impl Fn(&str, &str) -> (u16, Value) + Send + Sync + 'static) -> Self {
+        Self::start_with(handler, true)
+    }
+
+    /// Starts a node that, like some proxies, does not echo the block height.
+    pub fn start_without_height(
+        handler: impl Fn(&str, &str) -> (u16, Value) + Send + Sync + 'static,
+    ) -> Self {
+        Self::start_with(handler, false)
+    }
+
+    fn start_with(
+        handler: impl Fn(&str, &str) -> (u16, Value) + Send + Sync + 'static,
+        echo_height: bool,
+    ) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let handler: Arc<Handler> = Arc::new(handler);
+
+        let server = HttpServer::new({
+            let requests = requests.clone();
+
+            move || {
+                let requests = requests.clone();
+                let handler = handler.clone();
+
+                App::new().default_service(web::get().to(move |request: HttpRequest| {
+                    let requests = requests.clone();
+                    let handler = handler.clone();
+
+                    async move {
+                        let height = request
+                            .headers()
+                            .get("x-cosmos-block-height")
+                            .and_then(|h| h.to_str().ok())
+                            .map(String::from);
+
+                        requests.lock().unwrap().push(Request {
+                            path: request.path().to_string(),
+                            query: request.query_string().to_string(),
+                            height,
+                        });
+
+                        let (status, body) = handler(request.path(), request.query_string());
+
+                        let mut response =
+                            HttpResponse::build(StatusCode::from_u16(status).unwrap());
+
+                        if echo_height {
+                            response.insert_header(("grpc-metadata-x-cosmos-block-height", "500"));
+                        }
+
+                        response.json(body)
+                    }
+                }))
+            }
+        })
+        .workers(1)
+        .listen(listener)
+        .unwrap()
+        .run();
+
+        tokio::spawn(server);
+
+        Self { url, requests }
+    }
+
+    /// Returns every request received so far.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
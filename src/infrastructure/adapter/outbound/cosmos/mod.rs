@@ -0,0 +1,47 @@
+//! Cosmos Outbound Adapters
+//!
+//! Cosmos adapters implement outbound ports for tokens of the bank module of
+//! Cosmos SDK chains, read through the LCD (REST) endpoints. Tokens are
+//! identified by their denom and excluded sources are bech32 accounts.
+
+pub mod chain_id_provider;
+pub mod lcd_client;
+pub mod token_metadata_provider;
+pub mod token_supply_provider;
+
+#[cfg(test)]
+mod mock_lcd;
+
+pub use chain_id_provider::{CosmosChainIdProvider, NetworkMismatch};
+pub use lcd_client::{CosmosLcdClient, CosmosLcdError};
+pub use token_metadata_provider::CosmosTokenMetadataProvider;
+pub use token_supply_provider::CosmosTokenSupplyProvider;
+
+use anyhow::{Result, anyhow};
+
+use crate::domain::model::ChainAddress;
+
+/// Returns the bech32 account, or an error if the address is not a Cosmos
+/// account.
+fn cosmos_account(address: &ChainAddress) -> Result<&str> {
+    match address {
+        ChainAddress::Cosmos(account) => Ok(account),
+        _ => Err(anyhow!(
+            "{} is a {} address, not a Cosmos address",
+            address,
+            address.kind()
+        )),
+    }
+}
+
+/// Returns the denom, or an error if the address is not a Cosmos denom.
+fn cosmos_denom(address: &ChainAddress) -> Result<&str> {
+    match address {
+        ChainAddress::Denom(denom) => Ok(denom),
+        _ => Err(anyhow!(
+            "{} is a {} address, not a Cosmos denom",
+            address,
+            address.kind()
+        )),
+    }
+}
@@ -0,0 +1,216 @@
+use std::sync::Arc;
+
+use anyhow::{Result, anyhow};
+use serde::Deserialize;
+use tracing::info;
+
+use crate::application::port::outbound::TokenMetadataProvider;
+use crate::domain::model::{ChainAddress, Token};
+
+use super::cosmos_denom;
+use super::lcd_client::{AtHeight, CosmosLcdClient, CosmosLcdError};
+
+#[derive(Deserialize)]
+struct MetadataResponse {
+    metadata: Metadata,
+}
+
+#[derive(Deserialize)]
+struct Metadata {
+    #[serde(default)]
+    denom_units: Vec<DenomUnit>,
+
+    #[serde(default)]
+    display: String,
+
+    #[serde(default)]
+    name: String,
+
+    #[serde(default)]
+    symbol: String,
+}
+
+#[derive(Deserialize)]
+struct DenomUnit {
+    denom: String,
+
+    #[serde(default)]
+    exponent: u32,
+
+    #[serde(default)]
+    aliases: Vec<String>,
+}
+
+/// Provider for retrieving token metadata from the bank module of a Cosmos SDK
+/// chain.
+///
+/// Reads the denom metadata registered on chain. The decimals are the
+/// exponent of the display unit, so for `uatom` with display unit `atom` at
+/// exponent `6` the token has six decimals.
+pub struct CosmosTokenMetadataProvider {
+    lcd: Arc<CosmosLcdClient>,
+}
+
+impl CosmosTokenMetadataProvider {
+    /// Creates a new [`CosmosTokenMetadataProvider`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `lcd` - The client used to reach the LCD endpoints.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`CosmosTokenMetadataProvider`] instance.
+    pub fn new(lcd: Arc<CosmosLcdClient>) -> Self {
+        Self { lcd }
+    }
+}
+
+impl TokenMetadataProvider for CosmosTokenMetadataProvider {
+    async fn fetch_token_metadata(&self, token_address: ChainAddress) -> Result<Token> {
+        info!("Fetching token metadata for denom: {}", token_address);
+
+        let denom = cosmos_denom(&token_address)?;
+        let path = format!("/cosmos/bank/v1beta1/denoms_metadata/{}", denom);
+
+        let response: AtHeight<MetadataResponse> = match self.lcd.get(&path, &[], None).await {
+            Ok(response) => response,
+            Err(e)
+                if e.downcast_ref::<CosmosLcdError>()
+                    .is_some_and(|e| e.is_not_found()) =>
+            {
+                return Err(anyhow!(
+                    "{} has no denom metadata; set token.name, token.symbol and token.decimals",
+                    denom
+                ));
+            }
+            Err(e) => return Err(e),
+        };
+
+        let metadata = response.value.metadata;
+
+        let display = metadata
+            .denom_units
+            .iter()
+            .find(|u| u.denom == metadata.display || u.aliases.contains(&metadata.display))
+            .ok_or_else(|| {
+                anyhow!(
+                    "{} has no denom unit for display unit `{}`",
+                    denom,
+                    metadata.display
+                )
+            })?;
+
+        let decimals = u8::try_from(display.exponent)
+            .map_err(|_| anyhow!("{} has an exponent of {}", denom, display.exponent))?;
+
+        let symbol = match metadata.symbol.is_empty() {
+            true => metadata.display.to_uppercase(),
+            false => metadata.symbol,
+        };
+        let name = match metadata.name.is_empty() {
+            true => symbol.clone(),
+            false => metadata.name,
+        };
+
+        let token = Token::new(name, symbol, token_address, decimals);
+
+        info!("Token metadata: {}", token);
+
+        Ok(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::super::mock_lcd::MockLcd;
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Test Helper Functions
+
+    async fn fetch(status: u16, body: Value) -> (Result<Token>, MockLcd) {
+        let lcd = MockLcd::start(move |_, _| (status, body.clone()));
+        let provider =
+            CosmosTokenMetadataProvider::new(Arc::new(CosmosLcdClient::new(&lcd.url).unwrap()));
+
+        let result = provider
+            .fetch_token_metadata(ChainAddress::Denom("uatom".into()))
+            .await;
+
+        (result, lcd)
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[tokio::test]
+    async fn reads_decimals_from_the_display_unit() {
+        // Arrange.
+        let body = json!({
+            "metadata": {
+                "description": "The native staking token of the Cosmos Hub.",
+                "denom_units": [
+                    { "denom": "uatom", "exponent": 0, "aliases": ["microatom"] },
+                    { "denom": "matom", "exponent": 3, "aliases": ["milliatom"] },
+                    { "denom": "atom", "exponent": 6, "aliases": [] },
+                ],
+                "base": "uatom",
+                "display": "atom",
+                "name": "Cosmos Hub Atom",
+                "symbol": "ATOM",
+            },
+        });
+
+        // Act.
+        let (result, lcd) = fetch(200, body).await;
+
+        // Assert.
+        assert_eq!(
+            result.unwrap(),
+            Token::new(
+                "Cosmos Hub Atom",
+                "ATOM",
+                ChainAddress::Denom("uatom".into()),
+                6
+            )
+        );
+        assert_eq!(
+            lcd.requests()[0].path,
+            "/cosmos/bank/v1beta1/denoms_metadata/uatom"
+        );
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_the_display_unit_for_names() {
+        // Arrange.
+        let body = json!({
+            "metadata": {
+                "denom_units": [
+                    { "denom": "uatom", "exponent": 0 },
+                    { "denom": "atom", "exponent": 6 },
+                ],
+                "display": "atom",
+            },
+        });
+
+        // Act.
+        let (result, _) = fetch(200, body).await;
+
+        // Assert.
+        let token = result.unwrap();
+        assert_eq!(token.name, "ATOM");
+        assert_eq!(token.symbol, "ATOM");
+    }
+
+    #[tokio::test]
+    async fn explains_missing_metadata() {
+        // Act.
+        let (result, _) = fetch(404, json!({ "code": 5, "message": "not found" })).await;
+
+        // Assert.
+        assert!(result.unwrap_err().to_string().contains("set token.name"));
+    }
+}
@@ -0,0 +1,378 @@
+use std::sync::Arc;
+
+use alloy::primitives::{U256, utils};
+use anyhow::{Context as _, Result};
+use serde::Deserialize;
+use tracing::{info, warn};
+
+use crate::application::port::outbound::TokenSupplyProvider;
+use crate::domain::model::{Source, SourceBalance, Token, TokenSupply};
+
+use super::lcd_client::{AtHeight, CosmosLcdClient};
+use super::{cosmos_account, cosmos_denom};
+
+#[derive(Deserialize)]
+struct Coin {
+    denom: String,
+    amount: String,
+}
+
+#[derive(Deserialize)]
+struct SupplyResponse {
+    amount: Coin,
+}
+
+#[derive(Deserialize)]
+struct BalancesResponse {
+    balances: Vec<Coin>,
+
+    #[serde(default)]
+    pagination: Option<Pagination>,
+}
+
+#[derive(Deserialize)]
+struct Pagination {
+    next_key: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct LatestBlockResponse {
+    block: Block,
+}
+
+#[derive(Deserialize)]
+struct Block {
+    header: BlockHeader,
+}
+
+#[derive(Deserialize)]
+struct BlockHeader {
+    height: String,
+}
+
+/// Provider for retrieving token supply data from the bank module of a Cosmos
+/// SDK chain.
+///
+/// The total supply of the denom is read from `supply/by_denom`. Each excluded
+/// source, typically a module or vesting account, is read from
+/// `balances/{address}`; vesting accounts report their full balance, locked
+/// or not. Every balance is read at the height the supply was read at. If the
+/// node does not report that height, the supply is read again at the latest
+/// block, from `blocks/latest`.
+pub struct CosmosTokenSupplyProvider {
+    lcd: Arc<CosmosLcdClient>,
+}
+
+impl CosmosTokenSupplyProvider {
+    /// Creates a new [`CosmosTokenSupplyProvider`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `lcd` - The client used to reach the LCD endpoints.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`CosmosTokenSupplyProvider`] instance.
+    pub fn new(lcd: Arc<CosmosLcdClient>) -> Self {
+        Self { lcd }
+    }
+
+    /// Returns the height of the latest block.
+    async fn latest_height(&self) -> Result<u64> {
+        let latest: AtHeight<LatestBlockResponse> = self
+            .lcd
+            .get("/cosmos/base/tendermint/v1beta1/blocks/latest", &[], None)
+            .await?;
+
+        let height = latest.value.block.header.height;
+
+        height
+            .parse()
+            .with_context(|| format!("`{}` is not a block height", height))
+    }
+
+    /// Returns an account's balance of a denom, walking every page of its
+    /// balances until the denom is found.
+    async fn balance(&self, account: &str, denom: &str, height: Option<u64>) -> Result<U256> {
+        let path = format!("/cosmos/bank/v1beta1/balances/{}", account);
+        let mut next_key: Option<String> = None;
+
+        loop {
+            let query = match &next_key {
+                Some(key) => vec![("pagination.key", key.as_str())],
+                None => vec![],
+            };
+
+            let page: AtHeight<BalancesResponse> = self.lcd.get(&path, &query, height).await?;
+
+            if let Some(coin) = page.value.balances.iter().find(|c| c.denom == denom) {
+                return parse_amount(coin);
+            }
+
+            next_key = page.value.pagination.and_then(|p| p.next_key);
+
+            if next_key.as_deref().is_none_or(str::is_empty) {
+                return Ok(U256::ZERO);
+            }
+        }
+    }
+}
+
+impl TokenSupplyProvider for CosmosTokenSupplyProvider {
+    async fn fetch_token_supply(
+        &self,
+        token: &Token,
+        excluded_sources: &[Source],
+    ) -> Result<TokenSupply> {
+        info!("Fetching token supply for: {}", token);
+
+        let denom = cosmos_denom(&token.address)?;
+
+        let path = "/cosmos/bank/v1beta1/supply/by_denom";
+        let query = [("denom", denom)];
+
+        let mut supply: AtHeight<SupplyResponse> = self.lcd.get(path, &query, None).await?;
+
+        // Without the height the supply was read at, the balances could be
+        // read at a different block, so pin every read to the latest one.
+        let height = match supply.height {
+            Some(height) => height,
+            None => {
+                let height = self.latest_height().await?;
+
+                warn!(
+                    "The node did not report the height of the supply, reading it again at block {}",
+                    height
+                );
+
+                supply = self.lcd.get(path, &query, Some(height)).await?;
+                height
+            }
+        };
+
+        let total_supply = parse_amount(&supply.value.amount)?;
+        let mut excluded = U256::ZERO;
+        let mut breakdown = Vec::with_capacity(excluded_sources.len());
+
+        for i in excluded_sources.iter() {
+            info!("Beginning check for {} at {}", i.name, i.address);
+
+            let account = cosmos_account(&i.address)?;
+            let bal = self.balance(account, denom, Some(height)).await?;
+
+            info!("{}: {}", i.name, bal);
+
+            excluded = excluded.saturating_add(bal);
+
+            let formatted = utils::format_units(bal, token.decimals)?;
+            breakdown.push(
+                SourceBalance::new(i.name.clone(), i.address.clone(), formatted).with_raw(bal),
+            );
+
+            info!("Finished check for {} at {}", i.name, i.address);
+        }
+
+        let token_supply = TokenSupply::from_raw(total_supply, excluded, token.decimals)?
+            .with_block_number(height)
+            .with_breakdown(breakdown);
+
+        info!("Token Supply: {}", token_supply);
+
+        Ok(token_supply)
+    }
+}
+
+fn parse_amount(coin: &Coin) -> Result<U256> {
+    coin.amount
+        .parse()
+        .with_context(|| format!("`{}` is not a {} amount", coin.amount, coin.denom))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use crate::domain::model::ChainAddress;
+
+    use super::super::mock_lcd::MockLcd;
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Test Helper Functions
+
+    const COMMUNITY_POOL: &str = "cosmos1v3jkvemgd94xkmrddehhqutjwd682anhgerdcs";
+    const VESTING: &str = "cosmos1eryu4j7veh8vl5x36tfaf4wk6lvdnkkm646834";
+
+    fn start_lcd() -> MockLcd {
+        MockLcd::start(respond)
+    }
+
+    fn respond(path: &str, query: &str) -> (u16, Value) {
+        let community_pool = format!("/cosmos/bank/v1beta1/balances/{}", COMMUNITY_POOL);
+        let vesting = format!("/cosmos/bank/v1beta1/balances/{}", VESTING);
+
+        match path {
+            "/cosmos/bank/v1beta1/supply/by_denom" => (
+                200,
+                json!({ "amount": { "denom": "uatom", "amount": "10000000000" } }),
+            ),
+            p if p == community_pool => (
+                200,
+                json!({
+                    "balances": [{ "denom": "uatom", "amount": "2500000000" }],
+                    "pagination": { "next_key": null, "total": "1" },
+                }),
+            ),
+            // The denom is on the second page of the vesting account.
+            p if p == vesting && query.is_empty() => (
+                200,
+                json!({
+                    "balances": [{ "denom": "ibc/27394FB0", "amount": "7" }],
+                    "pagination": { "next_key": "AQ==", "total": "2" },
+                }),
+            ),
+            p if p == vesting => (
+                200,
+                json!({
+                    "balances": [{ "denom": "uatom", "amount": "500000000" }],
+                    "pagination": { "next_key": null, "total": "2" },
+                }),
+            ),
+            _ => (404, json!({ "code": 5, "message": "Not Implemented" })),
+        }
+    }
+
+    fn create_token() -> Token {
+        Token::new(
+            "Cosmos Hub Atom",
+            "ATOM",
+            ChainAddress::Denom("uatom".into()),
+            6,
+        )
+    }
+
+    fn source(name: &str, address: &str) -> Source {
+        Source {
+            name: name.into(),
+            address: address.parse().unwrap(),
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[tokio::test]
+    async fn fetches_supply_and_balances_at_the_same_height() {
+        // Arrange.
+        let lcd = start_lcd();
+        let provider =
+            CosmosTokenSupplyProvider::new(Arc::new(CosmosLcdClient::new(&lcd.url).unwrap()));
+        let sources = [
+            source("Community Pool", COMMUNITY_POOL),
+            source("Vesting", VESTING),
+        ];
+
+        // Act.
+        let supply = provider
+            .fetch_token_supply(&create_token(), &sources)
+            .await
+            .unwrap();
+
+        // Assert.
+        assert_eq!(supply.total_supply, "10000.000000");
+        assert_eq!(supply.circulating_supply, "7000.000000");
+        assert_eq!(supply.block_number, Some(500));
+        assert_eq!(supply.breakdown[0].balance, "2500.000000");
+        assert_eq!(supply.breakdown[1].balance, "500.000000");
+
+        let requests = lcd.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[0].query, "denom=uatom");
+        assert_eq!(requests[0].height, None);
+        assert_eq!(requests[3].query, "pagination.key=AQ%3D%3D");
+        assert!(
+            requests[1..]
+                .iter()
+                .all(|r| r.height.as_deref() == Some("500"))
+        );
+    }
+
+    #[tokio::test]
+    async fn pins_reads_to_the_latest_block_without_a_reported_height() {
+        // Arrange.
+        let lcd = MockLcd::start_without_height(|path, query| match path {
+            "/cosmos/base/tendermint/v1beta1/blocks/latest" => {
+                (200, json!({ "block": { "header": { "height": "777" } } }))
+            }
+            _ => respond(path, query),
+        });
+        let provider =
+            CosmosTokenSupplyProvider::new(Arc::new(CosmosLcdClient::new(&lcd.url).unwrap()));
+
+        // Act.
+        let supply = provider
+            .fetch_token_supply(&create_token(), &[source("Community Pool", COMMUNITY_POOL)])
+            .await
+            .unwrap();
+
+        // Assert.
+        assert_eq!(supply.block_number, Some(777));
+        assert_eq!(supply.circulating_supply, "7500.000000");
+
+        let requests = lcd.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[0].height, None);
+        assert_eq!(
+            requests[1].path,
+            "/cosmos/base/tendermint/v1beta1/blocks/latest"
+        );
+        assert!(
+            requests[2..]
+                .iter()
+                .all(|r| r.height.as_deref() == Some("777"))
+        );
+    }
+
+    #[tokio::test]
+    async fn surfaces_lcd_errors() {
+        // Arrange.
+        let lcd = MockLcd::start(|_, _| {
+            (
+                400,
+                json!({ "code": 3, "message": "invalid denom", "details": [] }),
+            )
+        });
+        let provider =
+            CosmosTokenSupplyProvider::new(Arc::new(CosmosLcdClient::new(&lcd.url).unwrap()));
+
+        // Act.
+        let result = provider.fetch_token_supply(&create_token(), &[]).await;
+
+        // Assert.
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "LCD error 3: invalid denom"
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_sources_that_are_not_accounts() {
+        // Arrange.
+        let lcd = start_lcd();
+        let provider =
+            CosmosTokenSupplyProvider::new(Arc::new(CosmosLcdClient::new(&lcd.url).unwrap()));
+
+        // Act.
+        let result = provider
+            .fetch_token_supply(&create_token(), &[source("Denom", "uosmo")])
+            .await;
+
+        // Assert.
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("not a Cosmos address")
+        );
+    }
+}
@@ -5,4 +5,9 @@
 //! APIs, message queues, and other services.
 
 pub mod blockchain;
+pub mod chain;
+pub mod cosmos;
 pub mod persistence;
+pub mod solana;
+pub mod static_config;
+pub mod webhook;
@@ -0,0 +1,107 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+
+use crate::application::port::outbound::SourceAuditLog;
+use crate::domain::model::SourceChange;
+
+/// File-backed audit log for changes to the excluded sources.
+///
+/// Each change is appended to the file as a single line of JSON, so the log
+/// can be inspected with standard tools such as `jq`.
+pub struct FileSourceAuditLog {
+    path: PathBuf,
+    write_lock: Mutex<()>,
+}
+
+impl FileSourceAuditLog {
+    /// Creates a new file-backed source audit log.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The JSON Lines file to append changes to.
+    ///
+    /// # Returns
+    ///
+    /// * A new FileSourceAuditLog instance.
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self {
+            path: path.into(),
+            write_lock: Mutex::new(()),
+        }
+    }
+}
+
+impl SourceAuditLog for FileSourceAuditLog {
+    async fn record(&self, change: &SourceChange) -> Result<()> {
+        let mut line = serde_json::to_vec(change)?;
+        line.push(b'\n');
+
+        let _guard = self.write_lock.lock().await;
+
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .with_context(|| format!("failed to open {}", self.path.display()))?;
+
+        file.write_all(&line)
+            .await
+            .with_context(|| format!("failed to append to {}", self.path.display()))?;
+
+        file.flush().await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use crate::domain::model::{Source, SourceAction};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_record_appends_one_line_per_change() {
+        // Arrange.
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("source_audit.jsonl");
+        let log = FileSourceAuditLog::new(&path);
+        let source = Source {
+            name: "Sink".into(),
+            address: address!("0xB1a932A665FB0A1D5d7979cd63e80a59EDCe31B4").into(),
+        };
+
+        // Act.
+        log.record(&SourceChange::now(
+            "ops",
+            SourceAction::Added,
+            source.clone(),
+        ))
+        .await
+        .unwrap();
+        log.record(&SourceChange::now("ci", SourceAction::Removed, source))
+            .await
+            .unwrap();
+
+        // Assert.
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let changes: Vec<SourceChange> = contents
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].actor, "ops");
+        assert_eq!(changes[1].action, SourceAction::Removed);
+    }
+}
@@ -0,0 +1,79 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+
+use crate::application::port::outbound::SourceRepository;
+use crate::domain::model::Source;
+
+use super::json_file::{read_json, write_json};
+
+/// File-backed repository for the excluded sources.
+///
+/// This repository stores the complete list of sources as a JSON file, so
+/// changes made at runtime survive restarts of the service.
+pub struct FileSourceRepository {
+    path: PathBuf,
+}
+
+impl FileSourceRepository {
+    /// Creates a new file-backed source repository.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The JSON file to store the sources in.
+    ///
+    /// # Returns
+    ///
+    /// * A new FileSourceRepository instance.
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl SourceRepository for FileSourceRepository {
+    async fn load(&self) -> Result<Option<Vec<Source>>> {
+        read_json(&self.path).await
+    }
+
+    async fn save(&self, sources: &[Source]) -> Result<()> {
+        write_json(&self.path, &sources).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_save_then_load_returns_sources() {
+        // Arrange.
+        let dir = tempfile::tempdir().unwrap();
+        let repository = FileSourceRepository::new(dir.path().join("excluded_sources.json"));
+        let sources = vec![Source {
+            name: "Sink".into(),
+            address: address!("0xB1a932A665FB0A1D5d7979cd63e80a59EDCe31B4").into(),
+        }];
+
+        // Act.
+        repository.save(&sources).await.unwrap();
+        let loaded = repository.load().await.unwrap();
+
+        // Assert.
+        assert_eq!(loaded, Some(sources));
+    }
+
+    #[tokio::test]
+    async fn test_load_returns_none_when_never_saved() {
+        // Arrange.
+        let dir = tempfile::tempdir().unwrap();
+        let repository = FileSourceRepository::new(dir.path().join("excluded_sources.json"));
+
+        // Act.
+        let loaded = repository.load().await.unwrap();
+
+        // Assert.
+        assert_eq!(loaded, None);
+    }
+}
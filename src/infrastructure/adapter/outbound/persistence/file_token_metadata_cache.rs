@@ -0,0 +1,85 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+
+use crate::application::port::outbound::TokenMetadataCache;
+use crate::domain::model::{ChainAddress, Token};
+
+use super::json_file::{read_json, write_json};
+
+/// File-backed cache for token metadata.
+///
+/// This cache stores the metadata of a single token as a JSON file, so it
+/// survives restarts of the service.
+pub struct FileTokenMetadataCache {
+    path: PathBuf,
+}
+
+impl FileTokenMetadataCache {
+    /// Creates a new file-backed token metadata cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The JSON file to store the metadata in.
+    ///
+    /// # Returns
+    ///
+    /// * A new FileTokenMetadataCache instance.
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl TokenMetadataCache for FileTokenMetadataCache {
+    async fn load(&self, token_address: ChainAddress) -> Result<Option<Token>> {
+        let token: Option<Token> = read_json(&self.path).await?;
+        Ok(token.filter(|t| t.address == token_address))
+    }
+
+    async fn store(&self, token: &Token) -> Result<()> {
+        write_json(&self.path, token).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use super::*;
+
+    const TOKEN: ChainAddress =
+        ChainAddress::Evm(address!("0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118"));
+
+    #[tokio::test]
+    async fn test_store_then_load_returns_token() {
+        // Arrange.
+        let dir = tempfile::tempdir().unwrap();
+        let cache = FileTokenMetadataCache::new(dir.path().join("token_metadata.json"));
+        let token = Token::new("Supply", "SUPPLY", TOKEN, 18);
+
+        // Act.
+        cache.store(&token).await.unwrap();
+        let result = cache.load(TOKEN).await.unwrap();
+
+        // Assert.
+        assert_eq!(result, Some(token));
+    }
+
+    #[tokio::test]
+    async fn test_load_ignores_other_tokens() {
+        // Arrange.
+        let dir = tempfile::tempdir().unwrap();
+        let cache = FileTokenMetadataCache::new(dir.path().join("token_metadata.json"));
+        let other = address!("0xB1a932A665FB0A1D5d7979cd63e80a59EDCe31B4");
+        cache
+            .store(&Token::new("Other", "OTHER", other, 6))
+            .await
+            .unwrap();
+
+        // Act.
+        let result = cache.load(TOKEN).await.unwrap();
+
+        // Assert.
+        assert_eq!(result, None);
+    }
+}
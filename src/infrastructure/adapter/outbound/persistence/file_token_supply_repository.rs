@@ -0,0 +1,157 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+
+use crate::application::port::outbound::TokenSupplyRepository;
+use crate::domain::error::SupplyError;
+use crate::domain::model::TokenSupply;
+
+use super::json_file::{read_json, write_json};
+
+/// File-backed repository for token supply data.
+///
+/// This repository serves token supply information from memory and writes
+/// every update to a JSON file, so the last snapshot survives a restart and
+/// can be served before the first fetch completes.
+///
+/// An update is written to the file before it is served. If the write fails
+/// the update is rejected and the previous snapshot is kept, so memory and
+/// disk never disagree and a restart never serves older data than before it.
+pub struct FileTokenSupplyRepository {
+    path: PathBuf,
+    token_supply: RwLock<Option<TokenSupply>>,
+}
+
+impl FileTokenSupplyRepository {
+    /// Opens a file-backed token supply repository.
+    ///
+    /// The last persisted snapshot is restored if the file exists. A file that
+    /// cannot be read is logged and ignored, so a corrupt snapshot never
+    /// prevents startup.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The JSON file to persist the snapshot in.
+    ///
+    /// # Returns
+    ///
+    /// * A new FileTokenSupplyRepository instance.
+    pub async fn open<P: Into<PathBuf>>(path: P) -> Self {
+        let path = path.into();
+
+        let token_supply = match read_json::<TokenSupply>(&path).await {
+            Ok(Some(supply)) => {
+                info!("Restored persisted token supply: {}", supply);
+                Some(supply)
+            }
+            Ok(None) => None,
+            Err(e) => {
+                warn!("Ignoring persisted token supply: {:#}", e);
+                None
+            }
+        };
+
+        Self {
+            path,
+            token_supply: RwLock::new(token_supply),
+        }
+    }
+}
+
+impl TokenSupplyRepository for FileTokenSupplyRepository {
+    async fn store(&self, supply: TokenSupply) -> Result<()> {
+        let mut current = self.token_supply.write().await;
+
+        write_json(&self.path, &supply)
+            .await
+            .context("token supply could not be persisted, keeping the previous snapshot")?;
+
+        *current = Some(supply);
+
+        Ok(())
+    }
+
+    async fn get_current(&self) -> Result<TokenSupply> {
+        let current = self.token_supply.read().await;
+        current
+            .clone()
+            .ok_or_else(|| SupplyError::NotInitialised.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_store_survives_reopen() {
+        // Arrange.
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("token_supply.json");
+        let supply = TokenSupply::new("3000.00", "2000.00");
+
+        let repo = FileTokenSupplyRepository::open(&path).await;
+
+        // Act.
+        repo.store(supply.clone()).await.unwrap();
+        let reopened = FileTokenSupplyRepository::open(&path).await;
+
+        // Assert.
+        assert_eq!(reopened.get_current().await.unwrap(), supply);
+    }
+
+    #[tokio::test]
+    async fn test_failed_write_keeps_previous_snapshot() {
+        // Arrange.
+        let dir = tempfile::tempdir().unwrap();
+        let parent = dir.path().join("data");
+        let previous = TokenSupply::new("3000.00", "2000.00");
+
+        let repo = FileTokenSupplyRepository::open(parent.join("token_supply.json")).await;
+        repo.store(previous.clone()).await.unwrap();
+
+        // Replace the directory with a file so the next write fails.
+        tokio::fs::remove_dir_all(&parent).await.unwrap();
+        tokio::fs::write(&parent, b"").await.unwrap();
+
+        // Act.
+        let result = repo.store(TokenSupply::new("4000.00", "3000.00")).await;
+
+        // Assert.
+        assert!(result.is_err());
+        assert_eq!(repo.get_current().await.unwrap(), previous);
+    }
+
+    #[tokio::test]
+    async fn test_get_current_fails_without_snapshot() {
+        // Arrange.
+        let dir = tempfile::tempdir().unwrap();
+        let repo = FileTokenSupplyRepository::open(dir.path().join("token_supply.json")).await;
+
+        // Act.
+        let result = repo.get_current().await;
+
+        // Assert.
+        let err = result.expect_err("Expected error without a snapshot");
+        assert_eq!(
+            err.downcast_ref::<SupplyError>(),
+            Some(&SupplyError::NotInitialised)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_corrupt_snapshot_is_ignored() {
+        // Arrange.
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("token_supply.json");
+        tokio::fs::write(&path, b"{not json").await.unwrap();
+
+        // Act.
+        let repo = FileTokenSupplyRepository::open(&path).await;
+
+        // Assert.
+        assert!(repo.get_current().await.is_err());
+    }
+}
@@ -2,6 +2,7 @@ use anyhow::Result;
 use tokio::sync::RwLock;
 
 use crate::application::port::outbound::TokenSupplyRepository;
+use crate::domain::error::SupplyError;
 use crate::domain::model::TokenSupply;
 
 /// In-memory repository for token supply data.
@@ -9,7 +10,7 @@ use crate::domain::model::TokenSupply;
 /// This repository stores token supply information in memory using a `RwLock`
 /// for safe concurrent access.
 pub struct InMemoryTokenSupplyRepository {
-    token_supply: RwLock<TokenSupply>,
+    token_supply: RwLock<Option<TokenSupply>>,
 }
 
 impl InMemoryTokenSupplyRepository {
@@ -23,7 +24,20 @@ impl InMemoryTokenSupplyRepository {
     ///
     /// * A new InMemoryTokenSupplyRepository instance.
     pub fn new(token_supply: TokenSupply) -> Self {
-        let token_supply = RwLock::new(token_supply);
+        let token_supply = RwLock::new(Some(token_supply));
+        Self { token_supply }
+    }
+
+    /// Creates a new in-memory token supply repository without any data.
+    ///
+    /// Until the first [`TokenSupplyRepository::store`], reads fail with
+    /// [`SupplyError::NotInitialised`].
+    ///
+    /// # Returns
+    ///
+    /// * A new, empty InMemoryTokenSupplyRepository instance.
+    pub fn empty() -> Self {
+        let token_supply = RwLock::new(None);
         Self { token_supply }
     }
 }
@@ -31,13 +45,15 @@ impl InMemoryTokenSupplyRepository {
 impl TokenSupplyRepository for InMemoryTokenSupplyRepository {
     async fn store(&self, supply: TokenSupply) -> Result<()> {
         let mut current = self.token_supply.write().await;
-        *current = supply;
+        *current = Some(supply);
         Ok(())
     }
 
     async fn get_current(&self) -> Result<TokenSupply> {
         let current = self.token_supply.read().await;
-        Ok(current.clone())
+        current
+            .clone()
+            .ok_or_else(|| SupplyError::NotInitialised.into())
     }
 }
 
@@ -77,4 +93,20 @@ mod tests {
         assert_eq!(result.total_supply, expected.total_supply);
         assert_eq!(result.circulating_supply, expected.circulating_supply);
     }
+
+    #[tokio::test]
+    async fn test_get_current_fails_when_empty() {
+        // Arrange.
+        let repo = InMemoryTokenSupplyRepository::empty();
+
+        // Act.
+        let result = repo.get_current().await;
+
+        // Assert.
+        let err = result.expect_err("Expected error for an empty repository");
+        assert_eq!(
+            err.downcast_ref::<SupplyError>(),
+            Some(&SupplyError::NotInitialised)
+        );
+    }
 }
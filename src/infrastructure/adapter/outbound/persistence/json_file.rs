@@ -0,0 +1,110 @@
+use std::io::ErrorKind;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// Reads and deserializes a JSON file.
+///
+/// # Arguments
+///
+/// * `path` - The file to read.
+///
+/// # Returns
+///
+/// * `Ok(Some(T))` if the file exists and is valid.
+/// * `Ok(None)` if the file does not exist.
+/// * `Err` if the file could not be read or deserialized.
+pub(crate) async fn read_json<T: DeserializeOwned>(path: &Path) -> Result<Option<T>> {
+    let bytes = match tokio::fs::read(path).await {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e).with_context(|| format!("failed to read {}", path.display())),
+    };
+
+    let value = serde_json::from_slice(&bytes)
+        .with_context(|| format!("failed to parse {}", path.display()))?;
+
+    Ok(Some(value))
+}
+
+/// Serializes a value and atomically replaces a JSON file with it.
+///
+/// The value is written to a temporary file next to `path` and then renamed,
+/// so readers never observe a partially written file. Missing parent
+/// directories are created.
+///
+/// # Arguments
+///
+/// * `path` - The file to write.
+/// * `value` - The value to serialize.
+///
+/// # Returns
+///
+/// * `Ok(())` on success, or an error if the file could not be written.
+pub(crate) async fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+
+    let bytes = serde_json::to_vec_pretty(value)?;
+    let tmp_path = path.with_extension("json.tmp");
+
+    tokio::fs::write(&tmp_path, bytes)
+        .await
+        .with_context(|| format!("failed to write {}", tmp_path.display()))?;
+
+    tokio::fs::rename(&tmp_path, path)
+        .await
+        .with_context(|| format!("failed to replace {}", path.display()))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn write_then_read_round_trips() {
+        // Arrange.
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("value.json");
+
+        // Act.
+        write_json(&path, &vec![1, 2, 3]).await.unwrap();
+        let value: Option<Vec<u32>> = read_json(&path).await.unwrap();
+
+        // Assert.
+        assert_eq!(value, Some(vec![1, 2, 3]));
+    }
+
+    #[tokio::test]
+    async fn read_missing_file_returns_none() {
+        // Arrange.
+        let dir = tempfile::tempdir().unwrap();
+
+        // Act.
+        let value: Option<u32> = read_json(&dir.path().join("missing.json")).await.unwrap();
+
+        // Assert.
+        assert_eq!(value, None);
+    }
+
+    #[tokio::test]
+    async fn read_corrupt_file_fails() {
+        // Arrange.
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("corrupt.json");
+        tokio::fs::write(&path, b"{not json").await.unwrap();
+
+        // Act.
+        let result: Result<Option<u32>> = read_json(&path).await;
+
+        // Assert.
+        assert!(result.is_err(), "Expected error for a corrupt file");
+    }
+}
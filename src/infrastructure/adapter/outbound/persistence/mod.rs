@@ -4,6 +4,15 @@
 //! layer. They handle data storage and retrieval while abstracting the specific
 //! persistence technology from the domain.
 
+pub mod file_source_audit_log;
+pub mod file_source_repository;
+pub mod file_token_metadata_cache;
+pub mod file_token_supply_repository;
 pub mod in_memory_token_supply_repository;
+mod json_file;
 
+pub use file_source_audit_log::FileSourceAuditLog;
+pub use file_source_repository::FileSourceRepository;
+pub use file_token_metadata_cache::FileTokenMetadataCache;
+pub use file_token_supply_repository::FileTokenSupplyRepository;
 pub use in_memory_token_supply_repository::InMemoryTokenSupplyRepository;
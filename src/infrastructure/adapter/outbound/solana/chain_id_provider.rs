@@ -0,0 +1,142 @@
+use std::fmt::Display;
+use std::sync::Arc;
+
+use anyhow::Result;
+use serde_json::json;
+
+use crate::application::port::outbound::ChainIdProvider;
+
+use super::rpc_client::SolanaRpcClient;
+
+/// Genesis hashes of the public Solana clusters, with the chain IDs the Solana
+/// token list assigns them.
+const CLUSTERS: [(&str, u64); 3] = [
+    ("5eykt4UsFv8P8NJdTREpY1vzqKqZKvdpKuc147dw2N9d", 101),
+    ("4uhcVJyU9pJkvQyS88uRDiswHXSCkY3zQawwpjk2NsNY", 102),
+    ("EtWTRABZaYq6iMfeYKouRu166VU2xqa1wcaWoxPkrZBG", 103),
+];
+
+/// Error returned when the connected node belongs to a cluster that is neither
+/// one of the public Solana clusters nor the configured one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownCluster {
+    /// The genesis hash reported by the node.
+    pub genesis_hash: String,
+}
+
+impl Display for UnknownCluster {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown Solana cluster with genesis hash {}; set blockchain.genesis_hash to use it",
+            self.genesis_hash
+        )
+    }
+}
+
+impl std::error::Error for UnknownCluster {}
+
+/// Provider for identifying the Solana cluster a node belongs to.
+///
+/// Solana has no chain ID, so the cluster is identified by its genesis hash
+/// and reported as `101` for mainnet-beta, `102` for testnet and `103` for
+/// devnet. Other clusters are only identified if configured with
+/// [`SolanaChainIdProvider::with_cluster`].
+pub struct SolanaChainIdProvider {
+    rpc: Arc<SolanaRpcClient>,
+    cluster: Option<(String, u64)>,
+}
+
+impl SolanaChainIdProvider {
+    /// Creates a new [`SolanaChainIdProvider`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `rpc` - The client used to reach the Solana node.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`SolanaChainIdProvider`] instance.
+    pub fn new(rpc: Arc<SolanaRpcClient>) -> Self {
+        Self { rpc, cluster: None }
+    }
+
+    /// Identifies one more cluster, such as a local validator.
+    ///
+    /// # Arguments
+    ///
+    /// * `genesis_hash` - The genesis hash of the cluster.
+    /// * `chain_id` - The chain ID to report for it.
+    ///
+    /// # Returns
+    ///
+    /// The provider, reporting `chain_id` for a node with `genesis_hash`.
+    pub fn with_cluster(mut self, genesis_hash: impl Into<String>, chain_id: u64) -> Self {
+        self.cluster = Some((genesis_hash.into(), chain_id));
+        self
+    }
+}
+
+impl ChainIdProvider for SolanaChainIdProvider {
+    async fn fetch_chain_id(&self) -> Result<u64> {
+        let genesis_hash: String = self.rpc.call("getGenesisHash", json!([])).await?;
+
+        if let Some((hash, chain_id)) = &self.cluster
+            && *hash == genesis_hash
+        {
+            return Ok(*chain_id);
+        }
+
+        CLUSTERS
+            .iter()
+            .find(|(hash, _)| *hash == genesis_hash)
+            .map(|(_, chain_id)| *chain_id)
+            .ok_or_else(|| UnknownCluster { genesis_hash }.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mock_rpc::MockRpc;
+    use super::*;
+
+    #[tokio::test]
+    async fn identifies_cluster_by_genesis_hash() {
+        // Arrange.
+        let devnet =
+            MockRpc::start(|_, _| Ok(json!("EtWTRABZaYq6iMfeYKouRu166VU2xqa1wcaWoxPkrZBG")));
+        let local = MockRpc::start(|_, _| Ok(json!("11111111111111111111111111111111")));
+
+        // Act.
+        let devnet =
+            SolanaChainIdProvider::new(Arc::new(SolanaRpcClient::new(&devnet.url).unwrap()));
+        let local = SolanaChainIdProvider::new(Arc::new(SolanaRpcClient::new(&local.url).unwrap()));
+
+        // Assert.
+        assert_eq!(devnet.fetch_chain_id().await.unwrap(), 103);
+        let error = local.fetch_chain_id().await.unwrap_err();
+        assert_eq!(
+            error.downcast_ref(),
+            Some(&UnknownCluster {
+                genesis_hash: "11111111111111111111111111111111".into()
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn identifies_the_configured_cluster() {
+        // Arrange.
+        let local = MockRpc::start(|_, _| Ok(json!("11111111111111111111111111111111")));
+        let client = Arc::new(SolanaRpcClient::new(&local.url).unwrap());
+
+        // Act.
+        let local = SolanaChainIdProvider::new(client.clone())
+            .with_cluster("11111111111111111111111111111111", 900);
+        let other = SolanaChainIdProvider::new(client)
+            .with_cluster("EtWTRABZaYq6iMfeYKouRu166VU2xqa1wcaWoxPkrZBG", 900);
+
+        // Assert.
+        assert_eq!(local.fetch_chain_id().await.unwrap(), 900);
+        assert!(other.fetch_chain_id().await.is_err());
+    }
+}
@@ -0,0 +1,80 @@
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+
+use actix_web::{App, HttpResponse, HttpServer, web};
+use serde_json::{Value, json};
+
+type Handler = dyn Fn(&str, &Value) -> Result<Value, (i64, String)> + Send + Sync;
+
+/// A local stand-in for a Solana JSON-RPC node that answers each call with
+/// the given handler and records it.
+#[derive(Clone)]
+pub struct MockRpc {
+    pub url: String,
+    calls: Arc<Mutex<Vec<(String, Value)>>>,
+}
+
+impl MockRpc {
+    pub fn start(
+        handler: impl Fn(&str, &Value) -> Result<Value, (i64, String)> + Send + Sync + 'static,
+    ) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let handler: Arc<Handler> = Arc::new(handler);
+
+        let server = HttpServer::new({
+            let calls = calls.clone();
+
+            move || {
+                let calls = calls.clone();
+                let handler = handler.clone();
+
+                App::new().route(
+                    "/",
+                    web::post().to(move |request: web::Json<Value>| {
+                        let calls = calls.clone();
+                        let handler = handler.clone();
+
+                        async move {
+                            let method = request["method"].as_str().unwrap_or_default();
+                            let params = request["params"].clone();
+                            calls
+                                .lock()
+                                .unwrap()
+                                .push((method.to_string(), params.clone()));
+
+                            let body = match handler(method, &params) {
+                                Ok(result) => json!({
+                                    "jsonrpc": "2.0",
+                                    "id": request["id"],
+                                    "result": result,
+                                }),
+                                Err((code, message)) => json!({
+                                    "jsonrpc": "2.0",
+                                    "id": request["id"],
+                                    "error": { "code": code, "message": message },
+                                }),
+                            };
+
+                            HttpResponse::Ok().json(body)
+                        }
+                    }),
+                )
+            }
+        })
+        .workers(1)
+        .listen(listener)
+        .unwrap()
+        .run();
+
+        tokio::spawn(server);
+
+        Self { url, calls }
+    }
+
+    /// Returns the method and parameters of every call received so far.
+    pub fn calls(&self) -> Vec<(String, Value)> {
+        self.calls.lock().unwrap().clone()
+    }
+}
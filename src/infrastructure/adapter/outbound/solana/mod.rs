@@ -0,0 +1,35 @@
+//! Solana Outbound Adapters
+//!
+//! Solana adapters implement outbound ports for SPL tokens over the Solana
+//! JSON-RPC API. Token addresses are mints and excluded sources are owner
+//! wallets, both written as base58 public keys.
+
+pub mod chain_id_provider;
+pub mod rpc_client;
+pub mod token_metadata_provider;
+pub mod token_supply_provider;
+
+#[cfg(test)]
+mod mock_rpc;
+
+pub use chain_id_provider::{SolanaChainIdProvider, UnknownCluster};
+pub use rpc_client::{SolanaRpcClient, SolanaRpcError};
+pub use token_metadata_provider::SolanaTokenMetadataProvider;
+pub use token_supply_provider::SolanaTokenSupplyProvider;
+
+use anyhow::{Result, anyhow};
+
+use crate::domain::model::{AddressKind, ChainAddress};
+
+/// Returns the base58 public key, or an error if the address belongs to
+/// another chain.
+fn solana_address(address: &ChainAddress) -> Result<String> {
+    match address.kind() {
+        AddressKind::Solana => Ok(address.to_string()),
+        kind => Err(anyhow!(
+            "{} is a {} address, not a Solana address",
+            address,
+            kind
+        )),
+    }
+}
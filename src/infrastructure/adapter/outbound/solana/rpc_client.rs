@@ -0,0 +1,129 @@
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// An error returned by a Solana JSON-RPC node.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct SolanaRpcError {
+    /// The JSON-RPC error code.
+    pub code: i64,
+
+    /// The error message.
+    pub message: String,
+}
+
+impl Display for SolanaRpcError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RPC error {}: {}", self.code, self.message)
+    }
+}
+
+impl std::error::Error for SolanaRpcError {}
+
+#[derive(Deserialize)]
+struct Response<T> {
+    result: Option<T>,
+    error: Option<SolanaRpcError>,
+}
+
+/// The `context` and `value` wrapper most Solana RPC methods respond with.
+#[derive(Debug, Deserialize)]
+pub struct WithContext<T> {
+    /// The slot the response was evaluated at.
+    pub context: Context,
+
+    /// The response itself.
+    pub value: T,
+}
+
+/// The slot a response was evaluated at.
+#[derive(Debug, Deserialize)]
+pub struct Context {
+    /// The slot.
+    pub slot: u64,
+}
+
+/// Minimal client for the Solana JSON-RPC API.
+pub struct SolanaRpcClient {
+    client: reqwest::Client,
+    url: String,
+    next_id: AtomicU64,
+}
+
+impl SolanaRpcClient {
+    /// Creates a new [`SolanaRpcClient`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The JSON-RPC endpoint of a Solana node.
+    ///
+    /// # Returns
+    ///
+    /// A new [`SolanaRpcClient`] instance, or an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be built.
+    pub fn new(url: impl Into<String>) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()?;
+
+        Ok(Self {
+            client,
+            url: url.into(),
+            next_id: AtomicU64::new(1),
+        })
+    }
+
+    /// Calls a JSON-RPC method.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The name of the method.
+    /// * `params` - The positional parameters.
+    ///
+    /// # Returns
+    ///
+    /// The deserialized result, or an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SolanaRpcError`] if the node responded with an error, or
+    /// another error if it could not be reached or the result was malformed.
+    pub async fn call<T: DeserializeOwned>(&self, method: &str, params: Value) -> Result<T> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": params,
+        });
+
+        let response: Response<T> = self
+            .client
+            .post(&self.url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| anyhow!(e.without_url()))?
+            .error_for_status()
+            .map_err(|e| anyhow!(e.without_url()))?
+            .json()
+            .await
+            .map_err(|e| anyhow!("malformed {} response: {}", method, e))?;
+
+        match (response.result, response.error) {
+            (_, Some(error)) => Err(error.into()),
+            (Some(result), None) => Ok(result),
+            (None, None) => Err(anyhow!("{} returned neither a result nor an error", method)),
+        }
+    }
+}
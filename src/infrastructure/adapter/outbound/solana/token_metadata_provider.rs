@@ -0,0 +1,212 @@
+use std::sync::Arc;
+
+use anyhow::{Result, anyhow};
+use serde::Deserialize;
+use serde_json::json;
+use tracing::{info, warn};
+
+use crate::application::port::outbound::TokenMetadataProvider;
+use crate::domain::model::{ChainAddress, Token};
+
+use super::rpc_client::{SolanaRpcClient, WithContext};
+use super::solana_address;
+
+#[derive(Deserialize)]
+struct Account {
+    data: AccountData,
+}
+
+#[derive(Deserialize)]
+struct AccountData {
+    parsed: ParsedMint,
+}
+
+#[derive(Deserialize)]
+struct ParsedMint {
+    #[serde(rename = "type")]
+    kind: String,
+    info: MintInfo,
+}
+
+#[derive(Deserialize)]
+struct MintInfo {
+    decimals: u8,
+
+    #[serde(default)]
+    extensions: Vec<Extension>,
+}
+
+#[derive(Deserialize)]
+struct Extension {
+    extension: String,
+
+    #[serde(default)]
+    state: Option<MetadataState>,
+}
+
+#[derive(Deserialize)]
+struct MetadataState {
+    #[serde(default)]
+    name: String,
+
+    #[serde(default)]
+    symbol: String,
+}
+
+/// Provider for retrieving SPL token metadata from a Solana cluster.
+///
+/// Decimals are read from the mint account. Name and symbol are only stored on
+/// chain by Token-2022 mints with the metadata extension; for other mints the
+/// mint address is used in their place, so configure `token.name` and
+/// `token.symbol`.
+pub struct SolanaTokenMetadataProvider {
+    rpc: Arc<SolanaRpcClient>,
+}
+
+impl SolanaTokenMetadataProvider {
+    /// Creates a new [`SolanaTokenMetadataProvider`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `rpc` - The client used to reach the Solana node.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`SolanaTokenMetadataProvider`] instance.
+    pub fn new(rpc: Arc<SolanaRpcClient>) -> Self {
+        Self { rpc }
+    }
+}
+
+impl TokenMetadataProvider for SolanaTokenMetadataProvider {
+    async fn fetch_token_metadata(&self, token_address: ChainAddress) -> Result<Token> {
+        info!("Fetching token metadata for address: {}", token_address);
+
+        let mint = solana_address(&token_address)?;
+
+        let account: WithContext<Option<Account>> = self
+            .rpc
+            .call(
+                "getAccountInfo",
+                json!([mint, { "encoding": "jsonParsed", "commitment": "finalized" }]),
+            )
+            .await?;
+
+        let parsed = account
+            .value
+            .ok_or_else(|| anyhow!("mint {} does not exist", mint))?
+            .data
+            .parsed;
+
+        if parsed.kind != "mint" {
+            return Err(anyhow!("{} is not a token mint", mint));
+        }
+
+        let metadata = parsed
+            .info
+            .extensions
+            .into_iter()
+            .find(|e| e.extension == "tokenMetadata")
+            .and_then(|e| e.state);
+
+        let (name, symbol) = match metadata {
+            Some(m) if !m.symbol.is_empty() => {
+                let name = if m.name.is_empty() {
+                    m.symbol.clone()
+                } else {
+                    m.name
+                };
+                (name, m.symbol)
+            }
+            _ => {
+                warn!(
+                    "Mint {} has no on-chain name or symbol, using its address",
+                    mint
+                );
+                (mint.clone(), mint.clone())
+            }
+        };
+
+        let token = Token::new(name, symbol, token_address, parsed.info.decimals);
+
+        info!("Token metadata: {}", token);
+
+        Ok(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::super::mock_rpc::MockRpc;
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Test Helper Functions
+
+    const MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+    fn mint_account(extensions: Value) -> Value {
+        json!({
+            "context": { "slot": 300 },
+            "value": {
+                "data": {
+                    "parsed": {
+                        "type": "mint",
+                        "info": { "decimals": 6, "extensions": extensions },
+                    },
+                    "program": "spl-token-2022",
+                },
+            },
+        })
+    }
+
+    async fn fetch(account: Value) -> Result<Token> {
+        let rpc = MockRpc::start(move |_, _| Ok(account.clone()));
+        let provider =
+            SolanaTokenMetadataProvider::new(Arc::new(SolanaRpcClient::new(&rpc.url).unwrap()));
+
+        provider.fetch_token_metadata(MINT.parse().unwrap()).await
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[tokio::test]
+    async fn reads_token_metadata_extension() {
+        // Arrange.
+        let account = mint_account(json!([{
+            "extension": "tokenMetadata",
+            "state": { "name": "Supply", "symbol": "SUPPLY" },
+        }]));
+
+        // Act.
+        let token = fetch(account).await.unwrap();
+
+        // Assert.
+        assert_eq!(
+            token,
+            Token::new("Supply", "SUPPLY", MINT.parse::<ChainAddress>().unwrap(), 6)
+        );
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_mint_address_without_metadata() {
+        // Act.
+        let token = fetch(mint_account(json!([]))).await.unwrap();
+
+        // Assert.
+        assert_eq!(token.symbol, MINT);
+        assert_eq!(token.decimals, 6);
+    }
+
+    #[tokio::test]
+    async fn rejects_missing_mint() {
+        // Act.
+        let result = fetch(json!({ "context": { "slot": 300 }, "value": null })).await;
+
+        // Assert.
+        assert!(result.unwrap_err().to_string().contains("does not exist"));
+    }
+}
@@ -0,0 +1,519 @@
+use std::sync::Arc;
+
+use alloy::primitives::{U256, utils};
+use anyhow::{Context as _, Result, anyhow};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use tracing::info;
+
+use crate::application::port::outbound::TokenSupplyProvider;
+use crate::domain::model::{Source, SourceBalance, Token, TokenSupply};
+
+use super::rpc_client::{SolanaRpcClient, WithContext};
+use super::solana_address;
+
+/// The commitment every read is made at. Finalized slots cannot be rolled
+/// back, so a published supply never reflects a dropped fork.
+const COMMITMENT: &str = "finalized";
+
+/// The programs that own SPL token accounts: the token program and
+/// Token-2022.
+const TOKEN_PROGRAMS: [&str; 2] = [
+    "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+    "TokenzQdBNbLqP5VEhdkAS6EPFLC1PEnBmYNWXKeWwuY",
+];
+
+#[derive(Deserialize)]
+struct TokenAmount {
+    amount: String,
+}
+
+#[derive(Deserialize)]
+struct KeyedAccount {
+    account: Account,
+}
+
+#[derive(Deserialize)]
+struct Account {
+    data: AccountData,
+}
+
+#[derive(Deserialize)]
+struct AccountData {
+    parsed: ParsedAccount,
+}
+
+#[derive(Deserialize)]
+struct ParsedAccount {
+    info: TokenAccountInfo,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TokenAccountInfo {
+    token_amount: TokenAmount,
+}
+
+/// The account at an excluded source's address. `data` is parsed only for
+/// accounts of known programs, so it is kept as a raw value.
+#[derive(Deserialize)]
+struct SourceAccount {
+    owner: String,
+    executable: bool,
+    data: Value,
+}
+
+/// How the balance of an excluded source is read.
+#[derive(Debug, PartialEq, Eq)]
+enum SourceKind {
+    /// A wallet, or another account, that owns token accounts for the mint.
+    Owner,
+
+    /// A token account for the mint itself.
+    TokenAccount,
+}
+
+/// Provider for retrieving SPL token supply data from a Solana cluster.
+///
+/// The total supply is read from the mint with `getTokenSupply`. Each excluded
+/// source is looked up with `getAccountInfo` and is either a token account for
+/// the mint, whose balance is read with `getTokenAccountBalance`, or an owner
+/// wallet, whose balance is the sum of every token account it owns for the
+/// mint, read with `getTokenAccountsByOwner`. Programs, mints and token
+/// accounts for other mints are rejected.
+pub struct SolanaTokenSupplyProvider {
+    rpc: Arc<SolanaRpcClient>,
+}
+
+impl SolanaTokenSupplyProvider {
+    /// Creates a new [`SolanaTokenSupplyProvider`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `rpc` - The client used to reach the Solana node.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`SolanaTokenSupplyProvider`] instance.
+    pub fn new(rpc: Arc<SolanaRpcClient>) -> Self {
+        Self { rpc }
+    }
+
+    /// Reads the balance of an excluded source, whichever kind it is.
+    async fn source_balance(&self, mint: &str, address: &str, min_slot: u64) -> Result<U256> {
+        match self.source_kind(mint, address, min_slot).await? {
+            SourceKind::Owner => self.owner_balance(mint, address, min_slot).await,
+            SourceKind::TokenAccount => self.token_account_balance(address, min_slot).await,
+        }
+    }
+
+    /// Tells an owner wallet from a token account by the program that owns the
+    /// account at `address`.
+    async fn source_kind(&self, mint: &str, address: &str, min_slot: u64) -> Result<SourceKind> {
+        let account: WithContext<Option<SourceAccount>> = self
+            .rpc
+            .call(
+                "getAccountInfo",
+                json!([
+                    address,
+                    {
+                        "encoding": "jsonParsed",
+                        "commitment": COMMITMENT,
+                        "minContextSlot": min_slot,
+                    },
+                ]),
+            )
+            .await?;
+
+        // A wallet holding no SOL does not exist, but may still own token
+        // accounts.
+        let Some(account) = account.value else {
+            return Ok(SourceKind::Owner);
+        };
+
+        if account.executable {
+            return Err(anyhow!(
+                "{} is a program, not an owner wallet or token account",
+                address
+            ));
+        }
+
+        if !TOKEN_PROGRAMS.contains(&account.owner.as_str()) {
+            return Ok(SourceKind::Owner);
+        }
+
+        let parsed = &account.data["parsed"];
+
+        if parsed["type"] != "account" {
+            return Err(anyhow!(
+                "{} is owned by the token program but is not a token account",
+                address
+            ));
+        }
+
+        if parsed["info"]["mint"] != mint {
+            return Err(anyhow!("{} is a token account for another mint", address));
+        }
+
+        Ok(SourceKind::TokenAccount)
+    }
+
+    /// Reads the balance of a single token account.
+    async fn token_account_balance(&self, account: &str, min_slot: u64) -> Result<U256> {
+        let balance: WithContext<TokenAmount> = self
+            .rpc
+            .call(
+                "getTokenAccountBalance",
+                json!([
+                    account,
+                    { "commitment": COMMITMENT, "minContextSlot": min_slot },
+                ]),
+            )
+            .await?;
+
+        parse_amount(&balance.value)
+    }
+
+    /// Sums the balances of every token account an owner holds for a mint.
+    async fn owner_balance(&self, mint: &str, owner: &str, min_slot: u64) -> Result<U256> {
+        let accounts: WithContext<Vec<KeyedAccount>> = self
+            .rpc
+            .call(
+                "getTokenAccountsByOwner",
+                json!([
+                    owner,
+                    { "mint": mint },
+                    {
+                        "encoding": "jsonParsed",
+                        "commitment": COMMITMENT,
+                        "minContextSlot": min_slot,
+                    },
+                ]),
+            )
+            .await?;
+
+        accounts.value.iter().try_fold(U256::ZERO, |sum, keyed| {
+            let amount = parse_amount(&keyed.account.data.parsed.info.token_amount)?;
+            Ok(sum.saturating_add(amount))
+        })
+    }
+}
+
+impl TokenSupplyProvider for SolanaTokenSupplyProvider {
+    async fn fetch_token_supply(
+        &self,
+        token: &Token,
+        excluded_sources: &[Source],
+    ) -> Result<TokenSupply> {
+        info!("Fetching token supply for: {}", token);
+
+        let mint = solana_address(&token.address)?;
+
+        let supply: WithContext<TokenAmount> = self
+            .rpc
+            .call(
+                "getTokenSupply",
+                json!([mint, { "commitment": COMMITMENT }]),
+            )
+            .await?;
+
+        // Solana cannot pin reads to a past slot, so require every balance to
+        // be read at or after the slot the supply was read at.
+        let slot = supply.context.slot;
+        let total_supply = parse_amount(&supply.value)?;
+        let mut excluded = U256::ZERO;
+        let mut breakdown = Vec::with_capacity(excluded_sources.len());
+
+        for i in excluded_sources.iter() {
+            info!("Beginning check for {} at {}", i.name, i.address);
+
+            let address = solana_address(&i.address)?;
+            let bal = self.source_balance(&mint, &address, slot).await?;
+
+            info!("{}: {}", i.name, bal);
+
+            excluded = excluded.saturating_add(bal);
+
+            let formatted = utils::format_units(bal, token.decimals)?;
+            breakdown.push(
+                SourceBalance::new(i.name.clone(), i.address.clone(), formatted).with_raw(bal),
+            );
+
+            info!("Finished check for {} at {}", i.name, i.address);
+        }
+
+        let token_supply = TokenSupply::from_raw(total_supply, excluded, token.decimals)?
+            .with_block_number(slot)
+            .with_breakdown(breakdown);
+
+        info!("Token Supply: {}", token_supply);
+
+        Ok(token_supply)
+    }
+}
+
+fn parse_amount(amount: &TokenAmount) -> Result<U256> {
+    amount
+        .amount
+        .parse()
+        .with_context(|| format!("`{}` is not a token amount", amount.amount))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use crate::domain::model::ChainAddress;
+
+    use super::super::mock_rpc::MockRpc;
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Test Helper Functions
+
+    const MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+    const TREASURY: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+    const VAULT: &str = "4Nd1mBQtrMJVYVfKf2PJy9NZUZdTAsp7D4xWLs4gDB4T";
+    const OTHER_MINT: &str = "So11111111111111111111111111111111111111112";
+
+    fn token_account(amount: &str) -> Value {
+        json!({
+            "pubkey": "4Nd1mBQtrMJVYVfKf2PJy9NZUZdTAsp7D4xWLs4gDB4T",
+            "account": {
+                "data": {
+                    "parsed": {
+                        "info": {
+                            "mint": MINT,
+                            "owner": TREASURY,
+                            "tokenAmount": { "amount": amount, "decimals": 6 },
+                        },
+                        "type": "account",
+                    },
+                    "program": "spl-token",
+                },
+            },
+        })
+    }
+
+    /// The `getAccountInfo` result for each address the tests use.
+    fn account_info(address: &str) -> Value {
+        let value = match address {
+            TREASURY => json!({
+                "owner": "11111111111111111111111111111111",
+                "executable": false,
+                "data": ["", "base64"],
+            }),
+            VAULT => json!({
+                "owner": TOKEN_PROGRAMS[0],
+                "executable": false,
+                "data": token_account("750000000")["account"]["data"],
+            }),
+            MINT => json!({
+                "owner": TOKEN_PROGRAMS[0],
+                "executable": false,
+                "data": { "parsed": { "info": {}, "type": "mint" }, "program": "spl-token" },
+            }),
+            _ => Value::Null,
+        };
+
+        json!({ "context": { "slot": 301 }, "value": value })
+    }
+
+    fn source(name: &str, address: &str) -> Source {
+        Source {
+            name: name.into(),
+            address: address.parse().unwrap(),
+        }
+    }
+
+    fn start_rpc() -> MockRpc {
+        MockRpc::start(|method, params| match method {
+            "getAccountInfo" => Ok(account_info(params[0].as_str().unwrap())),
+            "getTokenAccountBalance" => Ok(json!({
+                "context": { "slot": 301 },
+                "value": { "amount": "750000000", "decimals": 6 },
+            })),
+            "getTokenSupply" => Ok(json!({
+                "context": { "slot": 300 },
+                "value": { "amount": "10000000000", "decimals": 6 },
+            })),
+            "getTokenAccountsByOwner" => Ok(json!({
+                "context": { "slot": 301 },
+                "value": [token_account("2500000000"), token_account("500000000")],
+            })),
+            _ => Err((-32601, "Method not found".into())),
+        })
+    }
+
+    fn create_token() -> Token {
+        Token::new("USD Coin", "USDC", MINT.parse::<ChainAddress>().unwrap(), 6)
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[tokio::test]
+    async fn fetches_supply_and_sums_owner_accounts() {
+        // Arrange.
+        let rpc = start_rpc();
+        let provider =
+            SolanaTokenSupplyProvider::new(Arc::new(SolanaRpcClient::new(&rpc.url).unwrap()));
+        // Act.
+        let supply = provider
+            .fetch_token_supply(&create_token(), &[source("Treasury", TREASURY)])
+            .await
+            .unwrap();
+
+        // Assert.
+        assert_eq!(supply.total_supply, "10000.000000");
+        assert_eq!(supply.circulating_supply, "7000.000000");
+        assert_eq!(supply.raw_circulating_supply, U256::from(7_000_000_000u64));
+        assert_eq!(supply.block_number, Some(300));
+        assert_eq!(supply.breakdown[0].balance, "3000.000000");
+
+        let calls = rpc.calls();
+        assert_eq!(calls[1].0, "getAccountInfo");
+        assert_eq!(calls[2].0, "getTokenAccountsByOwner");
+        assert_eq!(calls[2].1[0], TREASURY);
+        assert_eq!(calls[2].1[1]["mint"], MINT);
+        assert_eq!(calls[2].1[2]["minContextSlot"], 300);
+    }
+
+    #[tokio::test]
+    async fn reads_token_account_sources_directly() {
+        // Arrange.
+        let rpc = start_rpc();
+        let provider =
+            SolanaTokenSupplyProvider::new(Arc::new(SolanaRpcClient::new(&rpc.url).unwrap()));
+
+        // Act.
+        let supply = provider
+            .fetch_token_supply(&create_token(), &[source("Vault", VAULT)])
+            .await
+            .unwrap();
+
+        // Assert.
+        assert_eq!(supply.breakdown[0].balance, "750.000000");
+        assert_eq!(supply.circulating_supply, "9250.000000");
+
+        let calls = rpc.calls();
+        assert_eq!(calls[2].0, "getTokenAccountBalance");
+        assert_eq!(calls[2].1[0], VAULT);
+        assert_eq!(calls[2].1[1]["minContextSlot"], 300);
+    }
+
+    #[tokio::test]
+    async fn reads_missing_accounts_as_owner_wallets() {
+        // Arrange.
+        let rpc = start_rpc();
+        let provider =
+            SolanaTokenSupplyProvider::new(Arc::new(SolanaRpcClient::new(&rpc.url).unwrap()));
+
+        // Act.
+        let supply = provider
+            .fetch_token_supply(&create_token(), &[source("Empty", OTHER_MINT)])
+            .await
+            .unwrap();
+
+        // Assert.
+        assert_eq!(supply.breakdown[0].balance, "3000.000000");
+    }
+
+    #[tokio::test]
+    async fn rejects_sources_that_are_neither_owners_nor_token_accounts() {
+        // Arrange.
+        let rpc = MockRpc::start(|method, params| match (method, params[0].as_str()) {
+            ("getTokenSupply", _) => Ok(json!({
+                "context": { "slot": 300 },
+                "value": { "amount": "10000000000", "decimals": 6 },
+            })),
+            ("getAccountInfo", Some(VAULT)) => Ok(json!({
+                "context": { "slot": 301 },
+                "value": {
+                    "owner": TOKEN_PROGRAMS[1],
+                    "executable": false,
+                    "data": {
+                        "parsed": {
+                            "info": { "mint": OTHER_MINT, "tokenAmount": { "amount": "1" } },
+                            "type": "account",
+                        },
+                        "program": "spl-token-2022",
+                    },
+                },
+            })),
+            ("getAccountInfo", Some(TREASURY)) => Ok(json!({
+                "context": { "slot": 301 },
+                "value": {
+                    "owner": "BPFLoaderUpgradeab1e11111111111111111111111",
+                    "executable": true,
+                    "data": ["", "base64"],
+                },
+            })),
+            ("getAccountInfo", _) => Ok(account_info(params[0].as_str().unwrap())),
+            _ => Err((-32601, "Method not found".into())),
+        });
+        let provider =
+            SolanaTokenSupplyProvider::new(Arc::new(SolanaRpcClient::new(&rpc.url).unwrap()));
+        let token = create_token();
+
+        // Act.
+        let other_mint = provider
+            .fetch_token_supply(&token, &[source("Vault", VAULT)])
+            .await;
+        let program = provider
+            .fetch_token_supply(&token, &[source("Program", TREASURY)])
+            .await;
+        let mint = provider
+            .fetch_token_supply(&token, &[source("Mint", MINT)])
+            .await;
+
+        // Assert.
+        assert!(other_mint.unwrap_err().to_string().contains("another mint"));
+        assert!(program.unwrap_err().to_string().contains("is a program"));
+        assert!(
+            mint.unwrap_err()
+                .to_string()
+                .contains("is not a token account")
+        );
+    }
+
+    #[tokio::test]
+    async fn surfaces_rpc_errors() {
+        // Arrange.
+        let rpc = MockRpc::start(|_, _| Err((-32602, "Invalid param: not a Token mint".into())));
+        let provider =
+            SolanaTokenSupplyProvider::new(Arc::new(SolanaRpcClient::new(&rpc.url).unwrap()));
+
+        // Act.
+        let result = provider.fetch_token_supply(&create_token(), &[]).await;
+
+        // Assert.
+        let error = result.unwrap_err().to_string();
+        assert_eq!(error, "RPC error -32602: Invalid param: not a Token mint");
+    }
+
+    #[tokio::test]
+    async fn rejects_evm_addresses() {
+        // Arrange.
+        let rpc = start_rpc();
+        let provider =
+            SolanaTokenSupplyProvider::new(Arc::new(SolanaRpcClient::new(&rpc.url).unwrap()));
+        let sink = Source {
+            name: "Sink".into(),
+            address: "0xB1a932A665FB0A1D5d7979cd63e80a59EDCe31B4"
+                .parse()
+                .unwrap(),
+        };
+
+        // Act.
+        let result = provider.fetch_token_supply(&create_token(), &[sink]).await;
+
+        // Assert.
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("not a Solana address")
+        );
+    }
+}
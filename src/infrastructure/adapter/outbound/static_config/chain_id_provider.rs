@@ -0,0 +1,32 @@
+use anyhow::Result;
+
+use crate::application::port::outbound::ChainIdProvider;
+
+/// Provider that reports the configured chain ID.
+///
+/// There is no chain to ask, so the configured value is always reported and
+/// chain verification always succeeds.
+pub struct StaticChainIdProvider {
+    chain_id: u64,
+}
+
+impl StaticChainIdProvider {
+    /// Creates a new [`StaticChainIdProvider`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - The chain ID to report.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`StaticChainIdProvider`] instance.
+    pub fn new(chain_id: u64) -> Self {
+        Self { chain_id }
+    }
+}
+
+impl ChainIdProvider for StaticChainIdProvider {
+    async fn fetch_chain_id(&self) -> Result<u64> {
+        Ok(self.chain_id)
+    }
+}
@@ -0,0 +1,13 @@
+//! Static Outbound Adapters
+//!
+//! Static adapters implement outbound ports from configuration values alone,
+//! without reaching any chain. They serve a known supply for tokens that are
+//! not deployed yet and for environments without RPC access.
+
+pub mod chain_id_provider;
+pub mod token_metadata_provider;
+pub mod token_supply_provider;
+
+pub use chain_id_provider::StaticChainIdProvider;
+pub use token_metadata_provider::StaticTokenMetadataProvider;
+pub use token_supply_provider::StaticTokenSupplyProvider;
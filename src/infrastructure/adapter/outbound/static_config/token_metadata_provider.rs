@@ -0,0 +1,74 @@
+use anyhow::{Result, anyhow};
+use tracing::info;
+
+use crate::application::port::outbound::TokenMetadataProvider;
+use crate::domain::model::{ChainAddress, Token, TokenMetadataOverrides};
+
+/// Provider that builds token metadata from the configured overrides.
+///
+/// `token.name`, `token.symbol` and `token.decimals` must all be configured,
+/// as there is no chain to read them from.
+pub struct StaticTokenMetadataProvider {
+    metadata: TokenMetadataOverrides,
+}
+
+impl StaticTokenMetadataProvider {
+    /// Creates a new [`StaticTokenMetadataProvider`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `metadata` - The configured name, symbol and decimals.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`StaticTokenMetadataProvider`] instance.
+    pub fn new(metadata: TokenMetadataOverrides) -> Self {
+        Self { metadata }
+    }
+}
+
+impl TokenMetadataProvider for StaticTokenMetadataProvider {
+    async fn fetch_token_metadata(&self, token_address: ChainAddress) -> Result<Token> {
+        let token = self.metadata.to_token(token_address).ok_or_else(|| {
+            anyhow!("the static provider requires token.name, token.symbol and token.decimals")
+        })?;
+
+        info!("Token metadata: {}", token);
+
+        Ok(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn requires_every_field() {
+        // Arrange.
+        let address = ChainAddress::from(address!("0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118"));
+        let complete = TokenMetadataOverrides {
+            name: Some("Supply".into()),
+            symbol: Some("SUPPLY".into()),
+            decimals: Some(18),
+        };
+        let partial = TokenMetadataOverrides {
+            decimals: None,
+            ..complete.clone()
+        };
+
+        // Act.
+        let token = StaticTokenMetadataProvider::new(complete)
+            .fetch_token_metadata(address.clone())
+            .await;
+        let missing = StaticTokenMetadataProvider::new(partial)
+            .fetch_token_metadata(address.clone())
+            .await;
+
+        // Assert.
+        assert_eq!(token.unwrap(), Token::new("Supply", "SUPPLY", address, 18));
+        assert!(missing.unwrap_err().to_string().contains("token.decimals"));
+    }
+}
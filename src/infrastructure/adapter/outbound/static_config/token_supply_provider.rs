@@ -0,0 +1,164 @@
+use alloy::primitives::{U256, utils};
+use anyhow::{Result, anyhow};
+use tracing::info;
+
+use crate::application::port::outbound::TokenSupplyProvider;
+use crate::domain::model::{Source, SourceBalance, Token, TokenSupply};
+use crate::infrastructure::configuration::blockchain::{StaticSupplyConfig, parse_whole_tokens};
+
+/// Provider that serves the supply configured in `blockchain.static_supply`.
+///
+/// The total supply and the balance of each excluded source are taken from
+/// the configuration, so sources added through the admin API hold nothing
+/// until a balance is configured for them.
+pub struct StaticTokenSupplyProvider {
+    supply: StaticSupplyConfig,
+}
+
+impl StaticTokenSupplyProvider {
+    /// Creates a new [`StaticTokenSupplyProvider`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `supply` - The configured total supply and source balances.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`StaticTokenSupplyProvider`] instance.
+    pub fn new(supply: StaticSupplyConfig) -> Self {
+        Self { supply }
+    }
+
+    /// Returns the configured balance of a source, or zero if it has none.
+    fn balance_of(&self, source: &Source, decimals: u8) -> Result<U256> {
+        match self
+            .supply
+            .balances
+            .iter()
+            .find(|b| b.address == source.address)
+        {
+            Some(b) => parse_whole_tokens(&b.balance, decimals)
+                .map_err(|e| anyhow!("balance of {}: {}", source.name, e)),
+            None => Ok(U256::ZERO),
+        }
+    }
+}
+
+impl TokenSupplyProvider for StaticTokenSupplyProvider {
+    async fn fetch_token_supply(
+        &self,
+        token: &Token,
+        excluded_sources: &[Source],
+    ) -> Result<TokenSupply> {
+        info!("Reading static token supply for: {}", token);
+
+        let total_supply = parse_whole_tokens(&self.supply.total_supply, token.decimals)
+            .map_err(|e| anyhow!("total supply: {}", e))?;
+        let mut excluded = U256::ZERO;
+        let mut breakdown = Vec::with_capacity(excluded_sources.len());
+
+        for i in excluded_sources.iter() {
+            let bal = self.balance_of(i, token.decimals)?;
+
+            excluded = excluded.saturating_add(bal);
+
+            let formatted = utils::format_units(bal, token.decimals)?;
+            breakdown.push(
+                SourceBalance::new(i.name.clone(), i.address.clone(), formatted).with_raw(bal),
+            );
+        }
+
+        let mut token_supply = TokenSupply::from_raw(total_supply, excluded, token.decimals)?
+            .with_breakdown(breakdown);
+
+        if let Some(block_number) = self.supply.block_number {
+            token_supply = token_supply.with_block_number(block_number);
+        }
+
+        info!("Token Supply: {}", token_supply);
+
+        Ok(token_supply)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use crate::infrastructure::configuration::blockchain::StaticBalance;
+
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Test Helper Functions
+
+    fn create_token() -> Token {
+        let address = address!("0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118");
+
+        Token::new("Supply", "SUPPLY", address, 6)
+    }
+
+    fn create_source(name: &str, address: &str) -> Source {
+        Source {
+            name: name.into(),
+            address: address.parse().unwrap(),
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[tokio::test]
+    async fn subtracts_configured_balances() {
+        // Arrange.
+        let provider = StaticTokenSupplyProvider::new(StaticSupplyConfig {
+            total_supply: "1000000".into(),
+            balances: vec![StaticBalance {
+                address: "0xB1a932A665FB0A1D5d7979cd63e80a59EDCe31B4"
+                    .parse()
+                    .unwrap(),
+                balance: "250000.5".into(),
+            }],
+            block_number: Some(42),
+        });
+        let sink = create_source("Sink", "0xB1a932A665FB0A1D5d7979cd63e80a59EDCe31B4");
+        let other = create_source("Other", "0x000000000000000000000000000000000000dEaD");
+
+        // Act.
+        let supply = provider
+            .fetch_token_supply(&create_token(), &[sink, other])
+            .await
+            .unwrap();
+
+        // Assert.
+        assert_eq!(supply.total_supply, "1000000.000000");
+        assert_eq!(supply.circulating_supply, "749999.500000");
+        assert_eq!(
+            supply.raw_circulating_supply,
+            U256::from(749_999_500_000u64)
+        );
+        assert_eq!(supply.block_number, Some(42));
+        assert_eq!(supply.breakdown[0].balance, "250000.500000");
+        assert_eq!(supply.breakdown[1].balance, "0.000000");
+    }
+
+    #[tokio::test]
+    async fn rejects_amounts_finer_than_the_token() {
+        // Arrange.
+        let provider = StaticTokenSupplyProvider::new(StaticSupplyConfig {
+            total_supply: "1.0000001".into(),
+            balances: Vec::new(),
+            block_number: None,
+        });
+
+        // Act.
+        let result = provider.fetch_token_supply(&create_token(), &[]).await;
+
+        // Assert.
+        let error = result.unwrap_err().to_string();
+        assert_eq!(
+            error,
+            "total supply: `1.0000001` has more than the token's 6 decimals"
+        );
+    }
+}
@@ -0,0 +1,8 @@
+//! Webhook Outbound Adapters
+//!
+//! Webhook adapters deliver notifications to operators by posting them to
+//! HTTP endpoints, such as chat integrations or alerting services.
+
+pub mod webhook_notifier;
+
+pub use webhook_notifier::{WebhookNotifier, sign};
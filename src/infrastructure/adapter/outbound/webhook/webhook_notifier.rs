@@ -0,0 +1,479 @@
+use std::time::Duration;
+
+use anyhow::{Result, anyhow, bail};
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
+use hmac::{Hmac, Mac};
+use reqwest::header::CONTENT_TYPE;
+use serde_json::{Value, json};
+use sha2::Sha256;
+use tracing::warn;
+
+use crate::application::port::outbound::SupplyEventNotifier;
+use crate::domain::model::SupplyEvent;
+use crate::infrastructure::backoff::Backoff;
+use crate::infrastructure::configuration::notification::{
+    NotificationConfig, WebhookConfig, WebhookFormat,
+};
+
+/// The header carrying the Unix timestamp a delivery was signed at.
+pub const TIMESTAMP_HEADER: &str = "X-Supply-Timestamp";
+
+/// The header carrying the signature of a delivery.
+pub const SIGNATURE_HEADER: &str = "X-Supply-Signature";
+
+/// The header carrying the kind of the delivered event.
+pub const EVENT_HEADER: &str = "X-Supply-Event";
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// Delivers supply events by posting them to the configured webhooks.
+///
+/// Deliveries that fail with a network error, a `429` or a `5xx` response are
+/// retried with exponential backoff. Bodies sent to a webhook with a secret
+/// are signed, see [`sign`].
+pub struct WebhookNotifier {
+    client: reqwest::Client,
+    application: String,
+    webhooks: Vec<WebhookConfig>,
+    max_attempts: u32,
+    retry_delay: Duration,
+}
+
+impl WebhookNotifier {
+    /// Creates a new [`WebhookNotifier`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `application` - The application name included in every event.
+    /// * `config` - The webhooks to deliver to and how often to attempt it.
+    ///
+    /// # Returns
+    ///
+    /// A new [`WebhookNotifier`] instance, or an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be built.
+    pub fn new(application: impl Into<String>, config: &NotificationConfig) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()?;
+
+        Ok(Self {
+            client,
+            application: application.into(),
+            webhooks: config.webhooks.clone(),
+            max_attempts: config.max_attempts.max(1),
+            retry_delay: Duration::from_secs(1),
+        })
+    }
+
+    /// Sets the delay before the first retry. Later retries back off from it.
+    ///
+    /// # Arguments
+    ///
+    /// * `retry_delay` - The delay before the first retry.
+    ///
+    /// # Returns
+    ///
+    /// The notifier with the delay applied.
+    pub fn with_retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    fn body(&self, format: WebhookFormat, event: &SupplyEvent, now: DateTime<Utc>) -> Value {
+        let message = format!("[{}] {}", self.application, event);
+
+        match format {
+            WebhookFormat::Json => {
+                let mut body = json!({
+                    "application": self.application,
+                    "occurred_at": now.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+                    "message": event.to_string(),
+                });
+
+                if let (Value::Object(body), Ok(Value::Object(fields))) =
+                    (&mut body, serde_json::to_value(event))
+                {
+                    body.extend(fields);
+                }
+
+                body
+            }
+            WebhookFormat::Slack => json!({ "text": message }),
+            WebhookFormat::Discord => json!({ "content": message }),
+        }
+    }
+
+    async fn deliver(&self, webhook: &WebhookConfig, event: &SupplyEvent) -> Result<()> {
+        let now = Utc::now();
+        let body = serde_json::to_vec(&self.body(webhook.format, event, now))?;
+        let timestamp = now.timestamp();
+
+        let mut backoff = Backoff::new(self.retry_delay, MAX_RETRY_DELAY);
+        let mut attempt = 1;
+
+        loop {
+            let mut request = self
+                .client
+                .post(&webhook.url)
+                .header(CONTENT_TYPE, "application/json")
+                .header(EVENT_HEADER, event.kind().as_str())
+                .body(body.clone());
+
+            if let Some(secret) = &webhook.secret {
+                request = request
+                    .header(TIMESTAMP_HEADER, timestamp)
+                    .header(SIGNATURE_HEADER, sign(secret, timestamp, &body));
+            }
+
+            let error = match request.send().await {
+                Ok(response) if response.status().is_success() => return Ok(()),
+                Ok(response) => {
+                    let status = response.status();
+
+                    if !status.is_server_error() && status != reqwest::StatusCode::TOO_MANY_REQUESTS
+                    {
+                        bail!("rejected with {}", status);
+                    }
+
+                    anyhow!("failed with {}", status)
+                }
+                Err(e) => anyhow!(e.without_url()),
+            };
+
+            if attempt >= self.max_attempts {
+                return Err(error.context(format!("gave up after {} attempts", attempt)));
+            }
+
+            let delay = backoff.next_delay();
+            warn!(
+                "Webhook delivery attempt {} failed, retrying in {:?}: {}",
+                attempt, delay, error
+            );
+
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+impl SupplyEventNotifier for WebhookNotifier {
+    async fn notify(&self, event: &SupplyEvent) -> Result<()> {
+        let kind = event.kind();
+
+        let deliveries = self
+            .webhooks
+            .iter()
+            .enumerate()
+            .filter(|(_, webhook)| webhook.events.contains(&kind))
+            .map(|(i, webhook)| async move {
+                self.deliver(webhook, event)
+                    .await
+                    .map_err(|e| format!("notifications.webhooks[{}]: {:#}", i, e))
+            });
+
+        let failures: Vec<String> = join_all(deliveries)
+            .await
+            .into_iter()
+            .filter_map(Result::err)
+            .collect();
+
+        if !failures.is_empty() {
+            bail!("webhook delivery failed: {}", failures.join("; "));
+        }
+
+        Ok(())
+    }
+}
+
+/// Signs a webhook body.
+///
+/// The signature is the hex-encoded HMAC-SHA256 of `{timestamp}.{body}`, keyed
+/// with the webhook secret and prefixed with `sha256=`. Receivers should
+/// recompute it from the `X-Supply-Timestamp` header and the raw body, and
+/// reject deliveries with an old timestamp.
+///
+/// # Arguments
+///
+/// * `secret` - The webhook secret.
+/// * `timestamp` - The Unix timestamp sent in `X-Supply-Timestamp`.
+/// * `body` - The raw request body.
+///
+/// # Returns
+///
+/// The value of the `X-Supply-Signature` header.
+pub fn sign(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+
+    format!("sha256={}", alloy::hex::encode(mac.finalize().into_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+
+    use actix_web::{App, HttpRequest, HttpResponse, HttpServer, web};
+
+    use crate::domain::model::SupplyEventKind;
+
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Mock Implementations for Testing
+
+    #[derive(Debug, Clone)]
+    struct Delivery {
+        event: Option<String>,
+        timestamp: Option<String>,
+        signature: Option<String>,
+        body: Vec<u8>,
+    }
+
+    impl Delivery {
+        fn json(&self) -> Value {
+            serde_json::from_slice(&self.body).unwrap()
+        }
+    }
+
+    /// A local stand-in for a webhook receiver that records every request and
+    /// fails the first few.
+    #[derive(Clone)]
+    struct Receiver {
+        deliveries: Arc<Mutex<Vec<Delivery>>>,
+        failures: Arc<Mutex<u32>>,
+        status: u16,
+        url: String,
+    }
+
+    impl Receiver {
+        fn start(failures: u32, status: u16) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}/hook", listener.local_addr().unwrap());
+
+            let receiver = Self {
+                deliveries: Arc::new(Mutex::new(Vec::new())),
+                failures: Arc::new(Mutex::new(failures)),
+                status,
+                url,
+            };
+
+            let state = receiver.clone();
+            let server = HttpServer::new(move || {
+                App::new()
+                    .app_data(web::Data::new(state.clone()))
+                    .route("/hook", web::post().to(Receiver::handle))
+            })
+            .workers(1)
+            .listen(listener)
+            .unwrap()
+            .run();
+
+            tokio::spawn(server);
+
+            receiver
+        }
+
+        async fn handle(
+            req: HttpRequest,
+            body: web::Bytes,
+            state: web::Data<Receiver>,
+        ) -> HttpResponse {
+            let header = |name: &str| {
+                req.headers()
+                    .get(name)
+                    .map(|v| v.to_str().unwrap().to_string())
+            };
+
+            state.deliveries.lock().unwrap().push(Delivery {
+                event: header(EVENT_HEADER),
+                timestamp: header(TIMESTAMP_HEADER),
+                signature: header(SIGNATURE_HEADER),
+                body: body.to_vec(),
+            });
+
+            let mut failures = state.failures.lock().unwrap();
+
+            if *failures > 0 {
+                *failures -= 1;
+                return HttpResponse::build(
+                    actix_web::http::StatusCode::from_u16(state.status).unwrap(),
+                )
+                .finish();
+            }
+
+            HttpResponse::NoContent().finish()
+        }
+
+        fn deliveries(&self) -> Vec<Delivery> {
+            self.deliveries.lock().unwrap().clone()
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Test Helper Functions
+
+    fn webhook(url: &str, format: WebhookFormat, secret: Option<&str>) -> WebhookConfig {
+        WebhookConfig {
+            url: url.into(),
+            format,
+            secret: secret.map(String::from),
+            events: SupplyEventKind::ALL.to_vec(),
+        }
+    }
+
+    fn notifier(webhooks: Vec<WebhookConfig>) -> WebhookNotifier {
+        let config = NotificationConfig {
+            webhooks,
+            ..NotificationConfig::default()
+        };
+
+        WebhookNotifier::new("supply-api", &config)
+            .unwrap()
+            .with_retry_delay(Duration::from_millis(10))
+    }
+
+    fn failing() -> SupplyEvent {
+        SupplyEvent::FetchFailing {
+            consecutive_failures: 3,
+            error: "connection refused".into(),
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[tokio::test]
+    async fn delivers_signed_json_events() {
+        // Arrange.
+        let receiver = Receiver::start(0, 500);
+        let notifier = notifier(vec![webhook(
+            &receiver.url,
+            WebhookFormat::Json,
+            Some("s3cret"),
+        )]);
+
+        // Act.
+        notifier.notify(&failing()).await.unwrap();
+
+        // Assert.
+        let deliveries = receiver.deliveries();
+        assert_eq!(deliveries.len(), 1);
+
+        let delivery = &deliveries[0];
+        let timestamp: i64 = delivery.timestamp.as_ref().unwrap().parse().unwrap();
+        let expected = sign("s3cret", timestamp, &delivery.body);
+        assert_eq!(delivery.signature.as_deref(), Some(expected.as_str()));
+        assert_eq!(delivery.event.as_deref(), Some("fetch_failing"));
+
+        let body = delivery.json();
+        assert_eq!(body["event"], "fetch_failing");
+        assert_eq!(body["application"], "supply-api");
+        assert_eq!(body["consecutive_failures"], 3);
+        assert_eq!(body["error"], "connection refused");
+    }
+
+    #[tokio::test]
+    async fn formats_chat_messages_without_signing() {
+        // Arrange.
+        let slack = Receiver::start(0, 500);
+        let discord = Receiver::start(0, 500);
+        let notifier = notifier(vec![
+            webhook(&slack.url, WebhookFormat::Slack, None),
+            webhook(&discord.url, WebhookFormat::Discord, None),
+        ]);
+
+        // Act.
+        notifier.notify(&failing()).await.unwrap();
+
+        // Assert.
+        let message = "[supply-api] Supply refresh failed 3 times in a row: connection refused";
+        let slack = &slack.deliveries()[0];
+        assert_eq!(slack.json(), json!({ "text": message }));
+        assert_eq!(slack.signature, None);
+        assert_eq!(
+            discord.deliveries()[0].json(),
+            json!({ "content": message })
+        );
+    }
+
+    #[tokio::test]
+    async fn retries_server_errors_until_delivered() {
+        // Arrange.
+        let receiver = Receiver::start(2, 503);
+        let notifier = notifier(vec![webhook(&receiver.url, WebhookFormat::Json, None)]);
+
+        // Act.
+        let result = notifier.notify(&failing()).await;
+
+        // Assert.
+        assert!(result.is_ok());
+        assert_eq!(receiver.deliveries().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        // Arrange.
+        let receiver = Receiver::start(5, 500);
+        let notifier = notifier(vec![webhook(&receiver.url, WebhookFormat::Json, None)]);
+
+        // Act.
+        let result = notifier.notify(&failing()).await;
+
+        // Assert.
+        let error = result.unwrap_err().to_string();
+        assert!(error.contains("notifications.webhooks[0]"), "{}", error);
+        assert!(error.contains("gave up after 3 attempts"), "{}", error);
+        assert_eq!(receiver.deliveries().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_client_errors() {
+        // Arrange.
+        let receiver = Receiver::start(5, 404);
+        let notifier = notifier(vec![webhook(&receiver.url, WebhookFormat::Json, None)]);
+
+        // Act.
+        let result = notifier.notify(&failing()).await;
+
+        // Assert.
+        assert!(result.is_err());
+        assert_eq!(receiver.deliveries().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn skips_webhooks_not_subscribed_to_the_event() {
+        // Arrange.
+        let receiver = Receiver::start(0, 500);
+        let mut subscribed = webhook(&receiver.url, WebhookFormat::Json, None);
+        subscribed.events = vec![SupplyEventKind::Recovered];
+        let notifier = notifier(vec![subscribed]);
+
+        // Act.
+        notifier.notify(&failing()).await.unwrap();
+
+        // Assert.
+        assert!(receiver.deliveries().is_empty());
+    }
+
+    #[test]
+    fn sign_matches_a_known_signature() {
+        // Act.
+        let signature = sign("secret", 1700000000, b"{}");
+
+        // Assert.
+        assert_eq!(
+            signature,
+            "sha256=b8569b78799ff9e3cbff0fc2d63a33a2b57f3282abd07c37ae5e8e7d79a5f163"
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+/// Exponential backoff for retrying failed operations.
+///
+/// Each call to [`Backoff::next_delay`] returns twice the previous delay,
+/// capped at a maximum. [`Backoff::reset`] starts over after a success.
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    initial: Duration,
+    max: Duration,
+    current: Duration,
+}
+
+impl Backoff {
+    /// Creates a new [`Backoff`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `initial` - The first delay.
+    /// * `max` - The largest delay that will ever be returned.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`Backoff`] instance.
+    pub fn new(initial: Duration, max: Duration) -> Self {
+        Self {
+            initial,
+            max,
+            current: initial,
+        }
+    }
+
+    /// Returns the delay to wait before the next attempt.
+    pub fn next_delay(&mut self) -> Duration {
+        let delay = self.current.min(self.max);
+        self.current = (self.current * 2).min(self.max);
+        delay
+    }
+
+    /// Resets the delay to its initial value.
+    pub fn reset(&mut self) {
+        self.current = self.initial;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delays_double_up_to_the_maximum() {
+        // Arrange.
+        let mut backoff = Backoff::new(Duration::from_secs(1), Duration::from_secs(5));
+
+        // Act.
+        let delays: Vec<u64> = (0..5).map(|_| backoff.next_delay().as_secs()).collect();
+
+        // Assert.
+        assert_eq!(delays, vec![1, 2, 4, 5, 5]);
+    }
+
+    #[test]
+    fn reset_starts_over() {
+        // Arrange.
+        let mut backoff = Backoff::new(Duration::from_secs(1), Duration::from_secs(5));
+        backoff.next_delay();
+        backoff.next_delay();
+
+        // Act.
+        backoff.reset();
+
+        // Assert.
+        assert_eq!(backoff.next_delay(), Duration::from_secs(1));
+    }
+}
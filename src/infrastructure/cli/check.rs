@@ -0,0 +1,352 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use chrono::{NaiveDate, Utc};
+use serde::Serialize;
+
+use crate::application::port::inbound::TokenMetadataService;
+use crate::application::port::outbound::{
+    ChainIdProvider, SourceRepository, TokenMetadataProvider, TokenSupplyProvider,
+};
+use crate::application::use_case::{SupplyAdjuster, SupplyGuard, TokenMetadataUseCase};
+use crate::domain::model::{Token, TokenSupply};
+use crate::infrastructure::adapter::outbound::chain::ChainVerifier;
+use crate::infrastructure::adapter::outbound::persistence::{
+    FileSourceRepository, FileTokenMetadataCache,
+};
+use crate::infrastructure::configuration::Config;
+use crate::infrastructure::telemetry::setup_tracing;
+
+use super::{OutputFormat, ProviderCommand, ensure_valid, with_providers};
+
+/// Fetches the token supply once and prints it.
+///
+/// Logs are written to stderr so the printed result can be piped.
+///
+/// # Arguments
+///
+/// * `config` - The loaded application configuration.
+/// * `format` - How to print the result.
+///
+/// # Returns
+///
+/// `Result<()>` - Success, or the error that stopped the fetch.
+pub async fn run(config: Config, format: OutputFormat) -> Result<()> {
+    setup_tracing(config.application_name.clone(), std::io::stderr);
+
+    ensure_valid(&config)?;
+
+    with_providers(config, Check { format }).await
+}
+
+struct Check {
+    format: OutputFormat,
+}
+
+impl ProviderCommand for Check {
+    async fn run<C, S, M>(
+        self,
+        config: Config,
+        chain_id_provider: C,
+        supply_provider: S,
+        metadata_provider: M,
+    ) -> Result<()>
+    where
+        C: ChainIdProvider + Send + Sync + 'static,
+        S: TokenSupplyProvider + Send + Sync + 'static,
+        M: TokenMetadataProvider + Send + Sync + 'static,
+    {
+        check(
+            config,
+            self.format,
+            chain_id_provider,
+            supply_provider,
+            metadata_provider,
+        )
+        .await
+    }
+}
+
+/// Fetches the token supply once with the providers of the configured chain.
+async fn check<C, S, M>(
+    config: Config,
+    format: OutputFormat,
+    chain_id_provider: C,
+    supply_provider: S,
+    metadata_provider: M,
+) -> Result<()>
+where
+    C: ChainIdProvider,
+    S: TokenSupplyProvider,
+    M: TokenMetadataProvider + Send + Sync,
+{
+    let chain_verifier = ChainVerifier::new(
+        chain_id_provider,
+        config.blockchain.chain_id,
+        Duration::ZERO,
+    );
+
+    let chain_id = chain_verifier.verify().await?;
+
+    let metadata_cache = FileTokenMetadataCache::new(config.persistence.token_metadata_path());
+    let metadata_service = TokenMetadataUseCase::new(metadata_provider, metadata_cache);
+
+    let token = metadata_service
+        .resolve_token_metadata(config.token.address, &config.token.overrides)
+        .await?;
+
+    // Sources changed through the admin API take precedence, as in `serve`.
+    let sources = FileSourceRepository::new(config.persistence.excluded_sources_path())
+        .load()
+        .await?
+        .unwrap_or(config.excluded_sources);
+
+    let supply = supply_provider.fetch_token_supply(&token, &sources).await?;
+    let supply = SupplyAdjuster::new(config.adjustments).apply(
+        supply,
+        token.decimals,
+        Utc::now().date_naive(),
+    )?;
+
+    let output = match format {
+        OutputFormat::Table => render_table(chain_id, &token, &supply),
+        OutputFormat::Json => render_json(chain_id, &token, &supply)?,
+    };
+
+    println!("{}", output);
+
+    // Apply the checks `serve` makes before publishing once the numbers are
+    // printed, so an operator can see what would be rejected and why.
+    SupplyGuard::default()
+        .with_reject_zero_circulating(config.guards.reject_zero_circulating)
+        .check(None, &supply)
+        .context("`serve` would not publish this supply")?;
+
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct CheckReport<'a> {
+    chain_id: u64,
+    token: TokenReport<'a>,
+    total_supply: &'a str,
+    circulating_supply: &'a str,
+    excluded_sources: Vec<SourceReport<'a>>,
+    adjustments: Vec<AdjustmentReport<'a>>,
+}
+
+#[derive(Serialize)]
+struct TokenReport<'a> {
+    name: &'a str,
+    symbol: &'a str,
+    address: String,
+    decimals: u8,
+}
+
+#[derive(Serialize)]
+struct SourceReport<'a> {
+    name: &'a str,
+    address: String,
+    balance: &'a str,
+}
+
+#[derive(Serialize)]
+struct AdjustmentReport<'a> {
+    reason: &'a str,
+    amount: &'a str,
+    effective_from: NaiveDate,
+    expires_on: Option<NaiveDate>,
+}
+
+fn render_json(chain_id: u64, token: &Token, supply: &TokenSupply) -> Result<String> {
+    let report = CheckReport {
+        chain_id,
+        token: TokenReport {
+            name: &token.name,
+            symbol: &token.symbol,
+            address: token.address.to_string(),
+            decimals: token.decimals,
+        },
+        total_supply: &supply.total_supply,
+        circulating_supply: &supply.circulating_supply,
+        excluded_sources: supply
+            .breakdown
+            .iter()
+            .map(|b| SourceReport {
+                name: &b.name,
+                address: b.address.to_string(),
+                balance: &b.balance,
+            })
+            .collect(),
+        adjustments: supply
+            .adjustments
+            .iter()
+            .map(|a| AdjustmentReport {
+                reason: &a.reason,
+                amount: &a.amount,
+                effective_from: a.effective_from,
+                expires_on: a.expires_on,
+            })
+            .collect(),
+    };
+
+    Ok(serde_json::to_string_pretty(&report)?)
+}
+
+fn render_table(chain_id: u64, token: &Token, supply: &TokenSupply) -> String {
+    let rows: Vec<[String; 3]> = supply
+        .breakdown
+        .iter()
+        .map(|b| [b.name.clone(), b.address.to_string(), b.balance.clone()])
+        .collect();
+
+    let mut out = String::new();
+    let _ = writeln!(out, "Token:              {}", token);
+    let _ = writeln!(out, "Chain ID:           {}", chain_id);
+    let _ = writeln!(out);
+
+    write_table(&mut out, ["SOURCE", "ADDRESS", "BALANCE"], &rows);
+
+    if rows.is_empty() {
+        let _ = writeln!(out, "(no excluded sources)");
+    }
+
+    if !supply.adjustments.is_empty() {
+        let rows: Vec<[String; 3]> = supply
+            .adjustments
+            .iter()
+            .map(|a| {
+                let period = match a.expires_on {
+                    Some(expires_on) => format!("{} to {}", a.effective_from, expires_on),
+                    None => format!("from {}", a.effective_from),
+                };
+
+                [a.reason.clone(), period, a.amount.clone()]
+            })
+            .collect();
+
+        let _ = writeln!(out);
+        write_table(&mut out, ["ADJUSTMENT", "APPLIES", "AMOUNT"], &rows);
+    }
+
+    let _ = writeln!(out);
+    let _ = writeln!(out, "Total supply:       {}", supply.total_supply);
+    let _ = write!(out, "Circulating supply: {}", supply.circulating_supply);
+
+    out
+}
+
+/// Writes a table whose last column is right-aligned.
+fn write_table(out: &mut String, header: [&str; 3], rows: &[[String; 3]]) {
+    let header = header.map(String::from);
+    let mut widths = header.clone().map(|h| h.len());
+    for row in rows {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(cell.len());
+        }
+    }
+
+    for row in std::iter::once(&header).chain(rows) {
+        let _ = writeln!(
+            out,
+            "{:<w0$}  {:<w1$}  {:>w2$}",
+            row[0],
+            row[1],
+            row[2],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2]
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::{I256, address};
+
+    use crate::domain::model::{AppliedAdjustment, SourceBalance};
+
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Test Helper Functions
+
+    fn create_token() -> Token {
+        let name = "Supply";
+        let symbol = "SUPPLY";
+        let address = address!("0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118");
+        let decimals = 18;
+
+        Token::new(name, symbol, address, decimals)
+    }
+
+    fn create_supply() -> TokenSupply {
+        let sink = address!("0xB1a932A665FB0A1D5d7979cd63e80a59EDCe31B4");
+        let breakdown = vec![SourceBalance::new("Sink", sink, "100000.0")];
+
+        TokenSupply::new("1000000.0", "900000.0").with_breakdown(breakdown)
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[test]
+    fn table_lists_each_excluded_source() {
+        // Act.
+        let output = render_table(11155111, &create_token(), &create_supply());
+
+        // Assert.
+        let expected = "\
+Token:              Supply (SUPPLY) - 18 decimals: 0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118
+Chain ID:           11155111
+
+SOURCE  ADDRESS                                      BALANCE
+Sink    0xB1a932A665FB0A1D5d7979cd63e80a59EDCe31B4  100000.0
+
+Total supply:       1000000.0
+Circulating supply: 900000.0";
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn json_contains_totals_and_breakdown() {
+        // Act.
+        let output = render_json(11155111, &create_token(), &create_supply()).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+        // Assert.
+        assert_eq!(value["chain_id"], 11155111);
+        assert_eq!(value["token"]["symbol"], "SUPPLY");
+        assert_eq!(value["total_supply"], "1000000.0");
+        assert_eq!(value["circulating_supply"], "900000.0");
+        assert_eq!(value["excluded_sources"][0]["name"], "Sink");
+        assert_eq!(value["excluded_sources"][0]["balance"], "100000.0");
+        assert_eq!(value["adjustments"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn table_lists_each_adjustment() {
+        // Arrange.
+        let adjustment = AppliedAdjustment {
+            reason: "Custodian".into(),
+            amount: "-2500.0".into(),
+            raw_amount: I256::ZERO,
+            effective_from: "2025-01-01".parse().unwrap(),
+            expires_on: Some("2025-07-01".parse().unwrap()),
+        };
+        let supply = create_supply().with_adjustments(vec![adjustment]);
+
+        // Act.
+        let output = render_table(11155111, &create_token(), &supply);
+
+        // Assert.
+        let expected = "\
+ADJUSTMENT  APPLIES                    AMOUNT
+Custodian   2025-01-01 to 2025-07-01  -2500.0
+";
+
+        assert!(output.contains(expected), "Unexpected output:\n{}", output);
+    }
+}
@@ -0,0 +1,282 @@
+//! Command-Line Interface
+//!
+//! This module defines the arguments and subcommands of the service binary.
+//! Each subcommand lives in its own module and receives the loaded
+//! configuration.
+
+pub mod check;
+pub mod openapi;
+pub mod serve;
+pub mod validate_config;
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use alloy::providers::ProviderBuilder;
+use anyhow::{Result, anyhow};
+use clap::{Parser, Subcommand, ValueEnum};
+use tracing::{error, warn};
+
+use crate::application::port::outbound::{
+    ChainIdProvider, TokenMetadataProvider, TokenSupplyProvider,
+};
+use crate::infrastructure::adapter::outbound::blockchain::{
+    BlockchainChainIdProvider, BlockchainTokenMetadataProvider, BlockchainTokenSupplyProvider,
+};
+use crate::infrastructure::adapter::outbound::cosmos::{
+    CosmosChainIdProvider, CosmosLcdClient, CosmosTokenMetadataProvider, CosmosTokenSupplyProvider,
+};
+use crate::infrastructure::adapter::outbound::solana::{
+    SolanaChainIdProvider, SolanaRpcClient, SolanaTokenMetadataProvider, SolanaTokenSupplyProvider,
+};
+use crate::infrastructure::adapter::outbound::static_config::{
+    StaticChainIdProvider, StaticTokenMetadataProvider, StaticTokenSupplyProvider,
+};
+use crate::infrastructure::configuration::Config;
+use crate::infrastructure::configuration::blockchain::ProviderKind;
+use crate::infrastructure::configuration::environment::Environment;
+
+/// CoinGecko compatible token supply service.
+#[derive(Parser, Debug)]
+#[command(version, about)]
+pub struct Cli {
+    /// Configuration file to load instead of `configuration/[environment].yaml`.
+    #[arg(long, global = true, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// Environment whose configuration to load. Overrides `APP_ENVIRONMENT`.
+    #[arg(long, global = true, value_name = "ENVIRONMENT", value_parser = parse_environment)]
+    pub env: Option<Environment>,
+
+    /// The subcommand to run. Defaults to `serve`.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// The subcommands supported by the binary.
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Run the HTTP server and the background supply worker.
+    Serve,
+
+    /// Fetch the token supply once and print it with a per-source breakdown.
+    Check {
+        /// How to print the result.
+        #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+        format: OutputFormat,
+    },
+
+    /// Load and validate the configuration without network access.
+    ValidateConfig,
+
+    /// Print the OpenAPI document of the HTTP API.
+    Openapi,
+}
+
+/// Output formats supported by commands that print results.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// A human-readable table.
+    Table,
+
+    /// A JSON document.
+    Json,
+}
+
+impl Cli {
+    /// Loads the configuration and runs the selected subcommand.
+    ///
+    /// # Returns
+    ///
+    /// `Result<()>` - Success, or the error that stopped the subcommand.
+    pub async fn run(self) -> Result<()> {
+        if let Some(Command::Openapi) = self.command {
+            return openapi::run();
+        }
+
+        let config = Config::load_with(self.config.as_deref(), self.env)?;
+
+        match self.command.unwrap_or(Command::Serve) {
+            Command::Serve => serve::run(config).await,
+            Command::Check { format } => check::run(config, format).await,
+            Command::ValidateConfig => validate_config::run(config),
+            Command::Openapi => openapi::run(),
+        }
+    }
+}
+
+fn parse_environment(s: &str) -> Result<Environment, String> {
+    Environment::try_from(s.to_string())
+}
+
+/// Validates the configuration and logs every issue found.
+///
+/// # Errors
+///
+/// Returns the validation report if it contains at least one error.
+pub(crate) fn ensure_valid(config: &Config) -> Result<()> {
+    let report = config.validate();
+
+    for issue in report.warnings() {
+        warn!("Configuration warning at {}", issue);
+    }
+
+    if report.has_errors() {
+        for issue in report.errors() {
+            error!("Configuration error at {}", issue);
+        }
+
+        return Err(report.into());
+    }
+
+    Ok(())
+}
+
+/// A subcommand that runs against the providers of the configured chain.
+pub(crate) trait ProviderCommand {
+    /// Runs the subcommand with the providers built by [`with_providers`].
+    async fn run<C, S, M>(
+        self,
+        config: Config,
+        chain_id_provider: C,
+        supply_provider: S,
+        metadata_provider: M,
+    ) -> Result<()>
+    where
+        C: ChainIdProvider + Send + Sync + 'static,
+        S: TokenSupplyProvider + Send + Sync + 'static,
+        M: TokenMetadataProvider + Send + Sync + 'static;
+}
+
+/// Builds the providers of the configured chain and runs a subcommand with
+/// them.
+///
+/// # Arguments
+///
+/// * `config` - The loaded application configuration.
+/// * `command` - The subcommand to run.
+///
+/// # Errors
+///
+/// Returns an error if a client cannot be built from the configuration, or
+/// the error that stopped the subcommand.
+pub(crate) async fn with_providers(config: Config, command: impl ProviderCommand) -> Result<()> {
+    match config.blockchain.provider {
+        ProviderKind::Evm => {
+            let alloy_provider = ProviderBuilder::new().on_http(config.blockchain.rpc_url.parse()?);
+            let alloy_provider = Arc::new(alloy_provider);
+
+            command
+                .run(
+                    config,
+                    BlockchainChainIdProvider::new(alloy_provider.clone()),
+                    BlockchainTokenSupplyProvider::new(alloy_provider.clone()),
+                    BlockchainTokenMetadataProvider::new(alloy_provider),
+                )
+                .await
+        }
+        ProviderKind::Solana => {
+            let rpc = Arc::new(SolanaRpcClient::new(config.blockchain.rpc_url.clone())?);
+            let chain_id_provider = match config.blockchain.genesis_hash.clone() {
+                Some(genesis_hash) => SolanaChainIdProvider::new(rpc.clone())
+                    .with_cluster(genesis_hash, config.blockchain.chain_id),
+                None => SolanaChainIdProvider::new(rpc.clone()),
+            };
+
+            command
+                .run(
+                    config,
+                    chain_id_provider,
+                    SolanaTokenSupplyProvider::new(rpc.clone()),
+                    SolanaTokenMetadataProvider::new(rpc),
+                )
+                .await
+        }
+        ProviderKind::Cosmos => {
+            let lcd = Arc::new(CosmosLcdClient::new(config.blockchain.rpc_url.clone())?);
+            let network = config.blockchain.network.clone().unwrap_or_default();
+            let chain_id = config.blockchain.chain_id;
+
+            command
+                .run(
+                    config,
+                    CosmosChainIdProvider::new(lcd.clone(), network, chain_id),
+                    CosmosTokenSupplyProvider::new(lcd.clone()),
+                    CosmosTokenMetadataProvider::new(lcd),
+                )
+                .await
+        }
+        ProviderKind::Static => {
+            let chain_id = config.blockchain.chain_id;
+            let supply = config
+                .blockchain
+                .static_supply
+                .clone()
+                .ok_or_else(|| anyhow!("blockchain.static_supply is required"))?;
+            let metadata = config.token.overrides.clone();
+
+            command
+                .run(
+                    config,
+                    StaticChainIdProvider::new(chain_id),
+                    StaticTokenSupplyProvider::new(supply),
+                    StaticTokenMetadataProvider::new(metadata),
+                )
+                .await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+
+    use super::*;
+
+    #[test]
+    fn cli_definition_is_valid() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn defaults_to_serve_without_subcommand() {
+        // Act.
+        let cli = Cli::try_parse_from(["coingecko_supply"]).unwrap();
+
+        // Assert.
+        assert!(cli.command.is_none());
+        assert!(cli.config.is_none());
+    }
+
+    #[test]
+    fn parses_check_with_global_overrides() {
+        // Act.
+        let cli = Cli::try_parse_from([
+            "coingecko_supply",
+            "check",
+            "--format",
+            "json",
+            "--env",
+            "staging",
+        ])
+        .unwrap();
+
+        // Assert.
+        assert!(matches!(cli.env, Some(Environment::Staging)));
+        assert!(matches!(
+            cli.command,
+            Some(Command::Check {
+                format: OutputFormat::Json
+            })
+        ));
+    }
+
+    #[test]
+    fn rejects_unknown_environment() {
+        // Act.
+        let result = Cli::try_parse_from(["coingecko_supply", "--env", "mainnet"]);
+
+        // Assert.
+        assert!(result.is_err(), "Expected error for unknown environment");
+    }
+}
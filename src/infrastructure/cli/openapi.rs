@@ -0,0 +1,20 @@
+use anyhow::Result;
+use utoipa::OpenApi;
+
+use crate::infrastructure::adapter::inbound::http::openapi::ApiDoc;
+
+/// Prints the OpenAPI document of the HTTP API.
+///
+/// The configuration is not needed, so this runs anywhere the binary builds.
+/// CI compares the output with the committed `openapi.json` to catch
+/// unreviewed changes to the API contract.
+///
+/// # Returns
+///
+/// `Result<()>` - Success, or an error if the document could not be
+/// serialized.
+pub fn run() -> Result<()> {
+    println!("{}", ApiDoc::openapi().to_pretty_json()?);
+
+    Ok(())
+}
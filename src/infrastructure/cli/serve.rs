@@ -0,0 +1,346 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use actix_web::web::Data;
+use anyhow::Result;
+use tokio::time::sleep;
+use tracing::{error, info, warn};
+
+use crate::application::port::inbound::{TokenMetadataService, TokenSupplyService};
+use crate::application::port::outbound::{
+    ChainIdProvider, TokenMetadataProvider, TokenSupplyProvider,
+};
+use crate::application::use_case::{
+    SourceUseCase, SupplyAdjuster, SupplyEventDetector, SupplyGuard, TokenMetadataUseCase,
+    TokenSupplyUseCase,
+};
+use crate::domain::model::Token;
+use crate::infrastructure::adapter::inbound::http::HttpApplication;
+use crate::infrastructure::adapter::inbound::http::explorer::Explorer;
+use crate::infrastructure::adapter::inbound::http::resolved_token::ResolvedToken;
+use crate::infrastructure::adapter::outbound::chain::{
+    ChainMismatch, ChainVerifiedTokenSupplyProvider, ChainVerifier,
+};
+use crate::infrastructure::adapter::outbound::cosmos::NetworkMismatch;
+use crate::infrastructure::adapter::outbound::persistence::{
+    FileSourceAuditLog, FileSourceRepository, FileTokenMetadataCache, FileTokenSupplyRepository,
+};
+use crate::infrastructure::adapter::outbound::solana::UnknownCluster;
+use crate::infrastructure::adapter::outbound::webhook::WebhookNotifier;
+use crate::infrastructure::backoff::Backoff;
+use crate::infrastructure::configuration::Config;
+use crate::infrastructure::configuration::token::TokenConfig;
+use crate::infrastructure::shutdown::shutdown_signal;
+use crate::infrastructure::telemetry::setup_tracing;
+use crate::infrastructure::worker::{
+    NotificationWorker, RefreshTrigger, TokenSupplyWorker, WorkerHandle, WorkerMonitor,
+};
+
+use super::{ProviderCommand, ensure_valid, with_providers};
+
+/// The first delay between failed startup attempts.
+const BOOTSTRAP_INITIAL_DELAY: Duration = Duration::from_secs(1);
+
+/// The longest delay between failed startup attempts.
+const BOOTSTRAP_MAX_DELAY: Duration = Duration::from_secs(60);
+
+/// Runs the HTTP server and the background supply worker until `SIGINT` or
+/// `SIGTERM`.
+///
+/// The HTTP server starts immediately and serves the last persisted snapshot,
+/// or `503 Service Unavailable` until the first fetch completes. Connecting to
+/// the blockchain is retried in the background, so a brief RPC outage during a
+/// deploy does not stop the service from starting.
+///
+/// On shutdown the server stops accepting connections and, together with the
+/// worker, gets `server.shutdown_timeout` seconds to finish the work in
+/// progress.
+///
+/// # Arguments
+///
+/// * `config` - The loaded application configuration.
+///
+/// # Returns
+///
+/// `Result<()>` - Success, or the error that stopped the service.
+pub async fn run(config: Config) -> Result<()> {
+    setup_tracing(config.application_name.clone(), std::io::stdout);
+
+    ensure_valid(&config)?;
+
+    with_providers(config, Serve).await
+}
+
+struct Serve;
+
+impl ProviderCommand for Serve {
+    async fn run<C, S, M>(
+        self,
+        config: Config,
+        chain_id_provider: C,
+        supply_provider: S,
+        metadata_provider: M,
+    ) -> Result<()>
+    where
+        C: ChainIdProvider + Send + Sync + 'static,
+        S: TokenSupplyProvider + Send + Sync + 'static,
+        M: TokenMetadataProvider + Send + Sync + 'static,
+    {
+        serve(
+            config,
+            chain_id_provider,
+            supply_provider,
+            metadata_provider,
+        )
+        .await
+    }
+}
+
+/// Runs the service against the providers of the configured chain.
+async fn serve<C, S, M>(
+    config: Config,
+    chain_id_provider: C,
+    supply_provider: S,
+    metadata_provider: M,
+) -> Result<()>
+where
+    C: ChainIdProvider + Send + Sync + 'static,
+    S: TokenSupplyProvider + Send + Sync + 'static,
+    M: TokenMetadataProvider + Send + Sync + 'static,
+{
+    let chain_verifier = ChainVerifier::new(
+        chain_id_provider,
+        config.blockchain.chain_id,
+        Duration::from_secs(config.blockchain.chain_check_interval),
+    );
+    let chain_verifier = Arc::new(chain_verifier);
+
+    let supply_provider =
+        ChainVerifiedTokenSupplyProvider::new(supply_provider, chain_verifier.clone());
+    let metadata_cache = FileTokenMetadataCache::new(config.persistence.token_metadata_path());
+    let metadata_service = TokenMetadataUseCase::new(metadata_provider, metadata_cache);
+    let metadata_service = Arc::new(metadata_service);
+
+    let repo = FileTokenSupplyRepository::open(config.persistence.token_supply_path()).await;
+
+    let service = TokenSupplyUseCase::new(supply_provider, repo)
+        .with_guard(
+            SupplyGuard::new(config.guards.max_change_percent)
+                .with_confirmations(config.guards.confirmations)
+                .with_reject_zero_circulating(config.guards.reject_zero_circulating),
+        )
+        .with_adjuster(SupplyAdjuster::new(config.adjustments.clone()));
+    let service = Arc::new(service);
+
+    let source_service = SourceUseCase::load(
+        FileSourceRepository::new(config.persistence.excluded_sources_path()),
+        FileSourceAuditLog::new(config.persistence.source_audit_path()),
+        config.excluded_sources,
+    )
+    .await?
+    .with_token_address(config.token.address.clone());
+    let source_service = match config.blockchain.provider.address_kind() {
+        Some(address_kind) => source_service.with_address_kind(address_kind),
+        None => source_service,
+    };
+    source_service.validate().await?;
+    let source_service = Arc::new(source_service);
+
+    let monitor = Arc::new(WorkerMonitor::new());
+    let refresh_trigger = Arc::new(RefreshTrigger::new());
+    let explorer = Explorer::new(config.blockchain.resolved_explorer_url());
+    let resolved_token = Arc::new(ResolvedToken::new(explorer));
+
+    let update_interval = Duration::from_secs(config.server.update_interval);
+    let notifications = &config.notifications;
+    let notification_handle = match notifications.is_enabled() {
+        true => {
+            let notifier = WebhookNotifier::new(config.application_name.clone(), notifications)?;
+            let stale_after = chrono::TimeDelta::from_std(update_interval * 2)?;
+
+            let mut detector = SupplyEventDetector::new(
+                notifications.change_percent,
+                notifications.failure_threshold,
+                stale_after,
+            );
+
+            if let Ok(published) = service.get_token_supply().await
+                && published.version > 0
+            {
+                detector = detector.with_previous(published);
+            }
+
+            let (worker, handle) = NotificationWorker::new(
+                Arc::new(notifier),
+                detector,
+                refresh_trigger.clone(),
+                update_interval,
+            );
+            worker.start(&handle).await;
+
+            Some(handle)
+        }
+        false => None,
+    };
+
+    let app = HttpApplication::build(
+        config.server.clone(),
+        Data::from(service.clone()),
+        Data::from(monitor.clone()),
+        Data::from(refresh_trigger.clone()),
+        Data::from(source_service.clone()),
+        Data::from(chain_verifier.clone()),
+        Data::from(resolved_token.clone()),
+    )
+    .await?;
+    let server_handle = app.handle();
+    let server = app.run();
+    tokio::pin!(server);
+
+    let grace_period = Duration::from_secs(config.server.shutdown_timeout);
+    let shutdown = shutdown_signal();
+    tokio::pin!(shutdown);
+
+    let mut bootstrap = tokio::spawn(bootstrap(
+        chain_verifier,
+        metadata_service,
+        config.token.clone(),
+    ));
+
+    let token = tokio::select! {
+        result = &mut server => return Ok(result?),
+        signal = &mut shutdown => {
+            info!("Received {}, shutting down gracefully", signal);
+            bootstrap.abort();
+
+            let (_, result, _) = tokio::join!(
+                server_handle.stop(true),
+                &mut server,
+                stop(notification_handle.as_ref(), grace_period),
+            );
+            return Ok(result?);
+        }
+        result = &mut bootstrap => result??,
+    };
+
+    resolved_token.set(token.clone());
+
+    let (worker, worker_handle) = TokenSupplyWorker::new(
+        service.clone(),
+        token,
+        source_service,
+        config.server.update_interval,
+    );
+
+    worker
+        .with_monitor(monitor)
+        .with_refresh_trigger(refresh_trigger)
+        .start(&worker_handle)
+        .await;
+
+    tokio::select! {
+        result = &mut server => {
+            tokio::join!(
+                worker_handle.shutdown(grace_period),
+                stop(notification_handle.as_ref(), grace_period),
+            );
+            result?;
+        }
+        signal = &mut shutdown => {
+            info!("Received {}, shutting down gracefully", signal);
+
+            let (_, result, _, _) = tokio::join!(
+                server_handle.stop(true),
+                &mut server,
+                worker_handle.shutdown(grace_period),
+                stop(notification_handle.as_ref(), grace_period),
+            );
+            result?;
+        }
+    }
+
+    info!("Shutdown complete");
+
+    Ok(())
+}
+
+/// Stops an optional worker, if it was started.
+async fn stop(handle: Option<&WorkerHandle>, grace_period: Duration) {
+    if let Some(handle) = handle {
+        handle.shutdown(grace_period).await;
+    }
+}
+
+/// Verifies the chain and resolves the token metadata, retrying until both
+/// succeed.
+///
+/// # Returns
+///
+/// The resolved token, or an error if the RPC serves the wrong chain or one
+/// that cannot be identified. Retrying cannot fix a misconfigured chain, so
+/// that error stops the service.
+async fn bootstrap<C, M>(
+    chain_verifier: Arc<ChainVerifier<C>>,
+    metadata_service: Arc<M>,
+    token_config: TokenConfig,
+) -> Result<Token>
+where
+    C: ChainIdProvider,
+    M: TokenMetadataService + Send + Sync + 'static,
+{
+    let mut backoff = Backoff::new(BOOTSTRAP_INITIAL_DELAY, BOOTSTRAP_MAX_DELAY);
+
+    let chain_id = loop {
+        match chain_verifier.verify().await {
+            Ok(chain_id) => break chain_id,
+            Err(e)
+                if e.is::<ChainMismatch>()
+                    || e.is::<NetworkMismatch>()
+                    || e.is::<UnknownCluster>() =>
+            {
+                return Err(e);
+            }
+            Err(e) => {
+                let delay = backoff.next_delay();
+                error!("Failed to verify chain ID, retrying in {:?}: {}", delay, e);
+                sleep(delay).await;
+            }
+        }
+    };
+
+    backoff.reset();
+
+    let token = loop {
+        let result = metadata_service
+            .resolve_token_metadata(token_config.address.clone(), &token_config.overrides)
+            .await;
+
+        match result {
+            Ok(token) => break token,
+            Err(e) => {
+                let delay = backoff.next_delay();
+                error!(
+                    "Failed to resolve token metadata, retrying in {:?}: {}",
+                    delay, e
+                );
+                sleep(delay).await;
+            }
+        }
+    };
+
+    info!("Reporting supply for {} on chain ID {}", token, chain_id);
+
+    // Configured or cached metadata skips the chain, so confirm the decimals
+    // in the background without delaying startup.
+    tokio::spawn({
+        let token = token.clone();
+
+        async move {
+            if let Err(e) = metadata_service.verify_token_decimals(&token).await {
+                warn!("Failed to verify token decimals: {}", e);
+            }
+        }
+    });
+
+    Ok(token)
+}
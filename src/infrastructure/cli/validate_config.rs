@@ -0,0 +1,30 @@
+use anyhow::Result;
+
+use crate::infrastructure::configuration::Config;
+
+/// Validates the configuration and prints the outcome.
+///
+/// No network access is performed, so this is safe to run in CI or before a
+/// deployment.
+///
+/// # Arguments
+///
+/// * `config` - The loaded application configuration.
+///
+/// # Returns
+///
+/// `Result<()>` - Success, or the validation report if it contains errors.
+pub fn run(config: Config) -> Result<()> {
+    let report = config.validate();
+
+    if report.has_errors() {
+        return Err(report.into());
+    }
+
+    match report.issues().is_empty() {
+        true => println!("Configuration is valid"),
+        false => println!("Configuration is valid with warnings\n{}", report),
+    }
+
+    Ok(())
+}
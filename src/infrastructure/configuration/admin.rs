@@ -0,0 +1,42 @@
+use serde::Deserialize;
+
+/// Configuration for the authenticated admin endpoints.
+///
+/// The admin endpoints are only served when at least one credential is
+/// configured.
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct AdminConfig {
+    /// Credentials that may call the admin endpoints.
+    #[serde(default)]
+    pub credentials: Vec<AdminCredential>,
+}
+
+impl AdminConfig {
+    /// Returns `true` if the admin endpoints should be served.
+    pub fn is_enabled(&self) -> bool {
+        !self.credentials.is_empty()
+    }
+}
+
+/// A named secret accepted by the admin endpoints.
+///
+/// The secret may be presented either as a bearer token in the
+/// `Authorization` header or in the `X-API-Key` header.
+#[derive(Deserialize, Clone)]
+pub struct AdminCredential {
+    /// Identifies who is using the credential in logs.
+    pub name: String,
+
+    /// The secret itself.
+    pub token: String,
+}
+
+impl std::fmt::Debug for AdminCredential {
+    /// Prints the credential without its secret.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AdminCredential")
+            .field("name", &self.name)
+            .field("token", &"<redacted>")
+            .finish()
+    }
+}
@@ -1,14 +1,214 @@
+use alloy::primitives::{U256, utils};
 use serde::Deserialize;
 
+use crate::domain::model::{AddressKind, ChainAddress};
+
 /// Configuration for blockchain connection.
 ///
 /// Contains settings required to connect to a blockchain node and interact with
 /// the blockchain network.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct BlockchainConfig {
+    /// The kind of chain the token lives on. Defaults to an EVM chain.
+    #[serde(default)]
+    pub provider: ProviderKind,
+
     /// URL endpoint for the blockchain's RPC service.
+    ///
+    /// For Cosmos chains this is the LCD (REST) endpoint. Not used by the
+    /// static provider.
+    #[serde(default)]
     pub rpc_url: String,
 
     /// Identifier for the blockchain network.
+    ///
+    /// Solana clusters use the IDs from the Solana token list: `101` for
+    /// mainnet-beta, `102` for testnet and `103` for devnet, or any ID for a
+    /// cluster identified by `genesis_hash`. Cosmos chains are identified by
+    /// `network` instead, and this value is only reported.
+    ///
+    /// Verified against the RPC at startup and periodically afterwards.
     pub chain_id: u64,
+
+    /// The chain ID of a Cosmos chain, such as `cosmoshub-4`.
+    ///
+    /// Required by the Cosmos provider, whose chains are identified by name,
+    /// and verified against the node in place of `chain_id`.
+    #[serde(default)]
+    pub network: Option<String>,
+
+    /// The genesis hash of a Solana cluster other than the public ones, such
+    /// as a local validator or a private cluster.
+    ///
+    /// A node with this genesis hash is reported as `chain_id`. Ignored by the
+    /// other providers.
+    #[serde(default)]
+    pub genesis_hash: Option<String>,
+
+    /// Interval in seconds after which the chain ID is verified again.
+    ///
+    /// The check runs before the next supply fetch once the interval has
+    /// elapsed. A value of `0` verifies the chain before every fetch.
+    #[serde(default = "default_chain_check_interval")]
+    pub chain_check_interval: u64,
+
+    /// Base URL of a block explorer for the chain, such as
+    /// `https://etherscan.io`.
+    ///
+    /// Used to link to the token from `/v1/token`. Defaults to a well-known
+    /// explorer for the chain ID, if there is one.
+    #[serde(default)]
+    pub explorer_url: Option<String>,
+
+    /// The supply served by the static provider. Required by that provider
+    /// and ignored by the others.
+    #[serde(default)]
+    pub static_supply: Option<StaticSupplyConfig>,
+}
+
+impl BlockchainConfig {
+    /// Returns the configured explorer URL, or the well-known explorer for the
+    /// chain ID if none is configured.
+    pub fn resolved_explorer_url(&self) -> Option<String> {
+        self.explorer_url
+            .clone()
+            .or_else(|| known_explorer_url(self.provider, self.chain_id).map(String::from))
+    }
+}
+
+fn default_chain_check_interval() -> u64 {
+    300
+}
+
+fn known_explorer_url(provider: ProviderKind, chain_id: u64) -> Option<&'static str> {
+    match (provider, chain_id) {
+        (ProviderKind::Solana, 101) => Some("https://solscan.io"),
+        (ProviderKind::Evm, 1) => Some("https://etherscan.io"),
+        (ProviderKind::Evm, 10) => Some("https://optimistic.etherscan.io"),
+        (ProviderKind::Evm, 56) => Some("https://bscscan.com"),
+        (ProviderKind::Evm, 137) => Some("https://polygonscan.com"),
+        (ProviderKind::Evm, 8453) => Some("https://basescan.org"),
+        (ProviderKind::Evm, 42161) => Some("https://arbiscan.io"),
+        (ProviderKind::Evm, 11155111) => Some("https://sepolia.etherscan.io"),
+        _ => None,
+    }
+}
+
+/// The kind of chain the supply is read from.
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProviderKind {
+    /// An EVM chain, read through Ethereum JSON-RPC.
+    #[default]
+    Evm,
+
+    /// A Solana cluster, read through Solana JSON-RPC.
+    Solana,
+
+    /// A Cosmos SDK chain, read through the bank module's LCD endpoints.
+    Cosmos,
+
+    /// No chain at all: the supply is read from `blockchain.static_supply`
+    /// and the metadata from the token overrides. For tokens that are not
+    /// deployed yet and for environments without RPC access.
+    Static,
+}
+
+impl ProviderKind {
+    /// Returns the kind of address sources have on the chain, or `None` if
+    /// the provider accepts addresses of any kind.
+    pub fn address_kind(&self) -> Option<AddressKind> {
+        match self {
+            ProviderKind::Evm => Some(AddressKind::Evm),
+            ProviderKind::Solana => Some(AddressKind::Solana),
+            ProviderKind::Cosmos => Some(AddressKind::Cosmos),
+            ProviderKind::Static => None,
+        }
+    }
+
+    /// Returns the kind of address that identifies the token on the chain, or
+    /// `None` if the provider accepts addresses of any kind.
+    ///
+    /// Cosmos tokens are identified by their denom; on other chains the token
+    /// has an address like any account.
+    pub fn token_kind(&self) -> Option<AddressKind> {
+        match self {
+            ProviderKind::Cosmos => Some(AddressKind::Denom),
+            _ => self.address_kind(),
+        }
+    }
+}
+
+/// The supply served by the static provider.
+///
+/// Amounts are in whole tokens, such as `"1500000.5"`, and are converted with
+/// the token's decimals:
+///
+/// ```yaml
+/// blockchain:
+///   provider: static
+///   chain_id: 1
+///   static_supply:
+///     total_supply: "1000000"
+///     block_number: 21000000
+///     balances:
+///       - address: "0xB1a932A665FB0A1D5d7979cd63e80a59EDCe31B4"
+///         balance: "250000"
+/// ```
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct StaticSupplyConfig {
+    /// The total supply, in whole tokens.
+    pub total_supply: String,
+
+    /// The balances of excluded sources. Sources without a balance hold
+    /// nothing.
+    #[serde(default)]
+    pub balances: Vec<StaticBalance>,
+
+    /// The block number reported with the supply, if any.
+    #[serde(default)]
+    pub block_number: Option<u64>,
+}
+
+/// The balance the static provider reports for an excluded source.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct StaticBalance {
+    /// The address of the excluded source.
+    pub address: ChainAddress,
+
+    /// The balance, in whole tokens.
+    pub balance: String,
+}
+
+/// Converts an amount in whole tokens to the token's smallest unit.
+///
+/// # Arguments
+///
+/// * `amount` - The amount, such as `"1500000.5"`.
+/// * `decimals` - The number of decimals the token uses.
+///
+/// # Returns
+///
+/// The amount in the token's smallest unit, or a description of why it is
+/// invalid.
+pub fn parse_whole_tokens(amount: &str, decimals: u8) -> Result<U256, String> {
+    let trimmed = amount.trim();
+    let (whole, fraction) = trimmed.split_once('.').unwrap_or((trimmed, ""));
+
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+
+    if whole.is_empty() || !is_digits(whole) || !is_digits(fraction) {
+        return Err(format!("`{}` is not a non-negative decimal number", amount));
+    }
+
+    if fraction.len() > decimals as usize {
+        return Err(format!(
+            "`{}` has more than the token's {} decimals",
+            amount, decimals
+        ));
+    }
+
+    utils::parse_units(trimmed, decimals)
+        .map(Into::into)
+        .map_err(|e| format!("`{}` is not a valid amount: {}", amount, e))
 }
@@ -0,0 +1,46 @@
+use serde::Deserialize;
+
+/// Configuration for cross-origin requests to the versioned API.
+///
+/// CORS is only enabled when at least one origin is allowed, and never applies
+/// to the admin endpoints.
+#[derive(Deserialize, Debug, Clone)]
+pub struct CorsConfig {
+    /// Origins allowed to call the API from a browser, such as
+    /// `https://example.com`, or `*` for any origin.
+    #[serde(default)]
+    pub allowed_origins: Vec<String>,
+
+    /// Methods allowed in cross-origin requests, or `*` for any method.
+    #[serde(default = "default_allowed_methods")]
+    pub allowed_methods: Vec<String>,
+
+    /// Request headers allowed in cross-origin requests, or `*` for any header.
+    #[serde(default)]
+    pub allowed_headers: Vec<String>,
+
+    /// Seconds browsers may cache a preflight response.
+    pub max_age: Option<usize>,
+}
+
+impl CorsConfig {
+    /// Returns `true` if cross-origin requests should be allowed.
+    pub fn is_enabled(&self) -> bool {
+        !self.allowed_origins.is_empty()
+    }
+}
+
+impl Default for CorsConfig {
+    fn default() -> Self {
+        Self {
+            allowed_origins: Vec::new(),
+            allowed_methods: default_allowed_methods(),
+            allowed_headers: Vec::new(),
+            max_age: None,
+        }
+    }
+}
+
+fn default_allowed_methods() -> Vec<String> {
+    vec!["GET".into()]
+}
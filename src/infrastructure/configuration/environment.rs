@@ -1,4 +1,5 @@
 /// The possible runtime environment for the application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Environment {
     /// Local development environment.
     Local,
@@ -0,0 +1,47 @@
+use serde::Deserialize;
+
+/// Configuration for the sanity checks applied to every fetched supply.
+///
+/// Excluded balances larger than the total, a circulating supply larger than
+/// the total and a zero total are always rejected. A rejected supply is not
+/// published, and the previous snapshot keeps being served.
+#[derive(Deserialize, Debug, Clone)]
+pub struct GuardConfig {
+    /// The largest change, in percent, allowed in the total or circulating
+    /// supply between consecutive snapshots.
+    ///
+    /// Larger moves are rejected until they are confirmed, the limit is
+    /// raised or the stored snapshot is replaced. Leave unset to allow any
+    /// change.
+    #[serde(default)]
+    pub max_change_percent: Option<f64>,
+
+    /// The number of consecutive rejected moves, agreeing with each other
+    /// within `max_change_percent`, after which the next agreeing supply is
+    /// accepted as the new baseline. `0` keeps rejecting them.
+    #[serde(default = "default_confirmations")]
+    pub confirmations: u32,
+
+    /// Whether to reject a zero circulating supply. Disable it for tokens
+    /// that are fully locked, not yet launched or read from a static supply.
+    #[serde(default = "default_reject_zero_circulating")]
+    pub reject_zero_circulating: bool,
+}
+
+impl Default for GuardConfig {
+    fn default() -> Self {
+        Self {
+            max_change_percent: None,
+            confirmations: default_confirmations(),
+            reject_zero_circulating: default_reject_zero_circulating(),
+        }
+    }
+}
+
+fn default_confirmations() -> u32 {
+    3
+}
+
+fn default_reject_zero_circulating() -> bool {
+    true
+}
@@ -1,10 +1,17 @@
-use alloy::primitives::Address;
+use std::path::Path;
+
 use serde::Deserialize;
 use tracing::info;
 
-use crate::domain::model::Source;
+use crate::domain::model::{Source, SupplyAdjustment};
 
-use super::{blockchain::BlockchainConfig, environment::Environment, server::ServerConfig};
+use super::blockchain::BlockchainConfig;
+use super::environment::Environment;
+use super::guard::GuardConfig;
+use super::notification::NotificationConfig;
+use super::persistence::PersistenceConfig;
+use super::server::ServerConfig;
+use super::token::TokenConfig;
 
 /// Root configuration for the application.
 ///
@@ -16,7 +23,7 @@ pub struct Config {
     pub application_name: String,
 
     /// The token for which supply information is retrieved.
-    pub token: Address,
+    pub token: TokenConfig,
 
     /// Server-related configuration settings.
     pub server: ServerConfig,
@@ -26,6 +33,23 @@ pub struct Config {
 
     /// The sources to exclude from circulating supply.
     pub excluded_sources: Vec<Source>,
+
+    /// Manual changes to the circulating supply, applied after the excluded
+    /// sources are subtracted.
+    #[serde(default)]
+    pub adjustments: Vec<SupplyAdjustment>,
+
+    /// On-disk persistence settings.
+    #[serde(default)]
+    pub persistence: PersistenceConfig,
+
+    /// Sanity checks applied before a fetched supply is published.
+    #[serde(default)]
+    pub guards: GuardConfig,
+
+    /// Webhooks notified about supply changes, failures and staleness.
+    #[serde(default)]
+    pub notifications: NotificationConfig,
 }
 
 impl Config {
@@ -41,22 +65,56 @@ impl Config {
     ///
     /// The loaded configuration or an error.
     pub fn load() -> Result<Config, config::ConfigError> {
-        let base_path = std::env::current_dir().expect("Failed to determine the current directory");
-        let config_dir = base_path.join("configuration");
+        Self::load_with(None, None)
+    }
+
+    /// Loads application configuration, optionally overriding the file or the
+    /// environment it is selected by.
+    ///
+    /// When `path` is given it is loaded instead of
+    /// `configuration/[environment].yaml`. When `environment` is given it takes
+    /// precedence over `APP_ENVIRONMENT`. Environment variables with prefix
+    /// `APP_` are always applied last.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - An explicit configuration file to load.
+    /// * `environment` - The environment whose configuration file to load.
+    ///
+    /// # Returns
+    ///
+    /// The loaded configuration or an error.
+    pub fn load_with(
+        path: Option<&Path>,
+        environment: Option<Environment>,
+    ) -> Result<Config, config::ConfigError> {
+        let config_file = match path {
+            Some(path) => {
+                info!("Building configuration from {}", path.display());
+                path.to_path_buf()
+            }
+            None => {
+                let base_path =
+                    std::env::current_dir().expect("Failed to determine the current directory");
+                let config_dir = base_path.join("configuration");
 
-        // Determine environment from APP_ENVIRONMENT or default to "local".
-        let environment: Environment = std::env::var("APP_ENVIRONMENT")
-            .unwrap_or_else(|_| "local".into())
-            .try_into()
-            .expect("Failed to parse APP_ENVIRONMENT.");
+                // Determine environment from APP_ENVIRONMENT or default to "local".
+                let environment = environment.unwrap_or_else(|| {
+                    std::env::var("APP_ENVIRONMENT")
+                        .unwrap_or_else(|_| "local".into())
+                        .try_into()
+                        .expect("Failed to parse APP_ENVIRONMENT.")
+                });
 
-        info!("Building configuration for {}", environment.as_str());
+                info!("Building configuration for {}", environment.as_str());
 
-        let config_filename = format!("{}.yaml", environment.as_str());
+                config_dir.join(format!("{}.yaml", environment.as_str()))
+            }
+        };
 
         // Build configuration from multiple sources.
         let settings = config::Config::builder()
-            .add_source(config::File::from(config_dir.join(config_filename)))
+            .add_source(config::File::from(config_file))
             .add_source(
                 config::Environment::with_prefix("APP")
                     .prefix_separator("_")
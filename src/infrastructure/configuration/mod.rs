@@ -3,9 +3,19 @@
 //! This module manages the application's configuration settings from various
 //! sources.
 
+pub mod admin;
 pub mod blockchain;
+pub mod cors;
 pub mod environment;
+pub mod guard;
 pub mod load;
+pub mod notification;
+pub mod persistence;
+pub mod rate_limit;
 pub mod server;
+pub mod tls;
+pub mod token;
+pub mod validation;
 
 pub use load::Config;
+pub use validation::{Severity, ValidationIssue, ValidationReport};
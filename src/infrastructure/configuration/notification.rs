@@ -0,0 +1,107 @@
+use serde::Deserialize;
+
+use crate::domain::model::SupplyEventKind;
+
+/// Configuration for notifying operators about supply events.
+///
+/// Notifications are only sent when at least one webhook is configured.
+#[derive(Deserialize, Debug, Clone)]
+pub struct NotificationConfig {
+    /// The change, in percent, in the total or circulating supply between
+    /// consecutive snapshots that raises a `supply_changed` event.
+    ///
+    /// Leave unset to never raise the event.
+    #[serde(default)]
+    pub change_percent: Option<f64>,
+
+    /// The number of consecutive failed refreshes that raises a
+    /// `fetch_failing` event.
+    #[serde(default = "default_failure_threshold")]
+    pub failure_threshold: u32,
+
+    /// The number of times delivery to a webhook is attempted before giving
+    /// up.
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: u32,
+
+    /// The webhooks events are delivered to.
+    #[serde(default)]
+    pub webhooks: Vec<WebhookConfig>,
+}
+
+impl NotificationConfig {
+    /// Returns `true` if notifications should be sent.
+    pub fn is_enabled(&self) -> bool {
+        !self.webhooks.is_empty()
+    }
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        Self {
+            change_percent: None,
+            failure_threshold: default_failure_threshold(),
+            max_attempts: default_max_attempts(),
+            webhooks: Vec::new(),
+        }
+    }
+}
+
+fn default_failure_threshold() -> u32 {
+    3
+}
+
+fn default_max_attempts() -> u32 {
+    3
+}
+
+/// The shape of the body posted to a webhook.
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookFormat {
+    /// The event as a JSON object, for services that process it further.
+    #[default]
+    Json,
+
+    /// A Slack incoming webhook message.
+    Slack,
+
+    /// A Discord webhook message.
+    Discord,
+}
+
+/// An HTTP endpoint events are posted to.
+#[derive(Deserialize, Clone)]
+pub struct WebhookConfig {
+    /// The URL events are posted to.
+    pub url: String,
+
+    /// The shape of the posted body.
+    #[serde(default)]
+    pub format: WebhookFormat,
+
+    /// A shared secret used to sign each body, so the receiver can verify
+    /// it was sent by this service.
+    #[serde(default)]
+    pub secret: Option<String>,
+
+    /// The events to deliver. Defaults to every event.
+    #[serde(default = "default_events")]
+    pub events: Vec<SupplyEventKind>,
+}
+
+fn default_events() -> Vec<SupplyEventKind> {
+    SupplyEventKind::ALL.to_vec()
+}
+
+impl std::fmt::Debug for WebhookConfig {
+    /// Prints the webhook without its secret.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookConfig")
+            .field("url", &self.url)
+            .field("format", &self.format)
+            .field("secret", &self.secret.as_ref().map(|_| "<redacted>"))
+            .field("events", &self.events)
+            .finish()
+    }
+}
@@ -0,0 +1,45 @@
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+/// Configuration for on-disk persistence.
+///
+/// Contains settings for where the service keeps state that should survive a
+/// restart.
+#[derive(Deserialize, Debug, Clone)]
+pub struct PersistenceConfig {
+    /// Directory in which state files are stored. Created if missing.
+    pub directory: PathBuf,
+}
+
+impl PersistenceConfig {
+    /// Returns the path of the token metadata cache file.
+    pub fn token_metadata_path(&self) -> PathBuf {
+        self.directory.join("token_metadata.json")
+    }
+
+    /// Returns the path of the file holding excluded sources changed at
+    /// runtime.
+    pub fn excluded_sources_path(&self) -> PathBuf {
+        self.directory.join("excluded_sources.json")
+    }
+
+    /// Returns the path of the audit log of excluded source changes.
+    pub fn source_audit_path(&self) -> PathBuf {
+        self.directory.join("source_audit.jsonl")
+    }
+
+    /// Returns the path of the token supply snapshot file.
+    pub fn token_supply_path(&self) -> PathBuf {
+        self.directory.join("token_supply.json")
+    }
+}
+
+impl Default for PersistenceConfig {
+    /// Stores state in `data` relative to the working directory.
+    fn default() -> Self {
+        Self {
+            directory: PathBuf::from("data"),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::net::IpAddr;
+
+use ipnet::IpNet;
+use serde::Deserialize;
+
+/// Configuration for per-client rate limiting.
+///
+/// Each route group has its own limit. A group without a limit is not rate
+/// limited. The health and metrics endpoints are never rate limited, so that
+/// probes and scrapers are not affected.
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct RateLimitConfig {
+    /// Proxies whose `X-Forwarded-For` header is trusted to identify the
+    /// client, as addresses or CIDR ranges.
+    #[serde(default)]
+    pub trusted_proxies: Vec<TrustedProxy>,
+
+    /// The limit for the supply endpoints.
+    pub public: Option<RateLimit>,
+
+    /// The limit for the admin endpoints.
+    pub admin: Option<RateLimit>,
+}
+
+/// A token-bucket limit applied to each client.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    /// The sustained number of requests a client may make per minute.
+    pub requests_per_minute: u32,
+
+    /// The number of requests a client may make in a burst before being
+    /// limited to the sustained rate.
+    pub burst: u32,
+}
+
+/// An address or CIDR range of a trusted reverse proxy.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(try_from = "String")]
+pub struct TrustedProxy(IpNet);
+
+impl TrustedProxy {
+    /// Returns `true` if the address belongs to this proxy.
+    pub fn contains(&self, address: &IpAddr) -> bool {
+        self.0.contains(address)
+    }
+}
+
+impl TryFrom<String> for TrustedProxy {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let value = value.trim();
+
+        value
+            .parse::<IpNet>()
+            .or_else(|_| value.parse::<IpAddr>().map(IpNet::from))
+            .map(TrustedProxy)
+            .map_err(|_| format!("`{}` is not an IP address or CIDR range", value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trusted_proxy_accepts_addresses_and_ranges() {
+        // Act.
+        let single = TrustedProxy::try_from("10.0.0.1".to_string()).unwrap();
+        let range = TrustedProxy::try_from("10.0.0.0/8".to_string()).unwrap();
+        let invalid = TrustedProxy::try_from("10.0.0.0/33".to_string());
+
+        // Assert.
+        assert!(single.contains(&"10.0.0.1".parse().unwrap()));
+        assert!(!single.contains(&"10.0.0.2".parse().unwrap()));
+        assert!(range.contains(&"10.200.3.4".parse().unwrap()));
+        assert!(invalid.is_err());
+    }
+}
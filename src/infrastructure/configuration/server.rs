@@ -1,9 +1,14 @@
 use serde::Deserialize;
 
+use super::admin::AdminConfig;
+use super::cors::CorsConfig;
+use super::rate_limit::RateLimitConfig;
+use super::tls::TlsConfig;
+
 /// Configuration for the HTTP server.
 ///
 /// Contains settings related to the API server's network configuration and behavior.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct ServerConfig {
     /// Hostname or IP address the server will bind to.
     pub host: String,
@@ -16,4 +21,36 @@ pub struct ServerConfig {
     /// Controls how frequently the background worker will fetch new supply
     /// data from the blockchain.
     pub update_interval: u64,
+
+    /// Seconds to wait for in-flight requests and the background worker to
+    /// finish when shutting down.
+    ///
+    /// Anything still running after this timeout is aborted.
+    #[serde(default = "default_shutdown_timeout")]
+    pub shutdown_timeout: u64,
+
+    /// Credentials for the admin endpoints.
+    #[serde(default)]
+    pub admin: AdminConfig,
+
+    /// Per-client rate limits.
+    #[serde(default)]
+    pub rate_limit: RateLimitConfig,
+
+    /// Serves an interactive API reference at `/docs`.
+    ///
+    /// The OpenAPI document at `/openapi.json` is always served.
+    #[serde(default)]
+    pub api_docs: bool,
+
+    /// Cross-origin access to the versioned API.
+    #[serde(default)]
+    pub cors: CorsConfig,
+
+    /// Serves HTTPS instead of plain HTTP when set.
+    pub tls: Option<TlsConfig>,
+}
+
+fn default_shutdown_timeout() -> u64 {
+    30
 }
@@ -0,0 +1,31 @@
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+/// Configuration for serving HTTPS directly, without a reverse proxy.
+///
+/// The certificate and key are re-read when either file changes, so rotated
+/// certificates are picked up without a restart.
+#[derive(Deserialize, Debug, Clone)]
+pub struct TlsConfig {
+    /// PEM file holding the certificate chain, leaf first.
+    pub cert_path: PathBuf,
+
+    /// PEM file holding the private key.
+    pub key_path: PathBuf,
+
+    /// PEM file holding the CAs trusted to issue client certificates.
+    ///
+    /// When set, the admin endpoints require a client certificate issued by
+    /// one of these CAs, in addition to an admin credential. The other
+    /// endpoints do not ask for one.
+    pub client_ca_path: Option<PathBuf>,
+
+    /// Interval in seconds between checks for rotated certificate files.
+    #[serde(default = "default_reload_interval")]
+    pub reload_interval: u64,
+}
+
+fn default_reload_interval() -> u64 {
+    60
+}
@@ -0,0 +1,118 @@
+use serde::Deserialize;
+
+use crate::domain::model::{ChainAddress, TokenMetadataOverrides};
+
+/// Configuration for the token being reported on.
+///
+/// May be given as a bare address, or as a map with the address and optional
+/// metadata overrides:
+///
+/// ```yaml
+/// token:
+///   address: "0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118"
+///   name: "Supply"
+///   symbol: "SUPPLY"
+///   decimals: 18
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+#[serde(from = "TokenConfigRepr")]
+pub struct TokenConfig {
+    /// The address of the token contract.
+    pub address: ChainAddress,
+
+    /// Metadata values that take precedence over fetched ones. When all of
+    /// them are set, no metadata lookup is performed.
+    pub overrides: TokenMetadataOverrides,
+}
+
+impl TokenConfig {
+    /// Creates a new [`TokenConfig`] without overrides.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the token contract.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`TokenConfig`] instance.
+    pub fn new(address: ChainAddress) -> Self {
+        Self {
+            address,
+            overrides: TokenMetadataOverrides::default(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TokenConfigRepr {
+    Address(ChainAddress),
+    Detailed {
+        address: ChainAddress,
+        #[serde(flatten)]
+        overrides: TokenMetadataOverrides,
+    },
+}
+
+impl From<TokenConfigRepr> for TokenConfig {
+    fn from(repr: TokenConfigRepr) -> Self {
+        match repr {
+            TokenConfigRepr::Address(address) => TokenConfig::new(address),
+            TokenConfigRepr::Detailed { address, overrides } => TokenConfig { address, overrides },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct Wrapper {
+        token: TokenConfig,
+    }
+
+    fn parse(yaml: &str) -> TokenConfig {
+        config::Config::builder()
+            .add_source(config::File::from_str(yaml, config::FileFormat::Yaml))
+            .build()
+            .and_then(|c| c.try_deserialize::<Wrapper>())
+            .expect("Failed to parse token config")
+            .token
+    }
+
+    const TOKEN: ChainAddress =
+        ChainAddress::Evm(address!("0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118"));
+
+    #[test]
+    fn parses_bare_address() {
+        // Act.
+        let token = parse(r#"token: "0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118""#);
+
+        // Assert.
+        assert_eq!(token.address, TOKEN);
+        assert_eq!(token.overrides, TokenMetadataOverrides::default());
+    }
+
+    #[test]
+    fn parses_address_with_overrides() {
+        // Act.
+        let token = parse(
+            r#"
+token:
+  address: "0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118"
+  symbol: "SUPPLY"
+  decimals: 18
+"#,
+        );
+
+        // Assert.
+        assert_eq!(token.address, TOKEN);
+        assert_eq!(token.overrides.name, None);
+        assert_eq!(token.overrides.symbol.as_deref(), Some("SUPPLY"));
+        assert_eq!(token.overrides.decimals, Some(18));
+    }
+}
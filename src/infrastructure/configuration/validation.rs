@@ -0,0 +1,1226 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use actix_web::http::Method;
+use actix_web::http::header::HeaderName;
+use alloy::transports::http::reqwest::Url;
+use chrono::Utc;
+
+use crate::domain::model::ChainAddress;
+
+use super::admin::AdminConfig;
+use super::blockchain::{BlockchainConfig, ProviderKind, parse_whole_tokens};
+use super::cors::CorsConfig;
+use super::guard::GuardConfig;
+use super::load::Config;
+use super::notification::{NotificationConfig, WebhookFormat};
+use super::rate_limit::RateLimit;
+use super::server::ServerConfig;
+use super::tls::TlsConfig;
+use super::token::TokenConfig;
+
+/// Update intervals below this many seconds are legal, but are likely to
+/// exhaust public RPC rate limits.
+const MIN_RECOMMENDED_UPDATE_INTERVAL: u64 = 10;
+
+/// Admin tokens shorter than this are too easy to guess.
+const MIN_ADMIN_TOKEN_LENGTH: usize = 16;
+
+/// The largest number of decimals a 256-bit amount can be formatted with.
+const MAX_DECIMALS: u8 = 77;
+
+/// The severity of a [`ValidationIssue`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// A legal but suspicious setting. Startup continues.
+    Warning,
+
+    /// An invalid setting. Startup must not continue.
+    Error,
+}
+
+impl Severity {
+    /// Converts the severity to a string representation.
+    ///
+    /// # Returns
+    ///
+    /// A static string identifier for the severity.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
+}
+
+/// A single problem found while validating the configuration.
+#[derive(Debug, Clone)]
+pub struct ValidationIssue {
+    /// How serious the problem is.
+    pub severity: Severity,
+
+    /// The configuration path of the offending value, such as
+    /// `excluded_sources[1].address`.
+    pub path: String,
+
+    /// A human-readable explanation of the problem.
+    pub message: String,
+}
+
+impl Display for ValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// The collected outcome of validating a [`Config`].
+///
+/// Validation never stops at the first problem, so a single report describes
+/// everything that needs fixing.
+#[derive(Debug, Default)]
+pub struct ValidationReport {
+    issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Records an error at the given configuration path.
+    pub fn error<P: Into<String>, M: Into<String>>(&mut self, path: P, message: M) {
+        self.push(Severity::Error, path, message);
+    }
+
+    /// Records a warning at the given configuration path.
+    pub fn warning<P: Into<String>, M: Into<String>>(&mut self, path: P, message: M) {
+        self.push(Severity::Warning, path, message);
+    }
+
+    /// Returns every recorded issue, in the order it was found.
+    pub fn issues(&self) -> &[ValidationIssue] {
+        &self.issues
+    }
+
+    /// Returns the recorded errors.
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.with_severity(Severity::Error)
+    }
+
+    /// Returns the recorded warnings.
+    pub fn warnings(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.with_severity(Severity::Warning)
+    }
+
+    /// Returns `true` if at least one error was recorded.
+    pub fn has_errors(&self) -> bool {
+        self.errors().next().is_some()
+    }
+
+    fn push<P: Into<String>, M: Into<String>>(&mut self, severity: Severity, path: P, message: M) {
+        self.issues.push(ValidationIssue {
+            severity,
+            path: path.into(),
+            message: message.into(),
+        });
+    }
+
+    fn with_severity(&self, severity: Severity) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues.iter().filter(move |i| i.severity == severity)
+    }
+}
+
+impl Display for ValidationReport {
+    /// Returns one line per issue, prefixed with its severity.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let errors = self.errors().count();
+        let warnings = self.issues.len() - errors;
+
+        write!(
+            f,
+            "configuration has {} error(s) and {} warning(s)",
+            errors, warnings
+        )?;
+
+        for issue in &self.issues {
+            write!(f, "\n  {}: {}", issue.severity.as_str(), issue)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationReport {}
+
+impl Config {
+    /// Validates the semantic correctness of the configuration.
+    ///
+    /// Deserialization only guarantees that values have the right shape. This
+    /// pass checks that they also make sense together, for example that no
+    /// excluded source is listed twice.
+    ///
+    /// # Returns
+    ///
+    /// A [`ValidationReport`] holding every problem found.
+    pub fn validate(&self) -> ValidationReport {
+        let mut report = ValidationReport::default();
+
+        if self.application_name.trim().is_empty() {
+            report.error("application_name", "must not be empty");
+        }
+
+        validate_token(&self.token, self.blockchain.provider, &mut report);
+        validate_server(&self.server, &mut report);
+        validate_blockchain(&self.blockchain, &mut report);
+        validate_excluded_sources(self, &mut report);
+        validate_static_supply(self, &mut report);
+        validate_adjustments(self, &mut report);
+        validate_guards(&self.guards, &mut report);
+        validate_notifications(&self.notifications, &mut report);
+
+        if self.persistence.directory.as_os_str().is_empty() {
+            report.error("persistence.directory", "must not be empty");
+        }
+
+        report
+    }
+}
+
+fn validate_token(token: &TokenConfig, provider: ProviderKind, report: &mut ValidationReport) {
+    if token.address.is_zero() {
+        report.error("token", "must not be the zero address");
+    }
+
+    if let Some(expected) = provider.token_kind()
+        && token.address.kind() != expected
+    {
+        report.error(
+            "token",
+            format!(
+                "{} is a {} address, but blockchain.provider reads {} addresses",
+                token.address,
+                token.address.kind(),
+                expected
+            ),
+        );
+    }
+
+    let overrides = &token.overrides;
+
+    if provider == ProviderKind::Static && overrides.to_token(token.address.clone()).is_none() {
+        report.error(
+            "token",
+            "the static provider requires token.name, token.symbol and token.decimals",
+        );
+    }
+
+    if provider == ProviderKind::Solana && (overrides.name.is_none() || overrides.symbol.is_none())
+    {
+        report.warning(
+            "token",
+            "most SPL mints have no on-chain name or symbol; set token.name and token.symbol",
+        );
+    }
+
+    if overrides.name.as_ref().is_some_and(|n| n.trim().is_empty()) {
+        report.error("token.name", "must not be empty when set");
+    }
+
+    if overrides
+        .symbol
+        .as_ref()
+        .is_some_and(|s| s.trim().is_empty())
+    {
+        report.error("token.symbol", "must not be empty when set");
+    }
+
+    if let Some(decimals) = overrides.decimals.filter(|d| *d > MAX_DECIMALS) {
+        report.error(
+            "token.decimals",
+            format!("{} exceeds the maximum of {}", decimals, MAX_DECIMALS),
+        );
+    }
+}
+
+fn validate_server(server: &ServerConfig, report: &mut ValidationReport) {
+    if server.host.trim().is_empty() {
+        report.error("server.host", "must not be empty");
+    }
+
+    if server.port == 0 {
+        report.warning(
+            "server.port",
+            "0 binds a random free port, which clients will not be able to find",
+        );
+    }
+
+    match server.update_interval {
+        0 => report.error(
+            "server.update_interval",
+            "must be greater than zero seconds",
+        ),
+        i if i < MIN_RECOMMENDED_UPDATE_INTERVAL => report.warning(
+            "server.update_interval",
+            format!(
+                "{} seconds is very short and may exceed RPC rate limits; consider at least {}",
+                i, MIN_RECOMMENDED_UPDATE_INTERVAL
+            ),
+        ),
+        _ => {}
+    }
+
+    validate_admin(&server.admin, report);
+
+    let limits = &server.rate_limit;
+    validate_rate_limit("server.rate_limit.public", limits.public, report);
+    validate_rate_limit("server.rate_limit.admin", limits.admin, report);
+
+    if let Some(tls) = &server.tls {
+        validate_tls(tls, &server.admin, report);
+    }
+
+    validate_cors(&server.cors, report);
+}
+
+fn validate_cors(cors: &CorsConfig, report: &mut ValidationReport) {
+    if !cors.is_enabled() {
+        return;
+    }
+
+    for (i, origin) in cors.allowed_origins.iter().enumerate() {
+        if origin == "*" {
+            if cors.allowed_origins.len() > 1 {
+                report.warning(
+                    format!("server.cors.allowed_origins[{}]", i),
+                    "`*` allows every origin, so the other origins have no effect",
+                );
+            }
+            continue;
+        }
+
+        let is_origin = Url::parse(origin)
+            .is_ok_and(|url| url.has_host() && url.origin().ascii_serialization() == *origin);
+
+        if !is_origin {
+            report.error(
+                format!("server.cors.allowed_origins[{}]", i),
+                format!(
+                    "`{}` is not an origin; use a scheme and host without a path, such as https://example.com",
+                    origin
+                ),
+            );
+        }
+    }
+
+    if cors.allowed_methods.is_empty() {
+        report.error(
+            "server.cors.allowed_methods",
+            "must list at least one method, or every preflight request fails",
+        );
+    }
+
+    for (i, method) in cors.allowed_methods.iter().enumerate() {
+        if method != "*" && Method::from_bytes(method.as_bytes()).is_err() {
+            report.error(
+                format!("server.cors.allowed_methods[{}]", i),
+                format!("`{}` is not an HTTP method", method),
+            );
+        }
+    }
+
+    for (i, header) in cors.allowed_headers.iter().enumerate() {
+        if header != "*" && HeaderName::from_bytes(header.as_bytes()).is_err() {
+            report.error(
+                format!("server.cors.allowed_headers[{}]", i),
+                format!("`{}` is not a header name", header),
+            );
+        }
+    }
+}
+
+fn validate_tls(tls: &TlsConfig, admin: &AdminConfig, report: &mut ValidationReport) {
+    if tls.reload_interval == 0 {
+        report.error(
+            "server.tls.reload_interval",
+            "must be greater than zero seconds",
+        );
+    }
+
+    if tls.client_ca_path.is_some() && !admin.is_enabled() {
+        report.warning(
+            "server.tls.client_ca_path",
+            "has no effect because no admin credentials are configured",
+        );
+    }
+}
+
+fn validate_rate_limit(path: &str, limit: Option<RateLimit>, report: &mut ValidationReport) {
+    let Some(limit) = limit else {
+        return;
+    };
+
+    if limit.requests_per_minute == 0 {
+        report.error(
+            format!("{}.requests_per_minute", path),
+            "must be greater than zero; remove the limit to disable it",
+        );
+    }
+
+    if limit.burst == 0 {
+        report.error(
+            format!("{}.burst", path),
+            "must be greater than zero, or every request would be rejected",
+        );
+    }
+}
+
+fn validate_admin(admin: &AdminConfig, report: &mut ValidationReport) {
+    let mut tokens: HashMap<&str, usize> = HashMap::new();
+    let mut names: HashMap<&str, usize> = HashMap::new();
+
+    for (i, credential) in admin.credentials.iter().enumerate() {
+        let path = format!("server.admin.credentials[{}]", i);
+        let name = credential.name.trim();
+
+        if name.is_empty() {
+            report.error(format!("{}.name", path), "must not be empty");
+        } else if let Some(first) = names.get(name) {
+            report.warning(
+                format!("{}.name", path),
+                format!(
+                    "`{}` is also used by server.admin.credentials[{}], so their actions cannot be told apart",
+                    name, first
+                ),
+            );
+        } else {
+            names.insert(name, i);
+        }
+
+        if credential.token.len() < MIN_ADMIN_TOKEN_LENGTH {
+            report.error(
+                format!("{}.token", path),
+                format!("must be at least {} characters", MIN_ADMIN_TOKEN_LENGTH),
+            );
+        } else if let Some(first) = tokens.get(credential.token.as_str()) {
+            report.error(
+                format!("{}.token", path),
+                format!("is the same as server.admin.credentials[{}].token", first),
+            );
+        } else {
+            tokens.insert(&credential.token, i);
+        }
+    }
+}
+
+fn validate_blockchain(blockchain: &BlockchainConfig, report: &mut ValidationReport) {
+    if blockchain.chain_id == 0 {
+        report.error("blockchain.chain_id", "must not be zero");
+    }
+
+    match &blockchain.genesis_hash {
+        Some(_) if blockchain.provider != ProviderKind::Solana => report.warning(
+            "blockchain.genesis_hash",
+            "is only used by the Solana provider",
+        ),
+        Some(hash) if hash.trim().is_empty() => {
+            report.error("blockchain.genesis_hash", "must not be empty when set")
+        }
+        Some(_) => {}
+        None if blockchain.provider == ProviderKind::Solana
+            && !(101..=103).contains(&blockchain.chain_id) =>
+        {
+            report.error(
+                "blockchain.chain_id",
+                "Solana clusters are identified as 101 (mainnet-beta), 102 (testnet) or 103 \
+                 (devnet); set blockchain.genesis_hash to use another cluster",
+            )
+        }
+        None => {}
+    }
+
+    if blockchain.provider == ProviderKind::Cosmos
+        && blockchain
+            .network
+            .as_ref()
+            .is_none_or(|n| n.trim().is_empty())
+    {
+        report.error(
+            "blockchain.network",
+            "is required by the Cosmos provider, for example `cosmoshub-4`",
+        );
+    }
+
+    // The static provider reads no chain, so it needs its supply instead of
+    // an RPC URL.
+    match blockchain.provider {
+        ProviderKind::Static if blockchain.static_supply.is_none() => report.error(
+            "blockchain.static_supply",
+            "is required by the static provider",
+        ),
+        ProviderKind::Static => {}
+        _ if blockchain.rpc_url.trim().is_empty() => {
+            report.error("blockchain.rpc_url", "must not be empty");
+            return;
+        }
+        _ => match blockchain.rpc_url.parse::<Url>() {
+            Ok(url) if !matches!(url.scheme(), "http" | "https") => report.error(
+                "blockchain.rpc_url",
+                format!("unsupported scheme `{}`, use http or https", url.scheme()),
+            ),
+            Ok(_) => {}
+            Err(e) => report.error("blockchain.rpc_url", format!("is not a valid URL: {}", e)),
+        },
+    }
+
+    match &blockchain.explorer_url {
+        Some(explorer_url) => match explorer_url.parse::<Url>() {
+            Ok(url) if matches!(url.scheme(), "http" | "https") && url.has_host() => {}
+            Ok(_) => report.error(
+                "blockchain.explorer_url",
+                "must be an http or https URL, such as https://etherscan.io",
+            ),
+            Err(e) => report.error(
+                "blockchain.explorer_url",
+                format!("is not a valid URL: {}", e),
+            ),
+        },
+        None if blockchain.resolved_explorer_url().is_none() => report.warning(
+            "blockchain.explorer_url",
+            format!(
+                "no explorer is known for chain ID {}, so /v1/token has no explorer link",
+                blockchain.chain_id
+            ),
+        ),
+        None => {}
+    }
+}
+
+fn validate_static_supply(config: &Config, report: &mut ValidationReport) {
+    let Some(supply) = &config.blockchain.static_supply else {
+        return;
+    };
+
+    if config.blockchain.provider != ProviderKind::Static {
+        report.warning(
+            "blockchain.static_supply",
+            "is only used by the static provider",
+        );
+    }
+
+    // Amounts are in whole tokens, so they can only be checked when the
+    // decimals are configured, which the static provider requires anyway.
+    let Some(decimals) = config.token.overrides.decimals else {
+        return;
+    };
+
+    if let Err(e) = parse_whole_tokens(&supply.total_supply, decimals) {
+        report.error("blockchain.static_supply.total_supply", e);
+    }
+
+    for (i, balance) in supply.balances.iter().enumerate() {
+        let path = format!("blockchain.static_supply.balances[{}]", i);
+
+        if let Err(e) = parse_whole_tokens(&balance.balance, decimals) {
+            report.error(format!("{}.balance", path), e);
+        }
+
+        if !config
+            .excluded_sources
+            .iter()
+            .any(|s| s.address == balance.address)
+        {
+            report.warning(
+                format!("{}.address", path),
+                format!(
+                    "{} is not an excluded source, so its balance is only used once it is added",
+                    balance.address
+                ),
+            );
+        }
+    }
+}
+
+fn validate_adjustments(config: &Config, report: &mut ValidationReport) {
+    let today = Utc::now().date_naive();
+
+    for (i, adjustment) in config.adjustments.iter().enumerate() {
+        let path = format!("adjustments[{}]", i);
+
+        if adjustment.reason.trim().is_empty() {
+            report.error(format!("{}.reason", path), "must not be empty");
+        }
+
+        // The amount can only be checked against the token's decimals when
+        // they are configured; otherwise it is checked when applied.
+        let amount = match config.token.overrides.decimals {
+            Some(decimals) => adjustment.raw_amount(decimals).map(|_| ()),
+            None if adjustment.is_well_formed() => Ok(()),
+            None => Err(format!("`{}` is not a decimal amount", adjustment.amount)),
+        };
+
+        if let Err(e) = amount {
+            report.error(format!("{}.amount", path), e);
+        }
+
+        match adjustment.expires_on {
+            Some(expires_on) if expires_on <= adjustment.effective_from => report.error(
+                format!("{}.expires_on", path),
+                "must be after effective_from",
+            ),
+            Some(expires_on) if expires_on <= today => report.warning(
+                format!("{}.expires_on", path),
+                format!(
+                    "{} has passed, so the adjustment is no longer applied",
+                    expires_on
+                ),
+            ),
+            _ => {}
+        }
+    }
+}
+
+fn validate_guards(guards: &GuardConfig, report: &mut ValidationReport) {
+    match guards.max_change_percent {
+        Some(percent) if !percent.is_finite() || percent <= 0.0 => report.error(
+            "guards.max_change_percent",
+            "must be greater than zero, or unset to allow any change",
+        ),
+        Some(percent) if percent < 1.0 => report.warning(
+            "guards.max_change_percent",
+            format!(
+                "{}% is likely to reject ordinary transfers in and out of excluded sources",
+                percent
+            ),
+        ),
+        _ => {}
+    }
+}
+
+fn validate_notifications(notifications: &NotificationConfig, report: &mut ValidationReport) {
+    if notifications
+        .change_percent
+        .is_some_and(|p| !p.is_finite() || p <= 0.0)
+    {
+        report.error(
+            "notifications.change_percent",
+            "must be greater than zero, or unset to never notify about changes",
+        );
+    }
+
+    if notifications.failure_threshold == 0 {
+        report.error("notifications.failure_threshold", "must be at least 1");
+    }
+
+    if notifications.max_attempts == 0 {
+        report.error("notifications.max_attempts", "must be at least 1");
+    }
+
+    for (i, webhook) in notifications.webhooks.iter().enumerate() {
+        let path = format!("notifications.webhooks[{}]", i);
+
+        match webhook.url.parse::<Url>() {
+            Ok(url) if matches!(url.scheme(), "http" | "https") && url.has_host() => {}
+            Ok(_) => report.error(format!("{}.url", path), "must be an http or https URL"),
+            Err(e) => report.error(
+                format!("{}.url", path),
+                format!("is not a valid URL: {}", e),
+            ),
+        }
+
+        if webhook.secret.as_ref().is_some_and(|s| s.is_empty()) {
+            report.error(format!("{}.secret", path), "must not be empty when set");
+        }
+
+        if webhook.secret.is_none() && webhook.format == WebhookFormat::Json {
+            report.warning(
+                format!("{}.secret", path),
+                "is unset, so the receiver cannot verify deliveries came from this service",
+            );
+        }
+
+        if webhook.events.is_empty() {
+            report.warning(
+                format!("{}.events", path),
+                "is empty, so nothing will be delivered",
+            );
+        }
+    }
+}
+
+fn validate_excluded_sources(config: &Config, report: &mut ValidationReport) {
+    if config.excluded_sources.is_empty() {
+        report.warning(
+            "excluded_sources",
+            "is empty, so circulating supply will always equal total supply",
+        );
+    }
+
+    let expected = config.blockchain.provider.address_kind();
+    let mut addresses: HashMap<ChainAddress, usize> = HashMap::new();
+    let mut names: HashMap<&str, usize> = HashMap::new();
+
+    for (i, source) in config.excluded_sources.iter().enumerate() {
+        let path = format!("excluded_sources[{}]", i);
+        let name = source.name.trim();
+
+        if name.is_empty() {
+            report.error(format!("{}.name", path), "must not be empty");
+        } else if let Some(first) = names.get(name) {
+            report.warning(
+                format!("{}.name", path),
+                format!("`{}` is also used by excluded_sources[{}]", name, first),
+            );
+        } else {
+            names.insert(name, i);
+        }
+
+        if let Some(first) = addresses.get(&source.address) {
+            report.error(
+                format!("{}.address", path),
+                format!(
+                    "{} is already listed at excluded_sources[{}] and would be subtracted twice",
+                    source.address, first
+                ),
+            );
+        } else {
+            addresses.insert(source.address.clone(), i);
+        }
+
+        if let Some(expected) = expected
+            && source.address.kind() != expected
+        {
+            report.error(
+                format!("{}.address", path),
+                format!(
+                    "{} is a {} address, but blockchain.provider reads {} addresses",
+                    source.address,
+                    source.address.kind(),
+                    expected
+                ),
+            );
+        }
+
+        if source.address.is_zero() {
+            report.error(
+                format!("{}.address", path),
+                "is the zero address and cannot be a source",
+            );
+        }
+
+        if source.address == config.token.address {
+            report.error(
+                format!("{}.address", path),
+                "is the token itself and cannot be a source",
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::{Address, address};
+
+    use crate::domain::model::{Source, SupplyAdjustment, SupplyEventKind, TokenMetadataOverrides};
+    use crate::infrastructure::configuration::admin::AdminCredential;
+    use crate::infrastructure::configuration::blockchain::{StaticBalance, StaticSupplyConfig};
+    use crate::infrastructure::configuration::notification::WebhookConfig;
+    use crate::infrastructure::configuration::persistence::PersistenceConfig;
+    use crate::infrastructure::configuration::rate_limit::RateLimitConfig;
+
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Test Helper Functions
+
+    const TOKEN: ChainAddress =
+        ChainAddress::Evm(address!("0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118"));
+    const SINK: ChainAddress =
+        ChainAddress::Evm(address!("0xB1a932A665FB0A1D5d7979cd63e80a59EDCe31B4"));
+
+    fn create_config() -> Config {
+        Config {
+            application_name: "coingecko_supply_test".into(),
+            token: TokenConfig::new(TOKEN),
+            server: ServerConfig {
+                host: "127.0.0.1".into(),
+                port: 3000,
+                update_interval: 1200,
+                shutdown_timeout: 30,
+                admin: AdminConfig::default(),
+                rate_limit: RateLimitConfig::default(),
+                api_docs: false,
+                cors: CorsConfig::default(),
+                tls: None,
+            },
+            blockchain: BlockchainConfig {
+                provider: ProviderKind::Evm,
+                rpc_url: "https://ethereum-sepolia-rpc.publicnode.com".into(),
+                chain_id: 11155111,
+                network: None,
+                genesis_hash: None,
+                chain_check_interval: 300,
+                explorer_url: None,
+                static_supply: None,
+            },
+            excluded_sources: vec![source("Sink", SINK)],
+            adjustments: Vec::new(),
+            persistence: PersistenceConfig::default(),
+            guards: GuardConfig::default(),
+            notifications: NotificationConfig::default(),
+        }
+    }
+
+    fn source(name: &str, address: ChainAddress) -> Source {
+        Source {
+            name: name.into(),
+            address,
+        }
+    }
+
+    fn credential(name: &str, token: &str) -> AdminCredential {
+        AdminCredential {
+            name: name.into(),
+            token: token.into(),
+        }
+    }
+
+    fn paths<'a>(issues: impl Iterator<Item = &'a ValidationIssue>) -> Vec<String> {
+        issues.map(|i| i.path.clone()).collect()
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[test]
+    fn valid_config_has_no_issues() {
+        // Arrange.
+        let config = create_config();
+
+        // Act.
+        let report = config.validate();
+
+        // Assert.
+        assert!(report.issues().is_empty(), "Unexpected issues: {}", report);
+    }
+
+    #[test]
+    fn collects_every_error_with_its_path() {
+        // Arrange.
+        let mut config = create_config();
+        config.token.address = Address::ZERO.into();
+        config.token.overrides.decimals = Some(78);
+        config.server.update_interval = 0;
+        config.blockchain.rpc_url = "".into();
+        config.excluded_sources.push(source("Sink Copy", SINK));
+
+        // Act.
+        let report = config.validate();
+
+        // Assert.
+        assert!(report.has_errors());
+        assert_eq!(
+            paths(report.errors()),
+            vec![
+                "token",
+                "token.decimals",
+                "server.update_interval",
+                "blockchain.rpc_url",
+                "excluded_sources[1].address",
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_rpc_url_with_unsupported_scheme() {
+        // Arrange.
+        let mut config = create_config();
+        config.blockchain.rpc_url = "wss://ethereum-sepolia-rpc.publicnode.com".into();
+
+        // Act.
+        let report = config.validate();
+
+        // Assert.
+        assert_eq!(paths(report.errors()), vec!["blockchain.rpc_url"]);
+    }
+
+    #[test]
+    fn suspicious_settings_are_warnings_only() {
+        // Arrange.
+        let mut config = create_config();
+        config.server.update_interval = 2;
+
+        // Act.
+        let report = config.validate();
+
+        // Assert.
+        assert!(!report.has_errors(), "Unexpected errors: {}", report);
+        assert_eq!(paths(report.warnings()), vec!["server.update_interval"]);
+    }
+
+    #[test]
+    fn rejects_the_token_and_zero_addresses_as_sources() {
+        // Arrange.
+        let mut config = create_config();
+        config.excluded_sources.push(source("Token", TOKEN));
+        config
+            .excluded_sources
+            .push(source("Burn", Address::ZERO.into()));
+
+        // Act.
+        let report = config.validate();
+
+        // Assert.
+        assert!(
+            report.warnings().next().is_none(),
+            "Unexpected warnings: {}",
+            report
+        );
+        assert_eq!(
+            paths(report.errors()),
+            vec!["excluded_sources[1].address", "excluded_sources[2].address"]
+        );
+    }
+
+    #[test]
+    fn rejects_weak_and_shared_admin_tokens() {
+        // Arrange.
+        let mut config = create_config();
+        config.server.admin.credentials = vec![
+            credential("ops", "0123456789abcdef"),
+            credential("deploy", "short"),
+            credential("ci", "0123456789abcdef"),
+        ];
+
+        // Act.
+        let report = config.validate();
+
+        // Assert.
+        assert_eq!(
+            paths(report.errors()),
+            vec![
+                "server.admin.credentials[1].token",
+                "server.admin.credentials[2].token",
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_rate_limits_that_block_every_request() {
+        // Arrange.
+        let mut config = create_config();
+        config.server.rate_limit.public = Some(RateLimit {
+            requests_per_minute: 0,
+            burst: 0,
+        });
+
+        // Act.
+        let report = config.validate();
+
+        // Assert.
+        assert_eq!(
+            paths(report.errors()),
+            vec![
+                "server.rate_limit.public.requests_per_minute",
+                "server.rate_limit.public.burst",
+            ]
+        );
+    }
+
+    #[test]
+    fn flags_tls_settings_that_cannot_work() {
+        // Arrange.
+        let mut config = create_config();
+        config.server.tls = Some(TlsConfig {
+            cert_path: "cert.pem".into(),
+            key_path: "key.pem".into(),
+            client_ca_path: Some("ca.pem".into()),
+            reload_interval: 0,
+        });
+
+        // Act.
+        let report = config.validate();
+
+        // Assert.
+        assert_eq!(paths(report.errors()), vec!["server.tls.reload_interval"]);
+        assert_eq!(paths(report.warnings()), vec!["server.tls.client_ca_path"]);
+    }
+
+    #[test]
+    fn rejects_cors_settings_browsers_cannot_match() {
+        // Arrange.
+        let mut config = create_config();
+        config.server.cors = CorsConfig {
+            allowed_origins: vec![
+                "https://example.com".into(),
+                "https://example.com/".into(),
+                "example.com".into(),
+            ],
+            allowed_methods: vec!["GET".into(), "GE T".into()],
+            allowed_headers: vec!["*".into()],
+            max_age: Some(600),
+        };
+
+        // Act.
+        let report = config.validate();
+
+        // Assert.
+        assert_eq!(
+            paths(report.errors()),
+            vec![
+                "server.cors.allowed_origins[1]",
+                "server.cors.allowed_origins[2]",
+                "server.cors.allowed_methods[1]",
+            ]
+        );
+    }
+
+    #[test]
+    fn checks_explorer_url() {
+        // Arrange.
+        let mut invalid = create_config();
+        invalid.blockchain.explorer_url = Some("etherscan.io".into());
+
+        let mut unknown = create_config();
+        unknown.blockchain.chain_id = 31337;
+
+        // Act.
+        let invalid = invalid.validate();
+        let unknown = unknown.validate();
+
+        // Assert.
+        assert_eq!(paths(invalid.errors()), vec!["blockchain.explorer_url"]);
+        assert_eq!(paths(unknown.warnings()), vec!["blockchain.explorer_url"]);
+    }
+
+    #[test]
+    fn checks_addresses_match_the_provider() {
+        // Arrange.
+        let mut config = create_config();
+        config.blockchain.provider = ProviderKind::Solana;
+        config.blockchain.chain_id = 101;
+        config.excluded_sources.push(source(
+            "Treasury",
+            "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"
+                .parse()
+                .unwrap(),
+        ));
+
+        // Act.
+        let report = config.validate();
+
+        // Assert.
+        assert_eq!(
+            paths(report.errors()),
+            vec!["token", "excluded_sources[0].address"]
+        );
+        assert_eq!(paths(report.warnings()), vec!["token"]);
+    }
+
+    #[test]
+    fn accepts_any_solana_chain_id_with_a_genesis_hash() {
+        // Arrange.
+        let solana = |genesis_hash: Option<&str>| {
+            let mut config = create_config();
+            config.blockchain.provider = ProviderKind::Solana;
+            config.blockchain.chain_id = 900;
+            config.blockchain.genesis_hash = genesis_hash.map(String::from);
+            config
+        };
+
+        // Act.
+        let public = solana(None).validate();
+        let local = solana(Some("11111111111111111111111111111111")).validate();
+
+        // Assert.
+        assert!(paths(public.errors()).contains(&"blockchain.chain_id".to_string()));
+        assert!(!paths(local.errors()).contains(&"blockchain.chain_id".to_string()));
+    }
+
+    #[test]
+    fn checks_cosmos_denom_and_network() {
+        // Arrange.
+        let cosmos = || {
+            let mut config = create_config();
+            config.blockchain.provider = ProviderKind::Cosmos;
+            config.token.address = ChainAddress::Denom("uatom".into());
+            config.excluded_sources = vec![source(
+                "Community Pool",
+                "cosmos1v3jkvemgd94xkmrddehhqutjwd682anhgerdcs"
+                    .parse()
+                    .unwrap(),
+            )];
+            config
+        };
+
+        let unnamed = cosmos();
+        let mut named = cosmos();
+        named.blockchain.network = Some("cosmoshub-4".into());
+
+        // Act.
+        let unnamed = unnamed.validate();
+        let named = named.validate();
+
+        // Assert.
+        assert_eq!(paths(unnamed.errors()), vec!["blockchain.network"]);
+        assert!(!named.has_errors(), "Unexpected errors: {}", named);
+    }
+
+    #[test]
+    fn checks_static_supply() {
+        // Arrange.
+        let unconfigured = {
+            let mut config = create_config();
+            config.blockchain.provider = ProviderKind::Static;
+            config.blockchain.rpc_url = String::new();
+            config
+        };
+
+        let mut configured = create_config();
+        configured.blockchain.provider = ProviderKind::Static;
+        configured.blockchain.rpc_url = String::new();
+        configured.token.overrides = TokenMetadataOverrides {
+            name: Some("Supply".into()),
+            symbol: Some("SUPPLY".into()),
+            decimals: Some(2),
+        };
+        configured.blockchain.static_supply = Some(StaticSupplyConfig {
+            total_supply: "1000.005".into(),
+            balances: vec![
+                StaticBalance {
+                    address: SINK,
+                    balance: "-1".into(),
+                },
+                StaticBalance {
+                    address: TOKEN,
+                    balance: "10".into(),
+                },
+            ],
+            block_number: None,
+        });
+
+        // Act.
+        let unconfigured = unconfigured.validate();
+        let configured = configured.validate();
+
+        // Assert.
+        assert_eq!(
+            paths(unconfigured.errors()),
+            vec!["token", "blockchain.static_supply"]
+        );
+        assert_eq!(
+            paths(configured.errors()),
+            vec![
+                "blockchain.static_supply.total_supply",
+                "blockchain.static_supply.balances[0].balance",
+            ]
+        );
+        assert_eq!(
+            paths(configured.warnings()),
+            vec![
+                "blockchain.explorer_url",
+                "blockchain.static_supply.balances[1].address",
+            ]
+        );
+    }
+
+    #[test]
+    fn checks_adjustments() {
+        // Arrange.
+        let adjustment =
+            |reason: &str, amount: &str, effective_from: &str, expires_on: &str| SupplyAdjustment {
+                reason: reason.into(),
+                amount: amount.into(),
+                effective_from: effective_from.parse().unwrap(),
+                expires_on: Some(expires_on.parse().unwrap()),
+            };
+
+        let mut config = create_config();
+        config.token.overrides.decimals = Some(2);
+        config.adjustments = vec![
+            adjustment("Custodian", "-1500.25", "2025-01-01", "2999-01-01"),
+            adjustment(" ", "1.005", "2025-01-01", "2999-01-01"),
+            adjustment("Reversed", "10", "2025-06-01", "2025-01-01"),
+            adjustment("Expired", "10", "2020-01-01", "2021-01-01"),
+        ];
+
+        // Act.
+        let report = config.validate();
+
+        // Assert.
+        assert_eq!(
+            paths(report.errors()),
+            vec![
+                "adjustments[1].reason",
+                "adjustments[1].amount",
+                "adjustments[2].expires_on",
+            ]
+        );
+        assert_eq!(paths(report.warnings()), vec!["adjustments[3].expires_on"]);
+    }
+
+    #[test]
+    fn checks_max_change_percent() {
+        // Arrange.
+        let mut negative = create_config();
+        negative.guards.max_change_percent = Some(-5.0);
+
+        let mut tight = create_config();
+        tight.guards.max_change_percent = Some(0.5);
+
+        // Act.
+        let negative = negative.validate();
+        let tight = tight.validate();
+
+        // Assert.
+        assert_eq!(paths(negative.errors()), vec!["guards.max_change_percent"]);
+        assert_eq!(paths(tight.warnings()), vec!["guards.max_change_percent"]);
+    }
+
+    #[test]
+    fn checks_webhooks() {
+        // Arrange.
+        let webhook = |url: &str, secret: Option<&str>| WebhookConfig {
+            url: url.into(),
+            format: WebhookFormat::Json,
+            secret: secret.map(String::from),
+            events: SupplyEventKind::ALL.to_vec(),
+        };
+
+        let mut config = create_config();
+        config.notifications.failure_threshold = 0;
+        config.notifications.webhooks = vec![
+            webhook("https://hooks.example.com/supply", Some("s3cret")),
+            webhook("ftp://hooks.example.com", Some("s3cret")),
+            webhook("https://hooks.example.com/unsigned", None),
+        ];
+
+        // Act.
+        let report = config.validate();
+
+        // Assert.
+        assert_eq!(
+            paths(report.errors()),
+            vec![
+                "notifications.failure_threshold",
+                "notifications.webhooks[1].url"
+            ]
+        );
+        assert_eq!(
+            paths(report.warnings()),
+            vec!["notifications.webhooks[2].secret"]
+        );
+    }
+
+    #[test]
+    fn report_display_lists_each_issue() {
+        // Arrange.
+        let mut config = create_config();
+        config.server.update_interval = 0;
+
+        // Act.
+        let output = config.validate().to_string();
+
+        // Assert.
+        assert_eq!(
+            output,
+            "configuration has 1 error(s) and 0 warning(s)\n  \
+             error: server.update_interval: must be greater than zero seconds"
+        );
+    }
+}
@@ -5,6 +5,9 @@
 //! This layer implements the contracts defined by the application and domain layers.
 
 pub mod adapter;
+pub mod backoff;
+pub mod cli;
 pub mod configuration;
+pub mod shutdown;
 pub mod telemetry;
 pub mod worker;
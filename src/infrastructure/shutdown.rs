@@ -0,0 +1,46 @@
+//! Shutdown Signals
+//!
+//! Listens for the signals that ask the service to stop. Container runtimes
+//! such as Docker and Kubernetes send `SIGTERM`, while an interactive terminal
+//! sends `SIGINT` on Ctrl+C.
+
+use tracing::error;
+
+/// Waits until the process receives `SIGINT` or, on Unix, `SIGTERM`.
+///
+/// # Returns
+///
+/// The name of the signal that was received.
+pub async fn shutdown_signal() -> &'static str {
+    tokio::select! {
+        _ = interrupt() => "SIGINT",
+        _ = terminate() => "SIGTERM",
+    }
+}
+
+async fn interrupt() {
+    if let Err(e) = tokio::signal::ctrl_c().await {
+        error!("Failed to listen for SIGINT: {}", e);
+        std::future::pending::<()>().await;
+    }
+}
+
+#[cfg(unix)]
+async fn terminate() {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    match signal(SignalKind::terminate()) {
+        Ok(mut sigterm) => {
+            sigterm.recv().await;
+        }
+        Err(e) => {
+            error!("Failed to listen for SIGTERM: {}", e);
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+#[cfg(not(unix))]
+async fn terminate() {
+    std::future::pending::<()>().await;
+}
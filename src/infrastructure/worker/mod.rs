@@ -3,6 +3,12 @@
 //! This module contains background worker implementations that perform scheduled
 //! or continuous operations in the applicaion.
 
+pub mod notification_worker;
+pub mod refresh_trigger;
 pub mod token_supply_worker;
+pub mod worker_monitor;
 
-pub use token_supply_worker::TokenSupplyWorker;
+pub use notification_worker::NotificationWorker;
+pub use refresh_trigger::{RefreshOutcome, RefreshTrigger};
+pub use token_supply_worker::{TokenSupplyWorker, WorkerHandle};
+pub use worker_monitor::{WorkerMonitor, WorkerState, WorkerStatus};
@@ -0,0 +1,258 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::sync::watch;
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{error, info, warn};
+
+use crate::application::port::outbound::SupplyEventNotifier;
+use crate::application::use_case::SupplyEventDetector;
+use crate::domain::model::SupplyEvent;
+
+use super::refresh_trigger::{RefreshOutcome, RefreshTrigger};
+use super::token_supply_worker::{WorkerHandle, shutdown_requested};
+
+/// Worker that notifies operators about supply events.
+///
+/// The worker observes the outcome of every refresh made by the
+/// [`TokenSupplyWorker`](super::TokenSupplyWorker), and checks the published
+/// snapshot for staleness on an interval. Events raised by the
+/// [`SupplyEventDetector`] are delivered in the background, so a slow webhook
+/// never delays the detection of the next event.
+pub struct NotificationWorker<N> {
+    notifier: Arc<N>,
+    detector: SupplyEventDetector,
+    refresh_trigger: Arc<RefreshTrigger>,
+    check_interval: Duration,
+}
+
+impl<N> NotificationWorker<N>
+where
+    N: SupplyEventNotifier + Send + Sync + 'static,
+{
+    /// Creates a new [`NotificationWorker`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `notifier` - Delivers the raised events.
+    /// * `detector` - Decides which events a refresh raises.
+    /// * `refresh_trigger` - The trigger the supply worker publishes the
+    ///   outcome of its refreshes to.
+    /// * `check_interval` - Time between staleness checks.
+    ///
+    /// # Returns
+    ///
+    /// A new [`NotificationWorker`] instance and the handle used to stop it.
+    pub fn new(
+        notifier: Arc<N>,
+        detector: SupplyEventDetector,
+        refresh_trigger: Arc<RefreshTrigger>,
+        check_interval: Duration,
+    ) -> (Self, WorkerHandle) {
+        let worker = Self {
+            notifier,
+            detector,
+            refresh_trigger,
+            check_interval,
+        };
+
+        (worker, WorkerHandle::new())
+    }
+
+    /// Starts the background worker task.
+    ///
+    /// The task runs until [`WorkerHandle::shutdown`] is called. Deliveries
+    /// still in progress at that point are abandoned.
+    pub async fn start(self, handle: &WorkerHandle) {
+        let shutdown_rx = handle.subscribe();
+
+        // Subscribe before spawning, so no refresh completed after this call
+        // is missed.
+        let mut outcomes = self.refresh_trigger.outcomes();
+        outcomes.mark_unchanged();
+
+        info!("Starting notification worker");
+
+        let task = tokio::spawn(async move {
+            self.run(outcomes, shutdown_rx).await;
+            info!("Notification worker stopped");
+        });
+
+        handle.attach(task);
+    }
+
+    /// Runs the detection loop until a shutdown is requested.
+    async fn run(
+        mut self,
+        mut outcomes: watch::Receiver<Option<RefreshOutcome>>,
+        mut shutdown_rx: watch::Receiver<bool>,
+    ) {
+        let mut stale_check = interval(self.check_interval);
+        stale_check.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            let events = tokio::select! {
+                biased;
+
+                _ = shutdown_requested(&mut shutdown_rx) => break,
+                changed = outcomes.changed() => {
+                    if changed.is_err() {
+                        break;
+                    }
+
+                    let Some(outcome) = outcomes.borrow_and_update().clone() else {
+                        continue;
+                    };
+
+                    match outcome.result {
+                        Ok(supply) => self.detector.on_success(&supply),
+                        Err(e) => self.detector.on_failure(&e).into_iter().collect(),
+                    }
+                }
+                _ = stale_check.tick() => {
+                    self.detector.check_stale(Utc::now()).into_iter().collect()
+                }
+            };
+
+            for event in events {
+                self.dispatch(event);
+            }
+        }
+    }
+
+    /// Delivers an event in the background.
+    fn dispatch(&self, event: SupplyEvent) {
+        warn!("Supply event `{}`: {}", event.kind().as_str(), event);
+
+        let notifier = self.notifier.clone();
+
+        tokio::spawn(async move {
+            if let Err(e) = notifier.notify(&event).await {
+                error!(
+                    "Failed to deliver `{}` notification: {:#}",
+                    event.kind().as_str(),
+                    e
+                );
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use anyhow::Result;
+    use chrono::TimeDelta;
+    use tokio::time::{sleep, timeout};
+
+    use crate::domain::model::TokenSupply;
+
+    use super::*;
+
+    // -------------------------------------------------------------------------
+    // Mock Implementations for Testing
+
+    #[derive(Default)]
+    struct MockNotifier {
+        events: Mutex<Vec<SupplyEvent>>,
+    }
+
+    impl MockNotifier {
+        fn events(&self) -> Vec<SupplyEvent> {
+            self.events.lock().unwrap().clone()
+        }
+    }
+
+    impl SupplyEventNotifier for MockNotifier {
+        async fn notify(&self, event: &SupplyEvent) -> Result<()> {
+            self.events.lock().unwrap().push(event.clone());
+            Ok(())
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Test Helper Functions
+
+    async fn wait_for_events(notifier: &MockNotifier, count: usize) -> Vec<SupplyEvent> {
+        timeout(Duration::from_secs(1), async {
+            loop {
+                let events = notifier.events();
+                if events.len() >= count {
+                    return events;
+                }
+                sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("Events should have been delivered")
+    }
+
+    // -------------------------------------------------------------------------
+    // Tests
+
+    #[tokio::test]
+    async fn worker_notifies_about_failures_and_recovery() {
+        // Arrange.
+        let notifier = Arc::new(MockNotifier::default());
+        let trigger = Arc::new(RefreshTrigger::new());
+        let detector = SupplyEventDetector::new(None, 2, TimeDelta::seconds(600));
+        let (worker, handle) = NotificationWorker::new(
+            notifier.clone(),
+            detector,
+            trigger.clone(),
+            Duration::from_secs(60),
+        );
+        worker.start(&handle).await;
+
+        // Act.
+        for result in [
+            Err("timeout".to_string()),
+            Err("timeout".to_string()),
+            Ok(TokenSupply::new("100", "50")),
+        ] {
+            let generation = trigger.begin();
+            trigger.complete(generation, result);
+            sleep(Duration::from_millis(20)).await;
+        }
+
+        // Assert.
+        let events = wait_for_events(&notifier, 2).await;
+        assert_eq!(
+            events,
+            vec![
+                SupplyEvent::FetchFailing {
+                    consecutive_failures: 2,
+                    error: "timeout".into(),
+                },
+                SupplyEvent::Recovered { failures: 2 },
+            ]
+        );
+        assert!(handle.shutdown(Duration::from_secs(1)).await);
+    }
+
+    #[tokio::test]
+    async fn worker_notifies_when_the_snapshot_goes_stale() {
+        // Arrange.
+        let notifier = Arc::new(MockNotifier::default());
+        let supply =
+            TokenSupply::new("100", "50").with_fetched_at(Utc::now() - TimeDelta::seconds(1200));
+        let detector =
+            SupplyEventDetector::new(None, 3, TimeDelta::seconds(600)).with_previous(supply);
+        let (worker, handle) = NotificationWorker::new(
+            notifier.clone(),
+            detector,
+            Arc::new(RefreshTrigger::new()),
+            Duration::from_millis(10),
+        );
+
+        // Act.
+        worker.start(&handle).await;
+
+        // Assert.
+        let events = wait_for_events(&notifier, 1).await;
+        assert!(matches!(events[..], [SupplyEvent::Stale { .. }]));
+        assert!(handle.shutdown(Duration::from_secs(1)).await);
+    }
+}
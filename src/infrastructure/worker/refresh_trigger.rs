@@ -0,0 +1,212 @@
+use std::sync::Mutex;
+
+use tokio::sync::{Notify, watch};
+
+use crate::domain::model::TokenSupply;
+
+/// The result of a completed refresh.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefreshOutcome {
+    /// The refresh this outcome belongs to. See [`RefreshTrigger::request`].
+    pub generation: u64,
+
+    /// The stored supply, or why fetching or storing it failed.
+    pub result: Result<TokenSupply, String>,
+}
+
+#[derive(Debug, Default)]
+struct Generations {
+    /// The most recently requested refresh.
+    requested: u64,
+
+    /// The most recently started refresh.
+    started: u64,
+}
+
+/// Wakes the supply worker to refresh ahead of its schedule.
+///
+/// Refreshes are numbered by generation. Requests made before the worker
+/// begins a refresh coalesce into it, so any number of concurrent triggers
+/// cause a single fetch. A request made while a fetch is already in progress
+/// queues one more, because the running fetch may predate whatever prompted
+/// the request.
+#[derive(Debug)]
+pub struct RefreshTrigger {
+    notify: Notify,
+    generations: Mutex<Generations>,
+    completed_tx: watch::Sender<Option<RefreshOutcome>>,
+}
+
+impl RefreshTrigger {
+    /// Creates a new [`RefreshTrigger`] instance.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`RefreshTrigger`] instance.
+    pub fn new() -> Self {
+        Self {
+            notify: Notify::new(),
+            generations: Mutex::new(Generations::default()),
+            completed_tx: watch::channel(None).0,
+        }
+    }
+
+    /// Requests a refresh and wakes the worker.
+    ///
+    /// # Returns
+    ///
+    /// The generation of the refresh that will satisfy this request, to pass
+    /// to [`RefreshTrigger::wait_for`].
+    pub fn request(&self) -> u64 {
+        let generation = {
+            let mut generations = self.generations.lock().unwrap();
+
+            if generations.requested == generations.started {
+                generations.requested += 1;
+            }
+
+            generations.requested
+        };
+
+        self.notify.notify_one();
+
+        generation
+    }
+
+    /// Waits until the given refresh, or a later one, has completed.
+    ///
+    /// # Arguments
+    ///
+    /// * `generation` - The generation returned by [`RefreshTrigger::request`].
+    ///
+    /// # Returns
+    ///
+    /// The outcome of the refresh.
+    pub async fn wait_for(&self, generation: u64) -> RefreshOutcome {
+        let mut completed_rx = self.completed_tx.subscribe();
+
+        let outcome = completed_rx
+            .wait_for(|o| o.as_ref().is_some_and(|o| o.generation >= generation))
+            .await
+            .expect("the sender lives as long as the trigger");
+
+        outcome.clone().expect("checked by the predicate")
+    }
+
+    /// Returns a receiver that observes the outcome of every refresh.
+    ///
+    /// Outcomes published in quick succession may be coalesced, so only the
+    /// latest is seen.
+    pub fn outcomes(&self) -> watch::Receiver<Option<RefreshOutcome>> {
+        self.completed_tx.subscribe()
+    }
+
+    /// Resolves when a refresh has been requested.
+    ///
+    /// Used by the worker. Requests that were already served by a scheduled
+    /// refresh can still wake the worker, so check
+    /// [`RefreshTrigger::is_pending`] before acting on the wake-up.
+    pub async fn requested(&self) {
+        self.notify.notified().await;
+    }
+
+    /// Returns `true` if a requested refresh has not started yet.
+    pub fn is_pending(&self) -> bool {
+        let generations = self.generations.lock().unwrap();
+        generations.requested > generations.started
+    }
+
+    /// Marks the start of a refresh, scheduled or requested.
+    ///
+    /// Every request made until now is served by this refresh.
+    ///
+    /// # Returns
+    ///
+    /// The generation to pass to [`RefreshTrigger::complete`].
+    pub fn begin(&self) -> u64 {
+        let mut generations = self.generations.lock().unwrap();
+        generations.started = generations.requested;
+        generations.started
+    }
+
+    /// Publishes the outcome of a refresh to everyone waiting for it.
+    ///
+    /// # Arguments
+    ///
+    /// * `generation` - The generation returned by [`RefreshTrigger::begin`].
+    /// * `result` - The stored supply, or why the refresh failed.
+    pub fn complete(&self, generation: u64, result: Result<TokenSupply, String>) {
+        self.completed_tx
+            .send_replace(Some(RefreshOutcome { generation, result }));
+    }
+}
+
+impl Default for RefreshTrigger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use tokio::time::timeout;
+
+    use super::*;
+
+    #[test]
+    fn concurrent_requests_coalesce_until_refresh_begins() {
+        // Arrange.
+        let trigger = RefreshTrigger::new();
+
+        // Act.
+        let first = trigger.request();
+        let second = trigger.request();
+        let started = trigger.begin();
+
+        // Assert.
+        assert_eq!(first, second);
+        assert_eq!(started, first);
+        assert!(!trigger.is_pending());
+    }
+
+    #[test]
+    fn request_during_refresh_queues_another() {
+        // Arrange.
+        let trigger = RefreshTrigger::new();
+        let in_progress = trigger.begin();
+
+        // Act.
+        let requested = trigger.request();
+
+        // Assert.
+        assert!(requested > in_progress);
+        assert!(trigger.is_pending());
+    }
+
+    #[tokio::test]
+    async fn waiters_receive_the_outcome_of_their_refresh() {
+        // Arrange.
+        let trigger = Arc::new(RefreshTrigger::new());
+        let generation = trigger.request();
+        let waiter = tokio::spawn({
+            let trigger = trigger.clone();
+            async move { trigger.wait_for(generation).await }
+        });
+
+        // Act.
+        trigger.requested().await;
+        let started = trigger.begin();
+        trigger.complete(started, Ok(TokenSupply::new("100", "50")));
+
+        // Assert.
+        let outcome = timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("Waiter should have been woken")
+            .unwrap();
+        assert_eq!(outcome.generation, generation);
+        assert_eq!(outcome.result, Ok(TokenSupply::new("100", "50")));
+    }
+}
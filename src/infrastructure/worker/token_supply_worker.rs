@@ -1,20 +1,89 @@
-use std::sync::Arc;
-use std::time::Duration;
+use std::any::Any;
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
-use tokio::sync::broadcast;
-use tokio::time::interval;
-use tracing::{error, info};
+use futures::FutureExt;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tokio::time::{interval, sleep, timeout};
+use tracing::{error, info, warn};
 
-use crate::application::port::inbound::TokenSupplyService;
-use crate::domain::model::{Source, Token};
+use crate::application::port::inbound::{SourceService, TokenSupplyService};
+use crate::domain::error::SupplyRejection;
+use crate::domain::model::{Token, TokenSupply};
+use crate::infrastructure::backoff::Backoff;
 
+use super::refresh_trigger::RefreshTrigger;
+use super::worker_monitor::WorkerMonitor;
+
+/// The first delay before restarting a crashed worker.
+const RESTART_INITIAL_DELAY: Duration = Duration::from_secs(1);
+
+/// The longest delay before restarting a crashed worker. A worker that stays
+/// up for longer than this is considered recovered, and the delay resets.
+const RESTART_MAX_DELAY: Duration = Duration::from_secs(60);
+
+/// Handle used to stop a running [`TokenSupplyWorker`].
+///
+/// Dropping the handle detaches the worker, which then runs until the process
+/// exits.
 pub struct WorkerHandle {
-    shutdown_tx: broadcast::Sender<()>,
+    shutdown_tx: watch::Sender<bool>,
+    task: Mutex<Option<JoinHandle<()>>>,
 }
+
 impl WorkerHandle {
-    pub fn shutdown(&self) {
-        let _ = self.shutdown_tx.send(());
+    /// Creates a handle for a worker that has not started yet.
+    pub(super) fn new() -> Self {
+        Self {
+            shutdown_tx: watch::channel(false).0,
+            task: Mutex::new(None),
+        }
+    }
+
+    /// Returns a receiver that observes the shutdown signal.
+    pub(super) fn subscribe(&self) -> watch::Receiver<bool> {
+        self.shutdown_tx.subscribe()
+    }
+
+    /// Attaches the task running the worker, so it can be awaited on shutdown.
+    pub(super) fn attach(&self, task: JoinHandle<()>) {
+        *self.task.lock().unwrap() = Some(task);
+    }
+
+    /// Signals the worker to stop and waits for its task to finish.
+    ///
+    /// A fetch or store that is already in progress is allowed to complete.
+    /// If it does not finish within `grace_period`, the task is aborted.
+    ///
+    /// # Arguments
+    ///
+    /// * `grace_period` - How long to wait for the worker to stop on its own.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the worker stopped cleanly, or `false` if it was aborted.
+    pub async fn shutdown(&self, grace_period: Duration) -> bool {
+        self.shutdown_tx.send_replace(true);
         info!("Shutdown signal sent to worker");
+
+        let Some(mut task) = self.task.lock().unwrap().take() else {
+            return true;
+        };
+
+        match timeout(grace_period, &mut task).await {
+            Ok(Ok(())) => true,
+            Ok(Err(e)) => {
+                error!("Worker failed while stopping: {}", e);
+                false
+            }
+            Err(_) => {
+                warn!("Worker did not stop within {:?}, aborting", grace_period);
+                task.abort();
+                false
+            }
+        }
     }
 }
 
@@ -22,16 +91,22 @@ impl WorkerHandle {
 ///
 /// This worker runs in the background and updates the latest token supply data
 /// at regular intervals based on the configured `update_interval`.
-pub struct TokenSupplyWorker<S> {
+///
+/// The update loop is supervised: if it panics, the panic is recorded in the
+/// worker's [`WorkerMonitor`] and the loop is restarted with backoff.
+pub struct TokenSupplyWorker<S, L> {
     token_supply_service: Arc<S>,
     token: Token,
-    excluded_sources: Vec<Source>,
+    source_service: Arc<L>,
     update_interval: u64,
+    monitor: Arc<WorkerMonitor>,
+    refresh_trigger: Arc<RefreshTrigger>,
 }
 
-impl<S> TokenSupplyWorker<S>
+impl<S, L> TokenSupplyWorker<S, L>
 where
     S: TokenSupplyService + Send + Sync + 'static,
+    L: SourceService + Send + Sync + 'static,
 {
     /// Creates a new [`TokenSupplyWorker`] instance.
     ///
@@ -39,7 +114,8 @@ where
     ///
     /// * `token_supply_service` - The service used to fetch and update token supply data.
     /// * `token` - The token for which supply information is retrieved.
-    /// * `excluded_sources` - A list of sources to exclude from the circulating supply.
+    /// * `source_service` - The service holding the live list of sources to
+    ///   exclude from the circulating supply.
     /// * `update_interval` - Time in seconds between update operations.
     ///
     /// # Returns
@@ -48,21 +124,48 @@ where
     pub fn new(
         token_supply_service: Arc<S>,
         token: Token,
-        excluded_sources: Vec<Source>,
+        source_service: Arc<L>,
         update_interval: u64,
     ) -> (Self, WorkerHandle) {
-        let (shutdown_tx, _) = broadcast::channel(1);
-
         let worker = Self {
             token_supply_service,
             token,
-            excluded_sources,
+            source_service,
             update_interval,
+            monitor: Arc::new(WorkerMonitor::new()),
+            refresh_trigger: Arc::new(RefreshTrigger::new()),
         };
 
-        let handle = WorkerHandle { shutdown_tx };
+        (worker, WorkerHandle::new())
+    }
 
-        (worker, handle)
+    /// Reports the worker's state to the given monitor instead of a private
+    /// one.
+    ///
+    /// # Arguments
+    ///
+    /// * `monitor` - The monitor shared with the health and metrics endpoints.
+    ///
+    /// # Returns
+    ///
+    /// The worker, reporting to `monitor`.
+    pub fn with_monitor(mut self, monitor: Arc<WorkerMonitor>) -> Self {
+        self.monitor = monitor;
+        self
+    }
+
+    /// Lets the given trigger wake the worker ahead of its schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `refresh_trigger` - The trigger shared with the admin endpoints.
+    ///
+    /// # Returns
+    ///
+    /// The worker, listening to `refresh_trigger`.
+    pub fn with_refresh_trigger(mut self, refresh_trigger: Arc<RefreshTrigger>) -> Self {
+        self.refresh_trigger = refresh_trigger;
+        self
     }
 
     /// Starts the background worker task.
@@ -71,46 +174,155 @@ where
     /// 1. Fetch the latest supply information.
     /// 2. Update the stored token supply data.
     ///
-    /// The task will continue running indefinitely until the application shuts
-    /// down. Any errors during fetch or update operations are logged but won't
-    /// stop the worker.
+    /// The task will continue running until [`WorkerHandle::shutdown`] is
+    /// called. Any errors during fetch or update operations are logged but
+    /// won't stop the worker, and a panic restarts it.
     ///
     /// # Note
     ///
     /// This method consumes the worker instance as it transfers ownership of
     /// the service to the background task.
     pub async fn start(self, handle: &WorkerHandle) {
-        let service = self.token_supply_service;
-        let token = self.token;
-        let sources = self.excluded_sources;
-        let freq = self.update_interval;
-        let mut shutdown_rx = handle.shutdown_tx.subscribe();
+        let mut shutdown_rx = handle.subscribe();
+
+        info!(
+            "Starting supply worker on interval: {} seconds",
+            self.update_interval
+        );
 
-        info!("Starting supply worker on interval: {} seconds", freq);
+        let task = tokio::spawn(async move {
+            let mut backoff = Backoff::new(RESTART_INITIAL_DELAY, RESTART_MAX_DELAY);
 
-        let mut update_interval = interval(Duration::from_secs(freq));
-        tokio::spawn(async move {
             loop {
+                self.monitor.running();
+                let started_at = Instant::now();
+
+                let run = AssertUnwindSafe(self.run(shutdown_rx.clone())).catch_unwind();
+                let error = match run.await {
+                    Ok(()) => break,
+                    Err(panic) => panic_message(panic),
+                };
+
+                if started_at.elapsed() >= RESTART_MAX_DELAY {
+                    backoff.reset();
+                }
+
+                let delay = backoff.next_delay();
+                error!(
+                    "Token supply worker crashed, restarting in {:?}: {}",
+                    delay, error
+                );
+                self.monitor.restarting(error);
+
                 tokio::select! {
-                    _ = update_interval.tick() => {
-                        info!("Fetching updated token supply data");
-
-                        match service.fetch_token_supply(&token, &sources).await {
-                            Ok(supply) => match service.update_token_supply(supply).await {
-                                Ok(_) => info!("Successfully updated token supply data"),
-                                Err(e) => error!("Failed to update token supply: {}", e),
-                            },
-                            Err(e) => error!("Failed to fetch circulating supply: {}", e),
-                        }
-                    }
-                    _ = shutdown_rx.recv() => {
-                        info!("Received shutdown signal, stopping worker");
-                        break;
-                    }
+                    biased;
+
+                    _ = shutdown_requested(&mut shutdown_rx) => break,
+                    _ = sleep(delay) => {}
                 }
             }
+
+            self.monitor.stopped();
             info!("Token supply worker stopped");
         });
+
+        handle.attach(task);
+    }
+
+    /// Runs the update loop until a shutdown is requested.
+    async fn run(&self, mut shutdown_rx: watch::Receiver<bool>) {
+        let trigger = &self.refresh_trigger;
+        let mut update_interval = interval(Duration::from_secs(self.update_interval));
+
+        loop {
+            tokio::select! {
+                biased;
+
+                _ = shutdown_requested(&mut shutdown_rx) => {
+                    info!("Received shutdown signal, stopping worker");
+                    break;
+                }
+                _ = trigger.requested() => {
+                    // Already served by a scheduled refresh.
+                    if !trigger.is_pending() {
+                        continue;
+                    }
+
+                    info!("Refresh requested, fetching updated token supply data");
+                    update_interval.reset();
+                }
+                _ = update_interval.tick() => {
+                    info!("Fetching updated token supply data");
+                }
+            }
+
+            let generation = trigger.begin();
+            let result = self.refresh().await;
+            trigger.complete(generation, result);
+        }
+    }
+
+    /// Fetches and stores the latest supply.
+    async fn refresh(&self) -> Result<TokenSupply, String> {
+        let service = &self.token_supply_service;
+        let sources = self.source_service.list_sources().await;
+
+        let supply = match service.fetch_token_supply(&self.token, &sources).await {
+            Ok(supply) => supply,
+            Err(e) => {
+                // Manual adjustments are applied while fetching, so an
+                // implausible supply can already be rejected here.
+                match e.downcast_ref::<SupplyRejection>() {
+                    Some(rejection) => self.reject(rejection),
+                    None => error!("Failed to fetch circulating supply: {}", e),
+                }
+                return Err(e.to_string());
+            }
+        };
+
+        match service.update_token_supply(supply.clone()).await {
+            Ok(_) => {
+                info!("Successfully updated token supply data");
+                Ok(supply)
+            }
+            Err(e) => {
+                match e.downcast_ref::<SupplyRejection>() {
+                    Some(rejection) => self.reject(rejection),
+                    None => error!("Failed to update token supply: {}", e),
+                }
+                Err(e.to_string())
+            }
+        }
+    }
+
+    /// Records a supply that was judged implausible and not stored.
+    fn reject(&self, rejection: &SupplyRejection) {
+        error!(
+            "Rejected implausible token supply, keeping the previous snapshot: {}",
+            rejection
+        );
+        self.monitor.rejected(rejection.to_string());
+    }
+}
+
+/// Resolves once a shutdown has been requested.
+///
+/// Never resolves if the [`WorkerHandle`] was dropped, which detaches the
+/// worker.
+pub(super) async fn shutdown_requested(shutdown_rx: &mut watch::Receiver<bool>) {
+    if shutdown_rx.wait_for(|stop| *stop).await.is_err() {
+        std::future::pending::<()>().await;
+    }
+}
+
+/// Extracts the message from a panic payload.
+fn panic_message(panic: Box<dyn Any + Send>) -> String {
+    match panic.downcast::<String>() {
+        Ok(message) => format!("panicked: {}", message),
+        Err(panic) => match panic.downcast::<&'static str>() {
+            Ok(message) => format!("panicked: {}", message),
+            Err(_) => "panicked".into(),
+        },
     }
 }
 
@@ -119,11 +331,13 @@ mod tests {
     use std::sync::{Arc, Mutex};
     use std::time::Duration;
 
-    use alloy::primitives::address;
+    use alloy::primitives::{U256, address};
     use anyhow::{Result, anyhow};
     use tokio::time::sleep;
 
-    use crate::domain::model::{Source, TokenSupply};
+    use crate::domain::error::SourceError;
+    use crate::domain::model::{ChainAddress, Source, TokenSupply};
+    use crate::infrastructure::worker::WorkerState;
 
     use super::*;
 
@@ -136,6 +350,10 @@ mod tests {
         update_count: Arc<Mutex<u32>>,
         fetch_should_fail: bool,
         update_should_fail: bool,
+        fetch_delay: Duration,
+        panic_on_first_fetch: bool,
+        fetch_rejection: Option<SupplyRejection>,
+        update_rejection: Option<SupplyRejection>,
     }
 
     impl MockTokenSupplyService {
@@ -145,8 +363,32 @@ mod tests {
                 update_count: Arc::new(Mutex::new(0)),
                 fetch_should_fail,
                 update_should_fail,
+                fetch_delay: Duration::ZERO,
+                panic_on_first_fetch: false,
+                fetch_rejection: None,
+                update_rejection: None,
             }
         }
+
+        fn with_fetch_rejection(mut self, rejection: SupplyRejection) -> Self {
+            self.fetch_rejection = Some(rejection);
+            self
+        }
+
+        fn with_update_rejection(mut self, rejection: SupplyRejection) -> Self {
+            self.update_rejection = Some(rejection);
+            self
+        }
+
+        fn with_panic_on_first_fetch(mut self) -> Self {
+            self.panic_on_first_fetch = true;
+            self
+        }
+
+        fn with_fetch_delay(mut self, fetch_delay: Duration) -> Self {
+            self.fetch_delay = fetch_delay;
+            self
+        }
     }
 
     impl TokenSupplyService for MockTokenSupplyService {
@@ -155,8 +397,21 @@ mod tests {
             _token: &Token,
             _sources: &[Source],
         ) -> Result<TokenSupply> {
-            let mut count = self.fetch_count.lock().unwrap();
-            *count += 1;
+            let count = {
+                let mut count = self.fetch_count.lock().unwrap();
+                *count += 1;
+                *count
+            };
+
+            if self.panic_on_first_fetch && count == 1 {
+                panic!("Simulated worker bug");
+            }
+
+            sleep(self.fetch_delay).await;
+
+            if let Some(rejection) = &self.fetch_rejection {
+                return Err(rejection.clone().into());
+            }
 
             match self.fetch_should_fail {
                 true => Err(anyhow!("Simulated fetch failure")),
@@ -168,6 +423,10 @@ mod tests {
             let mut count = self.update_count.lock().unwrap();
             *count += 1;
 
+            if let Some(rejection) = &self.update_rejection {
+                return Err(rejection.clone().into());
+            }
+
             match self.update_should_fail {
                 true => Err(anyhow!("Simulated fetch failure")),
                 false => Ok(()),
@@ -179,6 +438,22 @@ mod tests {
         }
     }
 
+    struct MockSourceService;
+
+    impl SourceService for MockSourceService {
+        async fn list_sources(&self) -> Vec<Source> {
+            Vec::new()
+        }
+
+        async fn add_source(&self, _source: Source, _actor: &str) -> Result<()> {
+            Ok(())
+        }
+
+        async fn remove_source(&self, address: ChainAddress, _actor: &str) -> Result<Source> {
+            Err(SourceError::NotFound(address).into())
+        }
+    }
+
     // -------------------------------------------------------------------------
     // Test Helper Functions
 
@@ -202,7 +477,7 @@ mod tests {
         let fetch_count = service.fetch_count.clone();
         let update_count = service.update_count.clone();
         let token = create_token();
-        let sources: Vec<Source> = Vec::new();
+        let sources = Arc::new(MockSourceService);
 
         // Act.
         let (worker, handle) = TokenSupplyWorker::new(service, token, sources, 1);
@@ -229,7 +504,7 @@ mod tests {
         let service = Arc::new(service);
         let fetch_count = service.fetch_count.clone();
         let token = create_token();
-        let sources: Vec<Source> = Vec::new();
+        let sources = Arc::new(MockSourceService);
 
         // Act.
         let (worker, handle) = TokenSupplyWorker::new(service, token, sources, 1);
@@ -252,7 +527,7 @@ mod tests {
         let fetch_count = service.fetch_count.clone();
         let update_count = service.update_count.clone();
         let token = create_token();
-        let sources: Vec<Source> = Vec::new();
+        let sources = Arc::new(MockSourceService);
 
         // Act.
         let (worker, handle) = TokenSupplyWorker::new(service, token, sources, 1);
@@ -280,7 +555,7 @@ mod tests {
         let fetch_count = service.fetch_count.clone();
         let update_count = service.update_count.clone();
         let token = create_token();
-        let sources: Vec<Source> = Vec::new();
+        let sources = Arc::new(MockSourceService);
 
         // Act.
         let (worker, handle) = TokenSupplyWorker::new(service, token, sources, 1);
@@ -301,4 +576,218 @@ mod tests {
             "Should not have attempted any updates due to fetch errors"
         );
     }
+
+    #[tokio::test]
+    async fn shutdown_waits_for_worker_to_stop() {
+        // Arrange.
+        let service = Arc::new(MockTokenSupplyService::new(false, false));
+        let fetch_count = service.fetch_count.clone();
+        let (worker, handle) =
+            TokenSupplyWorker::new(service, create_token(), Arc::new(MockSourceService), 3600);
+        worker.start(&handle).await;
+        sleep(Duration::from_millis(50)).await;
+
+        // Act.
+        let stopped_cleanly = handle.shutdown(Duration::from_secs(1)).await;
+
+        // Assert.
+        assert!(stopped_cleanly, "Worker should have stopped cleanly");
+        assert_eq!(*fetch_count.lock().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn shutdown_lets_in_progress_fetch_complete() {
+        // Arrange.
+        let service = MockTokenSupplyService::new(false, false);
+        let service = Arc::new(service.with_fetch_delay(Duration::from_millis(200)));
+        let update_count = service.update_count.clone();
+        let (worker, handle) =
+            TokenSupplyWorker::new(service, create_token(), Arc::new(MockSourceService), 3600);
+        worker.start(&handle).await;
+        sleep(Duration::from_millis(50)).await;
+
+        // Act.
+        let stopped_cleanly = handle.shutdown(Duration::from_secs(5)).await;
+
+        // Assert.
+        assert!(stopped_cleanly, "Worker should have stopped cleanly");
+        assert_eq!(
+            *update_count.lock().unwrap(),
+            1,
+            "The in-progress fetch should have been stored before stopping"
+        );
+    }
+
+    #[tokio::test]
+    async fn shutdown_aborts_worker_that_does_not_stop_in_time() {
+        // Arrange.
+        let service = MockTokenSupplyService::new(false, false);
+        let service = Arc::new(service.with_fetch_delay(Duration::from_secs(3600)));
+        let update_count = service.update_count.clone();
+        let (worker, handle) =
+            TokenSupplyWorker::new(service, create_token(), Arc::new(MockSourceService), 3600);
+        worker.start(&handle).await;
+        sleep(Duration::from_millis(50)).await;
+
+        // Act.
+        let stopped_cleanly = handle.shutdown(Duration::from_millis(100)).await;
+
+        // Assert.
+        assert!(!stopped_cleanly, "Worker should have been aborted");
+        assert_eq!(*update_count.lock().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn worker_records_rejected_updates() {
+        // Arrange.
+        let rejection = SupplyRejection::CirculatingExceedsTotal {
+            total: U256::from(1000),
+            circulating: U256::from(2000),
+        };
+        let service = MockTokenSupplyService::new(false, false).with_update_rejection(rejection);
+        let monitor = Arc::new(WorkerMonitor::new());
+        let (worker, handle) = TokenSupplyWorker::new(
+            Arc::new(service),
+            create_token(),
+            Arc::new(MockSourceService),
+            3600,
+        );
+
+        // Act.
+        worker.with_monitor(monitor.clone()).start(&handle).await;
+        sleep(Duration::from_millis(500)).await;
+
+        // Assert.
+        let status = monitor.status();
+        assert_eq!(status.rejected_updates, 1);
+        assert_eq!(
+            status.last_rejection.as_deref(),
+            Some("circulating supply (2000) exceeds the total supply (1000)")
+        );
+    }
+
+    #[tokio::test]
+    async fn worker_records_rejected_adjustments() {
+        // Arrange.
+        let rejection = SupplyRejection::Underflow {
+            total: U256::from(1000),
+            excluded: U256::from(2000),
+        };
+        let service = MockTokenSupplyService::new(false, false).with_fetch_rejection(rejection);
+        let service = Arc::new(service);
+        let update_count = service.update_count.clone();
+        let monitor = Arc::new(WorkerMonitor::new());
+        let (worker, handle) =
+            TokenSupplyWorker::new(service, create_token(), Arc::new(MockSourceService), 3600);
+
+        // Act.
+        worker.with_monitor(monitor.clone()).start(&handle).await;
+        sleep(Duration::from_millis(500)).await;
+
+        // Assert.
+        let status = monitor.status();
+        assert_eq!(status.rejected_updates, 1);
+        assert_eq!(
+            status.last_rejection.as_deref(),
+            Some("excluded balances (2000) exceed the total supply (1000)")
+        );
+        assert_eq!(*update_count.lock().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn worker_restarts_after_panic() {
+        // Arrange.
+        let service = MockTokenSupplyService::new(false, false).with_panic_on_first_fetch();
+        let service = Arc::new(service);
+        let fetch_count = service.fetch_count.clone();
+        let monitor = Arc::new(WorkerMonitor::new());
+        let (worker, handle) =
+            TokenSupplyWorker::new(service, create_token(), Arc::new(MockSourceService), 3600);
+
+        // Act.
+        worker.with_monitor(monitor.clone()).start(&handle).await;
+        sleep(Duration::from_millis(1500)).await;
+
+        // Assert.
+        let status = monitor.status();
+        assert_eq!(status.state, WorkerState::Running);
+        assert_eq!(status.restarts, 1);
+        assert_eq!(
+            status.last_error.as_deref(),
+            Some("panicked: Simulated worker bug")
+        );
+        assert_eq!(
+            *fetch_count.lock().unwrap(),
+            2,
+            "Should have fetched again after the restart"
+        );
+    }
+
+    #[tokio::test]
+    async fn shutdown_marks_worker_stopped() {
+        // Arrange.
+        let service = Arc::new(MockTokenSupplyService::new(false, false));
+        let monitor = Arc::new(WorkerMonitor::new());
+        let (worker, handle) =
+            TokenSupplyWorker::new(service, create_token(), Arc::new(MockSourceService), 3600);
+        worker.with_monitor(monitor.clone()).start(&handle).await;
+
+        // Act.
+        handle.shutdown(Duration::from_secs(1)).await;
+
+        // Assert.
+        assert_eq!(monitor.status().state, WorkerState::Stopped);
+    }
+
+    #[tokio::test]
+    async fn shutdown_interrupts_restart_backoff() {
+        // Arrange.
+        let service = MockTokenSupplyService::new(false, false).with_panic_on_first_fetch();
+        let service = Arc::new(service);
+        let fetch_count = service.fetch_count.clone();
+        let monitor = Arc::new(WorkerMonitor::new());
+        let (worker, handle) =
+            TokenSupplyWorker::new(service, create_token(), Arc::new(MockSourceService), 3600);
+        worker.with_monitor(monitor.clone()).start(&handle).await;
+        sleep(Duration::from_millis(50)).await;
+
+        // Act.
+        let stopped_cleanly = handle.shutdown(Duration::from_millis(200)).await;
+
+        // Assert.
+        assert!(stopped_cleanly, "Worker should have stopped during backoff");
+        assert_eq!(monitor.status().state, WorkerState::Stopped);
+        assert_eq!(*fetch_count.lock().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn refresh_trigger_wakes_worker_ahead_of_schedule() {
+        // Arrange.
+        let service = Arc::new(MockTokenSupplyService::new(false, false));
+        let fetch_count = service.fetch_count.clone();
+        let trigger = Arc::new(RefreshTrigger::new());
+        let (worker, handle) =
+            TokenSupplyWorker::new(service, create_token(), Arc::new(MockSourceService), 3600);
+        worker
+            .with_refresh_trigger(trigger.clone())
+            .start(&handle)
+            .await;
+        sleep(Duration::from_millis(50)).await;
+
+        // Act.
+        let first = trigger.request();
+        let second = trigger.request();
+        let outcome = tokio::time::timeout(Duration::from_secs(1), trigger.wait_for(second))
+            .await
+            .expect("Refresh should have completed");
+
+        // Assert.
+        assert_eq!(first, second, "Concurrent requests should coalesce");
+        assert_eq!(outcome.result, Ok(TokenSupply::new("2000", "1000.00")));
+        assert_eq!(
+            *fetch_count.lock().unwrap(),
+            2,
+            "Should have fetched once on start and once for the coalesced requests"
+        );
+    }
 }
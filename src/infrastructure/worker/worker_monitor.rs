@@ -0,0 +1,205 @@
+use std::sync::Mutex;
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// The lifecycle state of a supervised worker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkerState {
+    /// The worker has not been started yet, for example while the service is
+    /// still connecting to the blockchain.
+    Starting,
+
+    /// The worker is running.
+    Running,
+
+    /// The worker crashed and is waiting to be restarted.
+    Restarting,
+
+    /// The worker was shut down.
+    Stopped,
+}
+
+impl WorkerState {
+    /// Every state, in declaration order.
+    pub const ALL: [WorkerState; 4] = [
+        WorkerState::Starting,
+        WorkerState::Running,
+        WorkerState::Restarting,
+        WorkerState::Stopped,
+    ];
+
+    /// Converts the state to a string representation.
+    ///
+    /// # Returns
+    ///
+    /// A static string identifier for the state.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WorkerState::Starting => "starting",
+            WorkerState::Running => "running",
+            WorkerState::Restarting => "restarting",
+            WorkerState::Stopped => "stopped",
+        }
+    }
+
+    /// Returns `true` if the worker is expected to be producing updates.
+    ///
+    /// A worker that has not started yet is considered healthy, so that a
+    /// slow connection to the blockchain does not fail liveness checks.
+    pub fn is_healthy(&self) -> bool {
+        matches!(self, WorkerState::Starting | WorkerState::Running)
+    }
+}
+
+/// A point-in-time view of a worker's state.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct WorkerStatus {
+    /// The current lifecycle state.
+    pub state: WorkerState,
+
+    /// How many times the worker has been restarted after a crash.
+    pub restarts: u64,
+
+    /// The reason for the most recent crash, if any.
+    pub last_error: Option<String>,
+
+    /// How many fetched supplies were rejected as implausible.
+    pub rejected_updates: u64,
+
+    /// The reason the most recent supply was rejected, if any.
+    pub last_rejection: Option<String>,
+}
+
+/// Shared record of a worker's state.
+///
+/// The worker's supervisor writes to it, and the health and metrics endpoints
+/// read from it.
+#[derive(Debug)]
+pub struct WorkerMonitor {
+    status: Mutex<WorkerStatus>,
+}
+
+impl WorkerMonitor {
+    /// Creates a new [`WorkerMonitor`] instance in the
+    /// [`WorkerState::Starting`] state.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`WorkerMonitor`] instance.
+    pub fn new() -> Self {
+        Self {
+            status: Mutex::new(WorkerStatus {
+                state: WorkerState::Starting,
+                restarts: 0,
+                last_error: None,
+                rejected_updates: 0,
+                last_rejection: None,
+            }),
+        }
+    }
+
+    /// Returns a copy of the current status.
+    pub fn status(&self) -> WorkerStatus {
+        self.status.lock().unwrap().clone()
+    }
+
+    /// Records that the worker is running.
+    pub fn running(&self) {
+        self.status.lock().unwrap().state = WorkerState::Running;
+    }
+
+    /// Records that the worker crashed and will be restarted.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - Why the worker crashed.
+    pub fn restarting(&self, error: String) {
+        let mut status = self.status.lock().unwrap();
+
+        status.state = WorkerState::Restarting;
+        status.restarts += 1;
+        status.last_error = Some(error);
+    }
+
+    /// Records that a fetched supply was rejected as implausible.
+    ///
+    /// # Arguments
+    ///
+    /// * `reason` - Why the supply was rejected.
+    pub fn rejected(&self, reason: String) {
+        let mut status = self.status.lock().unwrap();
+
+        status.rejected_updates += 1;
+        status.last_rejection = Some(reason);
+    }
+
+    /// Records that the worker was shut down.
+    pub fn stopped(&self) {
+        self.status.lock().unwrap().state = WorkerState::Stopped;
+    }
+}
+
+impl Default for WorkerMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restarting_counts_restarts_and_keeps_last_error() {
+        // Arrange.
+        let monitor = WorkerMonitor::new();
+
+        // Act.
+        monitor.running();
+        monitor.restarting("first".into());
+        monitor.running();
+        monitor.restarting("second".into());
+
+        // Assert.
+        assert_eq!(
+            monitor.status(),
+            WorkerStatus {
+                state: WorkerState::Restarting,
+                restarts: 2,
+                last_error: Some("second".into()),
+                rejected_updates: 0,
+                last_rejection: None,
+            }
+        );
+    }
+
+    #[test]
+    fn rejected_counts_rejections_and_keeps_last_reason() {
+        // Arrange.
+        let monitor = WorkerMonitor::new();
+
+        // Act.
+        monitor.rejected("first".into());
+        monitor.rejected("second".into());
+
+        // Assert.
+        let status = monitor.status();
+        assert_eq!(status.rejected_updates, 2);
+        assert_eq!(status.last_rejection.as_deref(), Some("second"));
+        assert_eq!(status.state, WorkerState::Starting);
+    }
+
+    #[test]
+    fn only_starting_and_running_are_healthy() {
+        // Act.
+        let healthy: Vec<WorkerState> = WorkerState::ALL
+            .into_iter()
+            .filter(WorkerState::is_healthy)
+            .collect();
+
+        // Assert.
+        assert_eq!(healthy, vec![WorkerState::Starting, WorkerState::Running]);
+    }
+}
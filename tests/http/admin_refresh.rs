@@ -0,0 +1,179 @@
+use coingecko_supply::infrastructure::adapter::outbound::blockchain::mock_rpc::{Fault, RpcCall};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::common::{ADMIN_TOKEN, spawn_app, spawn_rpc_app, tokens};
+
+#[derive(Deserialize, Debug)]
+struct Body {
+    status: String,
+    total_supply: Option<String>,
+    circulating_supply: Option<String>,
+}
+
+#[tokio::test]
+async fn refresh_rejects_missing_credentials() {
+    // Arrange.
+    let app = spawn_app().await;
+
+    // Act.
+    let response = app
+        .api_client
+        .post(format!("{}/admin/refresh", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status().as_u16(), 401);
+    assert_eq!(
+        response.headers().get("www-authenticate").unwrap(),
+        "Bearer"
+    );
+}
+
+#[tokio::test]
+async fn refresh_rejects_invalid_bearer_token() {
+    // Arrange.
+    let app = spawn_app().await;
+
+    // Act.
+    let response = app
+        .api_client
+        .post(format!("{}/admin/refresh", &app.address))
+        .bearer_auth("not-the-admin-token")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status().as_u16(), 401);
+}
+
+#[tokio::test]
+async fn refresh_accepts_bearer_token() {
+    // Arrange.
+    let app = spawn_app().await;
+
+    // Act.
+    let response = app
+        .api_client
+        .post(format!("{}/admin/refresh", &app.address))
+        .bearer_auth(ADMIN_TOKEN)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status().as_u16(), 202);
+
+    let body = response
+        .json::<Body>()
+        .await
+        .expect("Failed to extract body");
+
+    assert_eq!(body.status, "accepted");
+}
+
+#[tokio::test]
+async fn refresh_with_wait_returns_new_supply() {
+    // Arrange.
+    let app = spawn_app().await;
+
+    // Act.
+    let response = app
+        .api_client
+        .post(format!("{}/admin/refresh?wait=true", &app.address))
+        .header("X-API-Key", ADMIN_TOKEN)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert!(response.status().is_success());
+
+    let body = response
+        .json::<Body>()
+        .await
+        .expect("Failed to extract body");
+
+    assert_eq!(body.status, "refreshed");
+    assert_eq!(body.total_supply.as_deref(), Some("4242.00"));
+    assert_eq!(body.circulating_supply.as_deref(), Some("4200.00"));
+}
+
+#[tokio::test]
+async fn refresh_reads_the_latest_chain_state() {
+    // Arrange.
+    let (app, rpc) = spawn_rpc_app().await;
+    rpc.update(|s| {
+        s.block_number += 10;
+        s.total_supply = tokens(5000);
+    });
+
+    // Act.
+    let response = app
+        .api_client
+        .post(format!("{}/admin/refresh?wait=true", &app.address))
+        .bearer_auth(ADMIN_TOKEN)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert!(response.status().is_success());
+
+    let body = response
+        .json::<Body>()
+        .await
+        .expect("Failed to extract body");
+
+    assert_eq!(
+        body.total_supply.as_deref(),
+        Some("5000.000000000000000000")
+    );
+    assert_eq!(
+        body.circulating_supply.as_deref(),
+        Some("4958.000000000000000000")
+    );
+}
+
+#[tokio::test]
+async fn refresh_reports_rpc_failures_and_keeps_the_last_supply() {
+    // Arrange.
+    let (app, rpc) = spawn_rpc_app().await;
+    rpc.inject(
+        RpcCall::TotalSupply,
+        Fault::Error {
+            code: -32000,
+            message: "header not found".into(),
+        },
+    );
+
+    // Act.
+    let response = app
+        .api_client
+        .post(format!("{}/admin/refresh?wait=true", &app.address))
+        .bearer_auth(ADMIN_TOKEN)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status().as_u16(), 502);
+
+    let body: Value = response.json().await.expect("Failed to extract body");
+    assert_eq!(body["code"], "refresh_failed");
+
+    let total = app
+        .api_client
+        .get(format!("{}/v1/total", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+        .json::<Value>()
+        .await
+        .expect("Failed to extract body");
+
+    assert_eq!(total["result"], "4242.000000000000000000");
+}
@@ -0,0 +1,182 @@
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::common::{ADMIN_TOKEN, spawn_app};
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Source {
+    name: String,
+    address: String,
+}
+
+const SINK: &str = "0xB1a932A665FB0A1D5d7979cd63e80a59EDCe31B4";
+const TREASURY: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+const TOKEN: &str = "0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118";
+const ZERO: &str = "0x0000000000000000000000000000000000000000";
+
+#[tokio::test]
+async fn sources_require_credentials() {
+    // Arrange.
+    let app = spawn_app().await;
+
+    // Act.
+    let response = app
+        .api_client
+        .get(format!("{}/admin/sources", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status().as_u16(), 401);
+}
+
+#[tokio::test]
+async fn list_sources_returns_configured_sources() {
+    // Arrange.
+    let app = spawn_app().await;
+
+    // Act.
+    let response = app
+        .api_client
+        .get(format!("{}/admin/sources", &app.address))
+        .bearer_auth(ADMIN_TOKEN)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert!(response.status().is_success());
+
+    let body = response
+        .json::<Vec<Source>>()
+        .await
+        .expect("Failed to extract body");
+
+    assert_eq!(
+        body,
+        vec![Source {
+            name: "Sink".into(),
+            address: SINK.into(),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn add_source_persists_and_audits_the_change() {
+    // Arrange.
+    let app = spawn_app().await;
+
+    // Act.
+    let response = app
+        .api_client
+        .post(format!("{}/admin/sources", &app.address))
+        .bearer_auth(ADMIN_TOKEN)
+        .json(&json!({ "name": "Treasury", "address": TREASURY }))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status().as_u16(), 201);
+
+    let saved = std::fs::read_to_string(app.data_dir.path().join("excluded_sources.json"))
+        .expect("Sources should have been saved");
+    assert!(saved.contains("Treasury"));
+
+    let audit = std::fs::read_to_string(app.data_dir.path().join("source_audit.jsonl"))
+        .expect("Change should have been audited");
+    let change: serde_json::Value = serde_json::from_str(audit.trim()).unwrap();
+    assert_eq!(change["actor"], "test");
+    assert_eq!(change["action"], "added");
+    assert_eq!(change["source"]["name"], "Treasury");
+}
+
+#[tokio::test]
+async fn add_source_rejects_unchecksummed_address() {
+    // Arrange.
+    let app = spawn_app().await;
+
+    // Act.
+    let response = app
+        .api_client
+        .post(format!("{}/admin/sources", &app.address))
+        .bearer_auth(ADMIN_TOKEN)
+        .json(&json!({ "name": "Treasury", "address": TREASURY.to_lowercase() }))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status().as_u16(), 400);
+}
+
+#[tokio::test]
+async fn add_source_rejects_zero_and_token_addresses() {
+    // Arrange.
+    let app = spawn_app().await;
+
+    for address in [ZERO, TOKEN] {
+        // Act.
+        let response = app
+            .api_client
+            .post(format!("{}/admin/sources", &app.address))
+            .bearer_auth(ADMIN_TOKEN)
+            .json(&json!({ "name": "Invalid", "address": address }))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        // Assert.
+        assert_eq!(response.status().as_u16(), 400, "Accepted {}", address);
+
+        let body: serde_json::Value = response.json().await.expect("Failed to extract body");
+        assert_eq!(body["code"], "invalid_source");
+    }
+}
+
+#[tokio::test]
+async fn add_source_rejects_duplicate_address() {
+    // Arrange.
+    let app = spawn_app().await;
+
+    // Act.
+    let response = app
+        .api_client
+        .post(format!("{}/admin/sources", &app.address))
+        .bearer_auth(ADMIN_TOKEN)
+        .json(&json!({ "name": "Sink Copy", "address": SINK }))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status().as_u16(), 409);
+}
+
+#[tokio::test]
+async fn remove_source_deletes_it_from_the_live_list() {
+    // Arrange.
+    let app = spawn_app().await;
+
+    // Act.
+    let response = app
+        .api_client
+        .delete(format!("{}/admin/sources/{}", &app.address, SINK))
+        .bearer_auth(ADMIN_TOKEN)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    let missing = app
+        .api_client
+        .delete(format!("{}/admin/sources/{}", &app.address, SINK))
+        .bearer_auth(ADMIN_TOKEN)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert!(response.status().is_success());
+    assert_eq!(missing.status().as_u16(), 404);
+}
@@ -0,0 +1,128 @@
+use std::time::Duration;
+
+use reqwest::header::{CACHE_CONTROL, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::{Response, StatusCode};
+
+use crate::common::{TestApp, spawn_app};
+
+// -----------------------------------------------------------------------------
+// Test Helper Functions
+
+/// Waits for the worker to store its first snapshot, which is the first one
+/// served with validators.
+async fn first_cacheable_response(app: &TestApp, path: &str) -> Response {
+    for _ in 0..50 {
+        let response = app
+            .api_client
+            .get(format!("{}{}", &app.address, path))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        if response.headers().contains_key(ETAG) {
+            return response;
+        }
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    panic!("The worker never stored a snapshot");
+}
+
+fn header(response: &Response, name: reqwest::header::HeaderName) -> String {
+    response
+        .headers()
+        .get(name)
+        .expect("Missing header")
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[tokio::test]
+async fn supply_responses_carry_cache_headers() {
+    // Arrange.
+    let app = spawn_app().await;
+
+    // Act.
+    let response = first_cacheable_response(&app, "/v1/total").await;
+
+    // Assert.
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().contains_key(LAST_MODIFIED));
+
+    let cache_control = header(&response, CACHE_CONTROL);
+    let max_age = cache_control
+        .split(", ")
+        .find_map(|d| d.strip_prefix("max-age="))
+        .expect("Missing max-age")
+        .parse::<u64>()
+        .unwrap();
+
+    assert!(cache_control.starts_with("public"));
+    assert!(max_age > 0);
+}
+
+#[tokio::test]
+async fn matching_if_none_match_returns_not_modified() {
+    // Arrange.
+    let app = spawn_app().await;
+    let first = first_cacheable_response(&app, "/v1/circulating").await;
+    let etag = header(&first, ETAG);
+
+    // Act.
+    let response = app
+        .api_client
+        .get(format!("{}/v1/circulating", &app.address))
+        .header(IF_NONE_MATCH, &etag)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(header(&response, ETAG), etag);
+    assert!(response.bytes().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn stale_if_none_match_returns_full_response() {
+    // Arrange.
+    let app = spawn_app().await;
+    first_cacheable_response(&app, "/v1/total").await;
+
+    // Act.
+    let response = app
+        .api_client
+        .get(format!("{}/v1/total", &app.address))
+        .header(IF_NONE_MATCH, "\"0-0\"")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn if_modified_since_last_modified_returns_not_modified() {
+    // Arrange.
+    let app = spawn_app().await;
+    let first = first_cacheable_response(&app, "/v1/total").await;
+    let last_modified = header(&first, LAST_MODIFIED);
+
+    // Act.
+    let response = app
+        .api_client
+        .get(format!("{}/v1/total", &app.address))
+        .header(IF_MODIFIED_SINCE, last_modified)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+}
@@ -1,6 +1,6 @@
 use serde::Deserialize;
 
-use crate::common::spawn_app;
+use crate::common::{spawn_app, spawn_uninitialised_app};
 
 #[derive(Deserialize, Debug)]
 struct Body {
@@ -8,6 +8,7 @@ struct Body {
 }
 
 #[tokio::test]
+#[allow(clippy::needless_borrows_for_generic_args)]
 async fn circulating_supply_works() {
     // Arrange.
     let app = spawn_app().await;
@@ -30,3 +31,20 @@ async fn circulating_supply_works() {
 
     assert_eq!(body.result, "4200.00");
 }
+
+#[tokio::test]
+async fn circulating_supply_is_unavailable_before_first_fetch() {
+    // Arrange.
+    let app = spawn_uninitialised_app().await;
+
+    // Act.
+    let response = app
+        .api_client
+        .get(format!("{}/v1/circulating", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status().as_u16(), 503);
+}
@@ -1,76 +1,268 @@
+use std::path::Path;
 use std::sync::{Arc, LazyLock};
+use std::time::Duration;
 
 use actix_web::web::Data;
-use alloy::primitives::address;
-use anyhow::Result;
+use alloy::primitives::{U256, address, utils};
+use alloy::providers::ProviderBuilder;
+use tempfile::TempDir;
 
-use coingecko_supply::application::port::outbound::TokenSupplyProvider;
-use coingecko_supply::application::use_case::TokenSupplyUseCase;
-use coingecko_supply::domain::model::{Source, Token, TokenSupply};
+use coingecko_supply::application::port::outbound::{
+    ChainIdProvider, TokenMetadataProvider, TokenSupplyProvider,
+};
+use coingecko_supply::application::use_case::{SourceUseCase, TokenSupplyUseCase};
+use coingecko_supply::domain::model::{Token, TokenSupply};
 use coingecko_supply::infrastructure::adapter::inbound::http::HttpApplication;
-use coingecko_supply::infrastructure::adapter::outbound::persistence::InMemoryTokenSupplyRepository;
+use coingecko_supply::infrastructure::adapter::inbound::http::explorer::Explorer;
+use coingecko_supply::infrastructure::adapter::inbound::http::resolved_token::ResolvedToken;
+use coingecko_supply::infrastructure::adapter::outbound::blockchain::mock_rpc::{
+    Erc20State, MockRpc,
+};
+use coingecko_supply::infrastructure::adapter::outbound::blockchain::{
+    BlockchainChainIdProvider, BlockchainTokenMetadataProvider, BlockchainTokenSupplyProvider,
+};
+use coingecko_supply::infrastructure::adapter::outbound::chain::ChainVerifier;
+use coingecko_supply::infrastructure::adapter::outbound::persistence::{
+    FileSourceAuditLog, FileSourceRepository, InMemoryTokenSupplyRepository,
+};
+use coingecko_supply::infrastructure::adapter::outbound::static_config::{
+    StaticChainIdProvider, StaticTokenMetadataProvider, StaticTokenSupplyProvider,
+};
 use coingecko_supply::infrastructure::configuration::Config;
+use coingecko_supply::infrastructure::configuration::admin::{AdminConfig, AdminCredential};
+use coingecko_supply::infrastructure::configuration::blockchain::ProviderKind;
 use coingecko_supply::infrastructure::telemetry::setup_tracing;
-use coingecko_supply::infrastructure::worker::TokenSupplyWorker;
+use coingecko_supply::infrastructure::worker::{RefreshTrigger, TokenSupplyWorker, WorkerMonitor};
+
+/// The token accepted by the admin endpoints of a [`TestApp`].
+pub const ADMIN_TOKEN: &str = "test-admin-token-0123456789";
+
+/// The configuration every [`TestApp`] starts from.
+const CONFIG_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/http/configuration.yaml");
 
 static TRACING: LazyLock<()> = LazyLock::new(|| {
     setup_tracing("test_app", std::io::sink);
 });
 
-#[derive(Clone)]
-struct MockSupply;
-
-impl TokenSupplyProvider for MockSupply {
-    async fn fetch_token_supply(&self, _token: &Token, _sources: &[Source]) -> Result<TokenSupply> {
-        Ok(TokenSupply::new("4242.00", "4200.00"))
-    }
-}
-
 pub struct TestApp {
     pub address: String,
     pub port: u16,
     pub api_client: reqwest::Client,
+    pub monitor: Arc<WorkerMonitor>,
+    pub data_dir: TempDir,
 }
 
 pub async fn spawn_app() -> TestApp {
-    LazyLock::force(&TRACING);
+    let token_supply = TokenSupply::new("4242.00", "4200.00");
+    let repo = InMemoryTokenSupplyRepository::new(token_supply);
 
-    let config = {
-        let mut c = Config::load().expect("Failed to retrieve config");
-        c.server.port = 0;
+    spawn(repo, true, |_| {}).await
+}
 
-        c
-    };
+/// Spawns an app whose configuration is adjusted by `configure` before the
+/// server is built.
+pub async fn spawn_app_with(configure: impl FnOnce(&mut Config)) -> TestApp {
+    let token_supply = TokenSupply::new("4242.00", "4200.00");
+    let repo = InMemoryTokenSupplyRepository::new(token_supply);
 
-    let name = "Supply";
-    let symbol = "SUPPLY";
-    let address = address!("0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118");
-    let decimals = 18;
+    spawn(repo, true, configure).await
+}
+
+/// Spawns an app that has not fetched or restored any supply yet, and whose
+/// worker has not been started.
+pub async fn spawn_uninitialised_app() -> TestApp {
+    spawn(InMemoryTokenSupplyRepository::empty(), false, |_| {}).await
+}
 
-    let token = Token::new(name, symbol, address, decimals);
+async fn spawn(
+    repo: InMemoryTokenSupplyRepository,
+    start_worker: bool,
+    configure: impl FnOnce(&mut Config),
+) -> TestApp {
+    let data_dir = tempfile::tempdir().expect("Failed to create data directory");
+    let config = load_config(&data_dir, configure);
 
-    let blockchain_provider = MockSupply;
-    let token_supply = TokenSupply::new("4242.00", "4200.00");
+    let token = StaticTokenMetadataProvider::new(config.token.overrides.clone())
+        .fetch_token_metadata(config.token.address.clone())
+        .await
+        .expect("Failed to build token metadata");
+
+    let supply = config
+        .blockchain
+        .static_supply
+        .clone()
+        .expect("Missing static supply");
+    let chain_id = config.blockchain.chain_id;
+
+    start(
+        config,
+        data_dir,
+        repo,
+        start_worker,
+        token,
+        StaticTokenSupplyProvider::new(supply),
+        StaticChainIdProvider::new(chain_id),
+    )
+    .await
+}
+
+/// Spawns an app whose supply and metadata are read from a mock RPC node,
+/// which is returned so tests can change its state or inject faults.
+///
+/// The node serves the token of the test configuration, whose Sink source
+/// holds 42 of its 4242 tokens.
+pub async fn spawn_rpc_app() -> (TestApp, MockRpc) {
+    let data_dir = tempfile::tempdir().expect("Failed to create data directory");
+    let mut config = load_config(&data_dir, |c| c.blockchain.provider = ProviderKind::Evm);
+
+    let state = Erc20State::new(
+        address!("0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118"),
+        "Supply",
+        "SUPPLY",
+        18,
+    )
+    .with_chain_id(config.blockchain.chain_id)
+    .with_block_number(8_123_456)
+    .with_total_supply(tokens(4242))
+    .with_balance(
+        address!("0xB1a932A665FB0A1D5d7979cd63e80a59EDCe31B4"),
+        tokens(42),
+    );
+    let rpc = MockRpc::start(state);
+    config.blockchain.rpc_url = rpc.url.clone();
+
+    let alloy_provider = ProviderBuilder::new().on_http(
+        config
+            .blockchain
+            .rpc_url
+            .parse()
+            .expect("Invalid mock RPC URL"),
+    );
+    let alloy_provider = Arc::new(alloy_provider);
+
+    let token = BlockchainTokenMetadataProvider::new(alloy_provider.clone())
+        .fetch_token_metadata(config.token.address.clone())
+        .await
+        .expect("Failed to fetch token metadata");
 
+    let token_supply = TokenSupply::from_raw(tokens(4242), tokens(42), 18).unwrap();
     let repo = InMemoryTokenSupplyRepository::new(token_supply);
 
-    let service = TokenSupplyUseCase::new(blockchain_provider, repo);
+    let app = start(
+        config,
+        data_dir,
+        repo,
+        true,
+        token,
+        BlockchainTokenSupplyProvider::new(alloy_provider.clone()),
+        BlockchainChainIdProvider::new(alloy_provider),
+    )
+    .await;
+
+    (app, rpc)
+}
+
+/// Loads the test configuration, storing data in `data_dir`.
+fn load_config(data_dir: &TempDir, configure: impl FnOnce(&mut Config)) -> Config {
+    LazyLock::force(&TRACING);
+
+    let mut c =
+        Config::load_with(Some(Path::new(CONFIG_PATH)), None).expect("Failed to retrieve config");
+    c.persistence.directory = data_dir.path().to_path_buf();
+    c.server.admin = AdminConfig {
+        credentials: vec![AdminCredential {
+            name: "test".into(),
+            token: ADMIN_TOKEN.into(),
+        }],
+    };
+    configure(&mut c);
+
+    c
+}
+
+/// Starts the server and, if asked to, the worker, with the given providers.
+async fn start<S, C>(
+    config: Config,
+    data_dir: TempDir,
+    repo: InMemoryTokenSupplyRepository,
+    start_worker: bool,
+    token: Token,
+    supply_provider: S,
+    chain_id_provider: C,
+) -> TestApp
+where
+    S: TokenSupplyProvider + Send + Sync + 'static,
+    C: ChainIdProvider + Send + Sync + 'static,
+{
+    let service = TokenSupplyUseCase::new(supply_provider, repo);
     let service = Arc::new(service);
 
+    let source_service = SourceUseCase::load(
+        FileSourceRepository::new(config.persistence.excluded_sources_path()),
+        FileSourceAuditLog::new(config.persistence.source_audit_path()),
+        config.excluded_sources,
+    )
+    .await
+    .expect("Failed to load excluded sources")
+    .with_token_address(config.token.address.clone());
+    source_service
+        .validate()
+        .await
+        .expect("Invalid excluded sources");
+    let source_service = Arc::new(source_service);
+
+    // Apps whose worker is not started model a server still starting up, so
+    // their token stays unresolved.
+    let explorer = Explorer::new(config.blockchain.resolved_explorer_url());
+    let resolved_token = Arc::new(ResolvedToken::new(explorer));
+    if start_worker {
+        resolved_token.set(token.clone());
+    }
+
     let (worker, handle) = TokenSupplyWorker::new(
         service.clone(),
         token,
-        config.excluded_sources,
+        source_service.clone(),
         config.server.update_interval,
     );
 
-    let app = HttpApplication::build(config.server, Data::from(service.clone()))
+    let monitor = Arc::new(WorkerMonitor::new());
+    let refresh_trigger = Arc::new(RefreshTrigger::new());
+
+    let chain_id = config.blockchain.chain_id;
+    let chain_verifier = ChainVerifier::new(chain_id_provider, chain_id, Duration::MAX);
+    chain_verifier
+        .verify()
         .await
-        .expect("Failed to build the application");
+        .expect("Failed to verify the chain");
+
+    let scheme = match config.server.tls {
+        Some(_) => "https",
+        None => "http",
+    };
+
+    let app = HttpApplication::build(
+        config.server,
+        Data::from(service.clone()),
+        Data::from(monitor.clone()),
+        Data::from(refresh_trigger.clone()),
+        Data::from(source_service),
+        Data::new(chain_verifier),
+        Data::from(resolved_token),
+    )
+    .await
+    .expect("Failed to build the application");
 
     let port = app.port();
 
-    worker.start(&handle).await;
+    if start_worker {
+        worker
+            .with_monitor(monitor.clone())
+            .with_refresh_trigger(refresh_trigger)
+            .start(&handle)
+            .await;
+    }
+    #[allow(clippy::let_underscore_future)]
     let _ = tokio::spawn(app.run());
 
     let api_client = reqwest::Client::builder()
@@ -79,8 +271,17 @@ pub async fn spawn_app() -> TestApp {
         .unwrap();
 
     TestApp {
-        address: format!("http://localhost:{}", port),
+        address: format!("{}://localhost:{}", scheme, port),
         port,
         api_client,
+        monitor,
+        data_dir,
     }
 }
+
+/// Converts whole tokens to the smallest unit of the 18-decimal test token.
+pub fn tokens(amount: u64) -> U256 {
+    utils::parse_units(&amount.to_string(), 18)
+        .expect("Invalid amount")
+        .into()
+}
@@ -0,0 +1,113 @@
+use reqwest::header::{
+    ACCESS_CONTROL_ALLOW_ORIGIN, ACCESS_CONTROL_MAX_AGE, ACCESS_CONTROL_REQUEST_METHOD, ORIGIN,
+};
+use reqwest::{Method, Response};
+
+use coingecko_supply::infrastructure::configuration::cors::CorsConfig;
+
+use crate::common::{TestApp, spawn_app_with};
+
+// -----------------------------------------------------------------------------
+// Test Helper Functions
+
+const DASHBOARD: &str = "https://dashboard.example.com";
+
+async fn spawn_cors_app() -> TestApp {
+    spawn_app_with(|c| {
+        c.server.cors = CorsConfig {
+            allowed_origins: vec![DASHBOARD.into()],
+            max_age: Some(600),
+            ..CorsConfig::default()
+        };
+    })
+    .await
+}
+
+async fn preflight(app: &TestApp, path: &str, origin: &str) -> Response {
+    app.api_client
+        .request(Method::OPTIONS, format!("{}{}", &app.address, path))
+        .header(ORIGIN, origin)
+        .header(ACCESS_CONTROL_REQUEST_METHOD, "GET")
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[tokio::test]
+async fn preflight_from_allowed_origin_succeeds() {
+    // Arrange.
+    let app = spawn_cors_app().await;
+
+    // Act.
+    let response = preflight(&app, "/v1/circulating", DASHBOARD).await;
+
+    // Assert.
+    assert!(response.status().is_success());
+    assert_eq!(response.headers()[ACCESS_CONTROL_ALLOW_ORIGIN], DASHBOARD);
+    assert_eq!(response.headers()[ACCESS_CONTROL_MAX_AGE], "600");
+}
+
+#[tokio::test]
+async fn request_from_allowed_origin_carries_cors_headers() {
+    // Arrange.
+    let app = spawn_cors_app().await;
+
+    // Act.
+    let response = app
+        .api_client
+        .get(format!("{}/v1/circulating", &app.address))
+        .header(ORIGIN, DASHBOARD)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert!(response.status().is_success());
+    assert_eq!(response.headers()[ACCESS_CONTROL_ALLOW_ORIGIN], DASHBOARD);
+}
+
+#[tokio::test]
+async fn request_from_other_origin_has_no_cors_headers() {
+    // Arrange.
+    let app = spawn_cors_app().await;
+
+    // Act.
+    let response = app
+        .api_client
+        .get(format!("{}/v1/circulating", &app.address))
+        .header(ORIGIN, "https://elsewhere.example.com")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert!(response.status().is_success());
+    assert!(!response.headers().contains_key(ACCESS_CONTROL_ALLOW_ORIGIN));
+}
+
+#[tokio::test]
+async fn admin_routes_do_not_allow_cross_origin_requests() {
+    // Arrange.
+    let app = spawn_cors_app().await;
+
+    // Act.
+    let response = preflight(&app, "/admin/refresh", DASHBOARD).await;
+
+    // Assert.
+    assert!(!response.headers().contains_key(ACCESS_CONTROL_ALLOW_ORIGIN));
+}
+
+#[tokio::test]
+async fn cors_is_disabled_without_allowed_origins() {
+    // Arrange.
+    let app = spawn_app_with(|_| {}).await;
+
+    // Act.
+    let response = preflight(&app, "/v1/circulating", DASHBOARD).await;
+
+    // Assert.
+    assert!(!response.headers().contains_key(ACCESS_CONTROL_ALLOW_ORIGIN));
+}
@@ -0,0 +1,164 @@
+use coingecko_supply::infrastructure::configuration::rate_limit::RateLimit;
+use serde_json::Value;
+
+use crate::common::{ADMIN_TOKEN, spawn_app, spawn_app_with, spawn_uninitialised_app};
+
+// -----------------------------------------------------------------------------
+// Test Helper Functions
+
+async fn envelope(response: reqwest::Response) -> Value {
+    let content_type = response
+        .headers()
+        .get("Content-Type")
+        .expect("Missing Content-Type header")
+        .to_str()
+        .unwrap()
+        .to_owned();
+
+    assert!(content_type.starts_with("application/json"));
+
+    let body: Value = response.json().await.expect("Body is not JSON");
+    assert!(
+        body["request_id"].as_str().is_some_and(|id| !id.is_empty()),
+        "Missing request_id in {}",
+        body
+    );
+
+    body
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[tokio::test]
+async fn unknown_routes_return_not_found_envelope() {
+    // Arrange.
+    let app = spawn_app().await;
+
+    // Act.
+    let response = app
+        .api_client
+        .get(format!("{}/v1/unknown", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status().as_u16(), 404);
+
+    let body = envelope(response).await;
+    assert_eq!(body["code"], "not_found");
+    assert!(body.get("retry_after").is_none());
+}
+
+#[tokio::test]
+async fn unsupported_methods_return_method_not_allowed_envelope() {
+    // Arrange.
+    let app = spawn_app().await;
+
+    // Act.
+    let response = app
+        .api_client
+        .delete(format!("{}/admin/sources", &app.address))
+        .bearer_auth(ADMIN_TOKEN)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status().as_u16(), 405);
+    assert_eq!(envelope(response).await["code"], "method_not_allowed");
+}
+
+#[tokio::test]
+async fn malformed_bodies_return_invalid_request_envelope() {
+    // Arrange.
+    let app = spawn_app().await;
+
+    // Act.
+    let response = app
+        .api_client
+        .post(format!("{}/admin/sources", &app.address))
+        .bearer_auth(ADMIN_TOKEN)
+        .header("Content-Type", "application/json")
+        .body("{ not json")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status().as_u16(), 400);
+    assert_eq!(envelope(response).await["code"], "invalid_request");
+}
+
+#[tokio::test]
+async fn uninitialised_supply_returns_its_own_code() {
+    // Arrange.
+    let app = spawn_uninitialised_app().await;
+
+    // Act.
+    let response = app
+        .api_client
+        .get(format!("{}/v1/total", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status().as_u16(), 503);
+    assert_eq!(envelope(response).await["code"], "supply_not_initialised");
+}
+
+#[tokio::test]
+async fn unauthenticated_admin_requests_return_unauthorized_envelope() {
+    // Arrange.
+    let app = spawn_app().await;
+
+    // Act.
+    let response = app
+        .api_client
+        .get(format!("{}/admin/sources", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status().as_u16(), 401);
+    assert_eq!(response.headers()["WWW-Authenticate"], "Bearer");
+    assert_eq!(envelope(response).await["code"], "unauthorized");
+}
+
+#[tokio::test]
+async fn rate_limited_requests_carry_retry_after() {
+    // Arrange.
+    let app = spawn_app_with(|c| {
+        c.server.rate_limit.public = Some(RateLimit {
+            requests_per_minute: 1,
+            burst: 1,
+        });
+    })
+    .await;
+
+    let url = format!("{}/v1/total", &app.address);
+    app.api_client.get(&url).send().await.unwrap();
+
+    // Act.
+    let response = app
+        .api_client
+        .get(&url)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status().as_u16(), 429);
+
+    let header = response.headers()["Retry-After"]
+        .to_str()
+        .unwrap()
+        .to_owned();
+    let body = envelope(response).await;
+
+    assert_eq!(body["code"], "rate_limited");
+    assert_eq!(body["retry_after"].to_string(), header);
+}
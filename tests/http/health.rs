@@ -1,6 +1,7 @@
-use crate::common::spawn_app;
+use crate::common::{spawn_app, spawn_uninitialised_app};
 
 #[tokio::test]
+#[allow(clippy::needless_borrows_for_generic_args)]
 async fn health_check_works() {
     // Arrange.
     let app = spawn_app().await;
@@ -17,3 +18,26 @@ async fn health_check_works() {
     assert!(response.status().is_success());
     assert_eq!(Some(0), response.content_length());
 }
+
+#[tokio::test]
+async fn health_check_fails_while_worker_is_restarting() {
+    // Arrange.
+    let app = spawn_uninitialised_app().await;
+    app.monitor.restarting("panicked: boom".into());
+
+    // Act.
+    let response = app
+        .api_client
+        .get(format!("{}/healthz", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status().as_u16(), 503);
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse body.");
+    assert_eq!(body["worker"]["state"], "restarting");
+    assert_eq!(body["worker"]["restarts"], 1);
+    assert_eq!(body["worker"]["last_error"], "panicked: boom");
+}
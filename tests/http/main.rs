@@ -1,4 +1,16 @@
+pub mod admin_refresh;
+pub mod admin_sources;
+pub mod caching;
 pub mod circulating_supply;
 pub mod common;
+pub mod cors;
+pub mod errors;
 pub mod health;
+pub mod metrics;
+pub mod openapi;
+pub mod rate_limit;
+pub mod static_provider;
+pub mod supply_document;
+pub mod tls;
+pub mod token;
 pub mod total_supply;
@@ -0,0 +1,26 @@
+use crate::common::spawn_uninitialised_app;
+
+#[tokio::test]
+async fn metrics_report_worker_state_and_restarts() {
+    // Arrange.
+    let app = spawn_uninitialised_app().await;
+    app.monitor.restarting("panicked: boom".into());
+
+    // Act.
+    let response = app
+        .api_client
+        .get(format!("{}/metrics", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert!(response.status().is_success());
+
+    let body = response.text().await.expect("Failed to extract body");
+
+    assert!(body.contains("supply_worker_state{state=\"restarting\"} 1"));
+    assert!(body.contains("supply_worker_state{state=\"running\"} 0"));
+    assert!(body.contains("supply_worker_restarts_total 1"));
+    assert!(body.contains("supply_rejected_updates_total 0"));
+}
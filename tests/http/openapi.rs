@@ -0,0 +1,70 @@
+use serde_json::Value;
+
+use crate::common::{spawn_app, spawn_app_with};
+
+#[tokio::test]
+async fn openapi_document_matches_committed_spec() {
+    // Arrange.
+    let app = spawn_app().await;
+    let committed = include_str!("../../openapi.json");
+
+    // Act.
+    let response = app
+        .api_client
+        .get(format!("{}/openapi.json", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert!(response.status().is_success());
+
+    let served = response
+        .json::<Value>()
+        .await
+        .expect("Failed to parse spec");
+    let committed: Value = serde_json::from_str(committed).unwrap();
+
+    assert!(
+        served == committed,
+        "The API changed; review it and run `make openapi` to update openapi.json"
+    );
+}
+
+#[tokio::test]
+async fn docs_page_is_not_served_by_default() {
+    // Arrange.
+    let app = spawn_app().await;
+
+    // Act.
+    let response = app
+        .api_client
+        .get(format!("{}/docs", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status().as_u16(), 404);
+}
+
+#[tokio::test]
+async fn docs_page_renders_openapi_document_when_enabled() {
+    // Arrange.
+    let app = spawn_app_with(|c| c.server.api_docs = true).await;
+
+    // Act.
+    let response = app
+        .api_client
+        .get(format!("{}/docs", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert!(response.status().is_success());
+
+    let body = response.text().await.expect("Failed to extract body");
+
+    assert!(body.contains("spec-url=\"/openapi.json\""));
+}
@@ -0,0 +1,109 @@
+use coingecko_supply::infrastructure::configuration::Config;
+use coingecko_supply::infrastructure::configuration::rate_limit::{RateLimit, TrustedProxy};
+
+use crate::common::{TestApp, spawn_app_with};
+
+// -----------------------------------------------------------------------------
+// Test Helper Functions
+
+fn limit_public(c: &mut Config) {
+    c.server.rate_limit.public = Some(RateLimit {
+        requests_per_minute: 1,
+        burst: 2,
+    });
+}
+
+async fn get(app: &TestApp, path: &str, forwarded_for: Option<&str>) -> reqwest::Response {
+    let mut request = app.api_client.get(format!("{}{}", &app.address, path));
+
+    if let Some(client) = forwarded_for {
+        request = request.header("X-Forwarded-For", client);
+    }
+
+    request.send().await.expect("Failed to execute request.")
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[tokio::test]
+async fn requests_over_the_burst_are_rejected_with_retry_after() {
+    // Arrange.
+    let app = spawn_app_with(limit_public).await;
+    get(&app, "/v1/total", None).await;
+    get(&app, "/v1/circulating", None).await;
+
+    // Act.
+    let response = get(&app, "/v1/total", None).await;
+
+    // Assert.
+    assert_eq!(response.status().as_u16(), 429);
+
+    let retry_after = response
+        .headers()
+        .get("Retry-After")
+        .expect("Missing Retry-After header")
+        .to_str()
+        .unwrap()
+        .parse::<u64>()
+        .expect("Retry-After is not a number of seconds");
+
+    assert!((1..=60).contains(&retry_after));
+}
+
+#[tokio::test]
+async fn health_and_metrics_are_not_rate_limited() {
+    // Arrange.
+    let app = spawn_app_with(limit_public).await;
+    for _ in 0..3 {
+        get(&app, "/v1/total", None).await;
+    }
+
+    // Act.
+    let health = get(&app, "/healthz", None).await;
+    let metrics = get(&app, "/metrics", None).await;
+
+    // Assert.
+    assert_ne!(health.status().as_u16(), 429);
+    assert_eq!(metrics.status().as_u16(), 200);
+}
+
+#[tokio::test]
+async fn rejections_are_counted_in_metrics() {
+    // Arrange.
+    let app = spawn_app_with(limit_public).await;
+    for _ in 0..4 {
+        get(&app, "/v1/total", None).await;
+    }
+
+    // Act.
+    let response = get(&app, "/metrics", None).await;
+
+    // Assert.
+    let body = response.text().await.expect("Failed to extract body");
+
+    assert!(body.contains("supply_rate_limited_requests_total{group=\"public\"} 2"));
+}
+
+#[tokio::test]
+async fn clients_behind_a_trusted_proxy_are_limited_separately() {
+    // Arrange.
+    let app = spawn_app_with(|c| {
+        limit_public(c);
+        c.server.rate_limit.trusted_proxies = vec![
+            TrustedProxy::try_from("127.0.0.1".to_string()).unwrap(),
+            TrustedProxy::try_from("::1".to_string()).unwrap(),
+        ];
+    })
+    .await;
+    get(&app, "/v1/total", Some("198.51.100.1")).await;
+    get(&app, "/v1/total", Some("198.51.100.1")).await;
+
+    // Act.
+    let limited = get(&app, "/v1/total", Some("198.51.100.1")).await;
+    let other = get(&app, "/v1/total", Some("198.51.100.2")).await;
+
+    // Assert.
+    assert_eq!(limited.status().as_u16(), 429);
+    assert!(other.status().is_success());
+}
@@ -0,0 +1,75 @@
+use alloy::primitives::address;
+use coingecko_supply::domain::model::TokenMetadataOverrides;
+use coingecko_supply::infrastructure::configuration::blockchain::{
+    StaticBalance, StaticSupplyConfig,
+};
+
+use crate::common::{TestApp, spawn_app_with};
+use crate::supply_document::first_document;
+
+// -----------------------------------------------------------------------------
+// Test Helper Functions
+
+/// Spawns an app for a pre-launch token whose Sink source holds 250000 of its
+/// 1000000 tokens.
+async fn spawn_prelaunch_app() -> TestApp {
+    spawn_app_with(|c| {
+        c.blockchain.static_supply = Some(StaticSupplyConfig {
+            total_supply: "1000000".into(),
+            balances: vec![StaticBalance {
+                address: address!("0xB1a932A665FB0A1D5d7979cd63e80a59EDCe31B4").into(),
+                balance: "250000".into(),
+            }],
+            block_number: Some(17),
+        });
+        c.token.overrides = TokenMetadataOverrides {
+            name: Some("Prelaunch".into()),
+            symbol: Some("PRE".into()),
+            decimals: Some(6),
+        };
+    })
+    .await
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[tokio::test]
+async fn static_provider_serves_configured_supply() {
+    // Arrange.
+    let app = spawn_prelaunch_app().await;
+
+    // Act.
+    let body = first_document(&app).await;
+
+    // Assert.
+    assert_eq!(body["token"]["name"], "Prelaunch");
+    assert_eq!(body["token"]["symbol"], "PRE");
+    assert_eq!(body["token"]["decimals"], 6);
+    assert_eq!(body["total_supply"]["raw"], "1000000000000");
+    assert_eq!(body["total_supply"]["formatted"], "1000000.000000");
+    assert_eq!(body["circulating_supply"]["raw"], "750000000000");
+    assert_eq!(body["circulating_supply"]["formatted"], "750000.000000");
+    assert_eq!(body["block_number"], 17);
+}
+
+#[tokio::test]
+async fn static_provider_serves_circulating_supply() {
+    // Arrange.
+    let app = spawn_prelaunch_app().await;
+    first_document(&app).await;
+
+    // Act.
+    let response = app
+        .api_client
+        .get(format!("{}/v1/circulating", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert!(response.status().is_success());
+
+    let body: serde_json::Value = response.json().await.expect("Failed to extract body");
+    assert_eq!(body["result"], "750000.000000");
+}
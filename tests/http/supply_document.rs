@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::common::{TestApp, spawn_app, spawn_uninitialised_app};
+
+// -----------------------------------------------------------------------------
+// Test Helper Functions
+
+/// Waits for the worker to store its first snapshot, which is the first one
+/// that records the token.
+pub async fn first_document(app: &TestApp) -> Value {
+    for _ in 0..50 {
+        let response = app
+            .api_client
+            .get(format!("{}/v2/supply", &app.address))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        if response.status().is_success() {
+            return response.json().await.expect("Failed to parse body.");
+        }
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    panic!("The worker never stored a snapshot");
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[tokio::test]
+async fn supply_document_includes_metadata_and_amounts() {
+    // Arrange.
+    let app = spawn_app().await;
+
+    // Act.
+    let body = first_document(&app).await;
+
+    // Assert.
+    assert_eq!(body["token"]["name"], "Supply");
+    assert_eq!(body["token"]["symbol"], "SUPPLY");
+    assert_eq!(
+        body["token"]["address"],
+        "0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118"
+    );
+    assert_eq!(body["token"]["decimals"], 2);
+    assert_eq!(body["total_supply"]["raw"], "424200");
+    assert_eq!(body["total_supply"]["formatted"], "4242.00");
+    assert_eq!(body["circulating_supply"]["raw"], "420000");
+    assert_eq!(body["circulating_supply"]["formatted"], "4200.00");
+    assert_eq!(body["block_number"], 8_123_456);
+}
+
+#[tokio::test]
+async fn supply_document_reports_freshness() {
+    // Arrange.
+    let app = spawn_app().await;
+
+    // Act.
+    let body = first_document(&app).await;
+
+    // Assert.
+    assert!(
+        body["fetched_at"]
+            .as_str()
+            .is_some_and(|s| s.ends_with('Z'))
+    );
+    assert!(body["age_seconds"].as_u64().is_some_and(|age| age < 60));
+    assert_eq!(body["stale"], false);
+}
+
+#[tokio::test]
+async fn supply_document_is_unavailable_before_first_fetch() {
+    // Arrange.
+    let app = spawn_uninitialised_app().await;
+
+    // Act.
+    let response = app
+        .api_client
+        .get(format!("{}/v2/supply", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status().as_u16(), 503);
+}
@@ -0,0 +1,226 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use rcgen::{
+    BasicConstraints, Certificate, CertificateParams, ExtendedKeyUsagePurpose, IsCa, KeyPair,
+};
+use reqwest::{Client, Identity, StatusCode};
+use tempfile::TempDir;
+
+use coingecko_supply::infrastructure::configuration::tls::TlsConfig;
+
+use crate::common::{ADMIN_TOKEN, TestApp, spawn_app_with};
+
+// -----------------------------------------------------------------------------
+// Test Helper Functions
+
+struct Authority {
+    cert: Certificate,
+    key: KeyPair,
+}
+
+impl Authority {
+    fn new() -> Self {
+        let mut params = CertificateParams::new(Vec::new()).unwrap();
+        params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+
+        let key = KeyPair::generate().unwrap();
+        let cert = params.self_signed(&key).unwrap();
+
+        Self { cert, key }
+    }
+
+    /// Issues a certificate, returning its PEM and the PEM of its key.
+    fn issue(&self, name: &str, usage: ExtendedKeyUsagePurpose) -> (String, String) {
+        let mut params = CertificateParams::new(vec![name.into()]).unwrap();
+        params.extended_key_usages = vec![usage];
+
+        let key = KeyPair::generate().unwrap();
+        let cert = params.signed_by(&key, &self.cert, &self.key).unwrap();
+
+        (cert.pem(), key.serialize_pem())
+    }
+}
+
+struct Certificates {
+    dir: TempDir,
+    server_ca: Authority,
+    client_ca: Authority,
+}
+
+impl Certificates {
+    fn new() -> Self {
+        let certs = Self {
+            dir: tempfile::tempdir().unwrap(),
+            server_ca: Authority::new(),
+            client_ca: Authority::new(),
+        };
+
+        std::fs::write(certs.path("client_ca.pem"), certs.client_ca.cert.pem()).unwrap();
+        certs.rotate_server_certificate(&certs.server_ca);
+
+        certs
+    }
+
+    fn path(&self, name: &str) -> PathBuf {
+        self.dir.path().join(name)
+    }
+
+    fn rotate_server_certificate(&self, ca: &Authority) {
+        let (cert, key) = ca.issue("localhost", ExtendedKeyUsagePurpose::ServerAuth);
+
+        std::fs::write(self.path("cert.pem"), cert).unwrap();
+        std::fs::write(self.path("key.pem"), key).unwrap();
+    }
+
+    fn config(&self, mutual: bool) -> TlsConfig {
+        TlsConfig {
+            cert_path: self.path("cert.pem"),
+            key_path: self.path("key.pem"),
+            client_ca_path: mutual.then(|| self.path("client_ca.pem")),
+            reload_interval: 1,
+        }
+    }
+}
+
+fn client(trusted: &Authority, identity: Option<&Authority>) -> Client {
+    let root = reqwest::Certificate::from_pem(trusted.cert.pem().as_bytes()).unwrap();
+
+    let mut builder = Client::builder()
+        .use_rustls_tls()
+        .tls_built_in_root_certs(false)
+        .add_root_certificate(root);
+
+    if let Some(ca) = identity {
+        let (cert, key) = ca.issue("ops", ExtendedKeyUsagePurpose::ClientAuth);
+        let pem = format!("{}{}", cert, key);
+
+        builder = builder.identity(Identity::from_pem(pem.as_bytes()).unwrap());
+    }
+
+    builder.build().unwrap()
+}
+
+async fn spawn_tls_app(certs: &Certificates, mutual: bool) -> TestApp {
+    let tls = certs.config(mutual);
+
+    spawn_app_with(|c| c.server.tls = Some(tls)).await
+}
+
+async fn refresh(app: &TestApp, client: &Client) -> reqwest::Result<reqwest::Response> {
+    client
+        .post(format!("{}/admin/refresh", &app.address))
+        .bearer_auth(ADMIN_TOKEN)
+        .send()
+        .await
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[tokio::test]
+async fn serves_https_with_configured_certificate() {
+    // Arrange.
+    let certs = Certificates::new();
+    let app = spawn_tls_app(&certs, false).await;
+
+    // Act.
+    let response = client(&certs.server_ca, None)
+        .get(format!("{}/v1/total", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert!(app.address.starts_with("https://"));
+    assert!(response.status().is_success());
+}
+
+#[tokio::test]
+async fn rotated_certificate_is_served_without_restart() {
+    // Arrange.
+    let certs = Certificates::new();
+    let app = spawn_tls_app(&certs, false).await;
+    let rotated_ca = Authority::new();
+    let client = client(&rotated_ca, None);
+
+    // Act.
+    certs.rotate_server_certificate(&rotated_ca);
+
+    // Assert.
+    for _ in 0..50 {
+        let response = client
+            .get(format!("{}/v1/total", &app.address))
+            .send()
+            .await;
+
+        if response.is_ok_and(|r| r.status().is_success()) {
+            return;
+        }
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    panic!("The rotated certificate was never served");
+}
+
+#[tokio::test]
+async fn admin_requires_client_certificate_when_client_ca_is_set() {
+    // Arrange.
+    let certs = Certificates::new();
+    let app = spawn_tls_app(&certs, true).await;
+
+    // Act.
+    let response = refresh(&app, &client(&certs.server_ca, None))
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn admin_accepts_client_certificate_from_client_ca() {
+    // Arrange.
+    let certs = Certificates::new();
+    let app = spawn_tls_app(&certs, true).await;
+
+    // Act.
+    let response = refresh(&app, &client(&certs.server_ca, Some(&certs.client_ca)))
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+}
+
+#[tokio::test]
+async fn client_certificate_from_another_ca_is_rejected() {
+    // Arrange.
+    let certs = Certificates::new();
+    let app = spawn_tls_app(&certs, true).await;
+    let untrusted = Authority::new();
+
+    // Act.
+    let result = refresh(&app, &client(&certs.server_ca, Some(&untrusted))).await;
+
+    // Assert.
+    assert!(result.is_err(), "Expected the handshake to fail");
+}
+
+#[tokio::test]
+async fn public_endpoints_do_not_require_client_certificate() {
+    // Arrange.
+    let certs = Certificates::new();
+    let app = spawn_tls_app(&certs, true).await;
+
+    // Act.
+    let response = client(&certs.server_ca, None)
+        .get(format!("{}/v1/circulating", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert!(response.status().is_success());
+}
@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::common::{TestApp, spawn_app, spawn_app_with, spawn_uninitialised_app};
+
+// -----------------------------------------------------------------------------
+// Test Helper Functions
+
+/// Waits for the token to be served.
+async fn first_token(app: &TestApp) -> Value {
+    for _ in 0..50 {
+        let response = app
+            .api_client
+            .get(format!("{}/v1/token", &app.address))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        if response.status().is_success() {
+            return response.json().await.expect("Failed to parse body.");
+        }
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    panic!("The worker never stored a snapshot");
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[tokio::test]
+async fn token_returns_metadata_chain_and_explorer_link() {
+    // Arrange.
+    let app = spawn_app().await;
+
+    // Act.
+    let body = first_token(&app).await;
+
+    // Assert.
+    assert_eq!(body["name"], "Supply");
+    assert_eq!(body["symbol"], "SUPPLY");
+    assert_eq!(
+        body["address"],
+        "0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118"
+    );
+    assert_eq!(body["decimals"], 2);
+    assert_eq!(body["chain_id"], 11155111);
+    assert_eq!(
+        body["explorer_url"],
+        "https://sepolia.etherscan.io/token/0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118"
+    );
+}
+
+#[tokio::test]
+async fn token_uses_configured_explorer() {
+    // Arrange.
+    let app = spawn_app_with(|c| {
+        c.blockchain.explorer_url = Some("https://explorer.example.com/".into());
+    })
+    .await;
+
+    // Act.
+    let body = first_token(&app).await;
+
+    // Assert.
+    assert_eq!(
+        body["explorer_url"],
+        "https://explorer.example.com/token/0xc3d7A72CcD1eDe897d83c8d768E624Abb69C4118"
+    );
+}
+
+#[tokio::test]
+async fn token_is_unavailable_before_first_fetch() {
+    // Arrange.
+    let app = spawn_uninitialised_app().await;
+
+    // Act.
+    let response = app
+        .api_client
+        .get(format!("{}/v1/token", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert.
+    assert_eq!(response.status().as_u16(), 503);
+}